@@ -68,10 +68,14 @@ use rust_serialization_benchmark::bench_speedy;
 #[cfg(feature = "wiring")]
 use rust_serialization_benchmark::bench_wiring;
 
-use rust_serialization_benchmark::generate_vec;
+#[cfg(feature = "measure-compression")]
+use rust_serialization_benchmark::dictionary;
+#[cfg(feature = "async")]
+use rust_serialization_benchmark::loopback;
+use rust_serialization_benchmark::{determinism, generate_vec, interop, random_access, stream};
 
 fn bench_log(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::log::{Log, Logs};
+    use rust_serialization_benchmark::datasets::log::{evolution, partial, Log, Logs};
 
     const BENCH: &'static str = "log";
 
@@ -105,17 +109,50 @@ fn bench_log(c: &mut Criterion) {
     bench_serde_brief::bench_borrowable(BENCH, c, &data);
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
+    bench_capnp::bench(
+        BENCH,
+        c,
+        &data,
+        |bytes| {
+            let message_reader =
+                capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
+            let data = message_reader
+                .get_root::<rust_serialization_benchmark::datasets::log::cp::logs::Reader>()
+                .unwrap();
+            for log in data.get_logs().unwrap().iter() {
+                black_box(log.get_address().unwrap());
+                black_box(log.get_code());
+                black_box(log.get_size());
+            }
+        },
+        |message| {
+            let data = message
+                .get_root::<rust_serialization_benchmark::datasets::log::cp::logs::Builder>()
+                .unwrap();
+            let mut logs = data.get_logs().unwrap();
+            for index in 0..logs.len() {
+                let mut log = logs.reborrow().get(index);
+                let mut address = log.reborrow().get_address().unwrap();
+                address.set_x0(0);
+                address.set_x1(0);
+                address.set_x2(0);
+                address.set_x3(0);
+                log.set_code(200);
+                log.set_size(0);
+            }
+        },
+    );
+    #[cfg(feature = "capnp")]
+    bench_capnp::bench_random_access(BENCH, c, &data, data.logs.len(), |bytes, index| {
         let message_reader =
             capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
         let data = message_reader
             .get_root::<rust_serialization_benchmark::datasets::log::cp::logs::Reader>()
             .unwrap();
-        for log in data.get_logs().unwrap().iter() {
-            black_box(log.get_address().unwrap());
-            black_box(log.get_code());
-            black_box(log.get_size());
-        }
+        let log = data.get_logs().unwrap().get(index as u32);
+        black_box(log.get_address().unwrap());
+        black_box(log.get_code());
+        black_box(log.get_size());
     });
 
     #[cfg(feature = "cbor4ii")]
@@ -155,7 +192,58 @@ fn bench_log(c: &mut Criterion) {
                 black_box(log.size());
             }
         },
+        |bytes| unsafe {
+            let logs = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::log::fb::Logs,
+            >(bytes)
+            .logs();
+            let elements = bench_flatbuffers::loc_of(bytes, logs.bytes());
+            let mut written = true;
+            for index in 0..logs.len() {
+                let log = bench_flatbuffers::table_loc(bytes, elements, index);
+                if let Some(address) = bench_flatbuffers::field_loc(
+                    bytes,
+                    log,
+                    rust_serialization_benchmark::datasets::log::fb::Log::VT_ADDRESS,
+                ) {
+                    let address = bench_flatbuffers::struct_mut::<
+                        rust_serialization_benchmark::datasets::log::fb::Address,
+                    >(bytes, address);
+                    address.set_x0(0);
+                    address.set_x1(0);
+                    address.set_x2(0);
+                    address.set_x3(0);
+                } else {
+                    written = false;
+                }
+                written &= bench_flatbuffers::mutate_scalar(
+                    bytes,
+                    log,
+                    rust_serialization_benchmark::datasets::log::fb::Log::VT_CODE,
+                    200u16,
+                );
+                written &= bench_flatbuffers::mutate_scalar(
+                    bytes,
+                    log,
+                    rust_serialization_benchmark::datasets::log::fb::Log::VT_SIZE,
+                    0u64,
+                );
+            }
+            written
+        },
     );
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_random_access(BENCH, c, &data, data.logs.len(), |bytes, index| {
+        let data = unsafe {
+            flatbuffers::root_unchecked::<rust_serialization_benchmark::datasets::log::fb::Logs>(
+                bytes,
+            )
+        };
+        let log = data.logs().get(index);
+        black_box(log.address());
+        black_box(log.code());
+        black_box(log.size());
+    });
 
     #[cfg(feature = "flexbuffers")]
     bench_flexbuffers::bench(BENCH, c, &data);
@@ -192,6 +280,13 @@ fn bench_log(c: &mut Criterion) {
             }
         },
     );
+    #[cfg(feature = "nibblecode")]
+    bench_nibblecode::bench_random_access(BENCH, c, &data, data.logs.len(), |logs, index| {
+        let log = &logs.logs[index];
+        black_box(&log.address);
+        black_box(log.code);
+        black_box(log.size);
+    });
 
     #[cfg(feature = "scale")]
     bench_parity_scale_codec::bench(BENCH, c, &data);
@@ -251,6 +346,13 @@ fn bench_log(c: &mut Criterion) {
             }
         },
     );
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_random_access(BENCH, c, &data, data.logs.len(), |logs, index| {
+        let log = &logs.logs[index];
+        black_box(&log.address);
+        black_box(log.code);
+        black_box(log.size);
+    });
 
     #[cfg(feature = "rmp-serde")]
     bench_rmp_serde::bench_borrowable(BENCH, c, &data);
@@ -281,10 +383,21 @@ fn bench_log(c: &mut Criterion) {
 
     #[cfg(feature = "wiring")]
     bench_wiring::bench(BENCH, c, &data);
+
+    evolution::check(BENCH, &data);
+    partial::bench(BENCH, c, &data);
+    random_access::bench_log(BENCH, c, &data);
+    interop::check_log(BENCH, &data);
+    determinism::check_log(BENCH, &data);
+    stream::bench_log(BENCH, c, &data);
+    #[cfg(feature = "measure-compression")]
+    dictionary::bench_log(BENCH, c, &data);
+    #[cfg(feature = "async")]
+    loopback::bench_log(BENCH, c, &data);
 }
 
 fn bench_mesh(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::mesh::{Mesh, Triangle};
+    use rust_serialization_benchmark::datasets::mesh::{evolution, partial, Mesh, Triangle};
 
     const BENCH: &'static str = "mesh";
 
@@ -318,15 +431,47 @@ fn bench_mesh(c: &mut Criterion) {
     bench_serde_brief::bench(BENCH, c, &data);
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
+    bench_capnp::bench(
+        BENCH,
+        c,
+        &data,
+        |bytes| {
+            let message_reader =
+                capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
+            let data = message_reader
+                .get_root::<rust_serialization_benchmark::datasets::mesh::cp::mesh::Reader>()
+                .unwrap();
+            for triangle in data.get_triangles().unwrap().iter() {
+                black_box(triangle.get_normal().unwrap());
+            }
+        },
+        |message| {
+            let data = message
+                .get_root::<rust_serialization_benchmark::datasets::mesh::cp::mesh::Builder>()
+                .unwrap();
+            let mut triangles = data.get_triangles().unwrap();
+            for index in 0..triangles.len() {
+                let mut normal = triangles.reborrow().get(index).get_normal().unwrap();
+                normal.set_x(0.0);
+                normal.set_y(0.0);
+                normal.set_z(0.0);
+            }
+        },
+    );
+    #[cfg(feature = "capnp")]
+    bench_capnp::bench_random_access(BENCH, c, &data, data.triangles.len(), |bytes, index| {
         let message_reader =
             capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
         let data = message_reader
             .get_root::<rust_serialization_benchmark::datasets::mesh::cp::mesh::Reader>()
             .unwrap();
-        for triangle in data.get_triangles().unwrap().iter() {
-            black_box(triangle.get_normal().unwrap());
-        }
+        black_box(
+            data.get_triangles()
+                .unwrap()
+                .get(index as u32)
+                .get_normal()
+                .unwrap(),
+        );
     });
 
     #[cfg(feature = "cbor4ii")]
@@ -362,6 +507,44 @@ fn bench_mesh(c: &mut Criterion) {
                 black_box(triangle.normal());
             }
         },
+        |bytes| unsafe {
+            let triangles = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::mesh::fb::Mesh,
+            >(bytes)
+            .triangles();
+            let elements = bench_flatbuffers::loc_of(bytes, triangles.bytes());
+            let normal =
+                rust_serialization_benchmark::datasets::mesh::fb::Vector3::new(0.0, 0.0, 0.0);
+            for index in 0..triangles.len() {
+                let triangle = bench_flatbuffers::struct_mut::<
+                    rust_serialization_benchmark::datasets::mesh::fb::Triangle,
+                >(
+                    bytes,
+                    elements
+                        + index
+                            * size_of::<rust_serialization_benchmark::datasets::mesh::fb::Triangle>(
+                            ),
+                );
+                triangle.set_normal(&normal);
+            }
+            // Triangles are structs, which are always stored
+            true
+        },
+    );
+    #[cfg(feature = "flatbuffers")]
+    bench_flatbuffers::bench_random_access(
+        BENCH,
+        c,
+        &data,
+        data.triangles.len(),
+        |bytes, index| {
+            let data = unsafe {
+                flatbuffers::root_unchecked::<rust_serialization_benchmark::datasets::mesh::fb::Mesh>(
+                    bytes,
+                )
+            };
+            black_box(data.triangles().get(index).normal());
+        },
     );
 
     #[cfg(feature = "flexbuffers")]
@@ -394,6 +577,10 @@ fn bench_mesh(c: &mut Criterion) {
             }
         },
     );
+    #[cfg(feature = "nibblecode")]
+    bench_nibblecode::bench_random_access(BENCH, c, &data, data.triangles.len(), |mesh, index| {
+        black_box(&mesh.triangles[index].normal);
+    });
 
     #[cfg(feature = "scale")]
     bench_parity_scale_codec::bench(BENCH, c, &data);
@@ -446,6 +633,10 @@ fn bench_mesh(c: &mut Criterion) {
             }
         },
     );
+    #[cfg(feature = "rkyv")]
+    bench_rkyv::bench_random_access(BENCH, c, &data, data.triangles.len(), |mesh, index| {
+        black_box(&mesh.triangles[index].normal);
+    });
 
     #[cfg(feature = "rmp-serde")]
     bench_rmp_serde::bench(BENCH, c, &data);
@@ -476,10 +667,23 @@ fn bench_mesh(c: &mut Criterion) {
 
     #[cfg(feature = "wiring")]
     bench_wiring::bench(BENCH, c, &data);
+
+    evolution::check(BENCH, &data);
+    partial::bench(BENCH, c, &data);
+    random_access::bench_mesh(BENCH, c, &data);
+    interop::check_mesh(BENCH, &data);
+    determinism::check_mesh(BENCH, &data);
+    stream::bench_mesh(BENCH, c, &data);
+    #[cfg(feature = "measure-compression")]
+    dictionary::bench_mesh(BENCH, c, &data);
+    #[cfg(feature = "async")]
+    loopback::bench_mesh(BENCH, c, &data);
 }
 
 fn bench_minecraft_savedata(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::minecraft_savedata::{Player, Players};
+    use rust_serialization_benchmark::datasets::minecraft_savedata::{
+        evolution, partial, Player, Players,
+    };
 
     const BENCH: &'static str = "minecraft_savedata";
 
@@ -513,16 +717,35 @@ fn bench_minecraft_savedata(c: &mut Criterion) {
     bench_serde_brief::bench_borrowable(BENCH, c, &data);
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
-        let message_reader =
-            capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
-        let data = message_reader
+    bench_capnp::bench(
+        BENCH,
+        c,
+        &data,
+        |bytes| {
+            let message_reader =
+                capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
+            let data = message_reader
       .get_root::<rust_serialization_benchmark::datasets::minecraft_savedata::cp::players::Reader>()
       .unwrap();
-        for player in data.get_players().unwrap().iter() {
-            black_box(player.get_game_type().unwrap());
-        }
-    });
+            for player in data.get_players().unwrap().iter() {
+                black_box(player.get_game_type().unwrap());
+            }
+        },
+        |message| {
+            let data = message
+            .get_root::<rust_serialization_benchmark::datasets::minecraft_savedata::cp::players::Builder>()
+            .unwrap();
+            let mut players = data.get_players().unwrap();
+            for index in 0..players.len() {
+                let mut player = players.reborrow().get(index);
+                player.set_game_type(rust_serialization_benchmark::datasets::minecraft_savedata::cp::GameType::Survival);
+                let mut spawn = player.get_spawn();
+                spawn.set_x(0);
+                spawn.set_y(0);
+                spawn.set_z(0);
+            }
+        },
+    );
 
     #[cfg(feature = "cbor4ii")]
     bench_cbor4ii::bench_borrowable(BENCH, c, &data);
@@ -558,6 +781,31 @@ fn bench_minecraft_savedata(c: &mut Criterion) {
                 black_box(player.game_type());
             }
         },
+        |bytes| unsafe {
+            use rust_serialization_benchmark::datasets::minecraft_savedata::fb::{
+                GameType, Player, Players,
+            };
+
+            let players = flatbuffers::root_unchecked::<Players>(bytes).players();
+            let elements = bench_flatbuffers::loc_of(bytes, players.bytes());
+            let mut written = true;
+            for index in 0..players.len() {
+                let player = bench_flatbuffers::table_loc(bytes, elements, index);
+                written &= bench_flatbuffers::mutate_scalar(
+                    bytes,
+                    player,
+                    Player::VT_GAME_TYPE,
+                    GameType::Survival,
+                );
+                written &=
+                    bench_flatbuffers::mutate_scalar(bytes, player, Player::VT_SPAWN_X, 0i64);
+                written &=
+                    bench_flatbuffers::mutate_scalar(bytes, player, Player::VT_SPAWN_Y, 0i64);
+                written &=
+                    bench_flatbuffers::mutate_scalar(bytes, player, Player::VT_SPAWN_Z, 0i64);
+            }
+            written
+        },
     );
 
     #[cfg(feature = "flexbuffers")]
@@ -678,10 +926,20 @@ fn bench_minecraft_savedata(c: &mut Criterion) {
 
     #[cfg(feature = "wiring")]
     bench_wiring::bench(BENCH, c, &data);
+
+    evolution::check(BENCH, &data);
+    partial::bench(BENCH, c, &data);
+    interop::check_minecraft_savedata(BENCH, &data);
+    determinism::check_minecraft_savedata(BENCH, &data);
+    stream::bench_minecraft_savedata(BENCH, c, &data);
+    #[cfg(feature = "measure-compression")]
+    dictionary::bench_minecraft_savedata(BENCH, c, &data);
+    #[cfg(feature = "async")]
+    loopback::bench_minecraft_savedata(BENCH, c, &data);
 }
 
 fn bench_mk48(c: &mut Criterion) {
-    use rust_serialization_benchmark::datasets::mk48::Updates;
+    use rust_serialization_benchmark::datasets::mk48::{evolution, partial, Updates};
 
     const BENCH: &'static str = "mk48";
 
@@ -715,16 +973,32 @@ fn bench_mk48(c: &mut Criterion) {
     bench_serde_brief::bench(BENCH, c, &data);
 
     #[cfg(feature = "capnp")]
-    bench_capnp::bench(BENCH, c, &data, |bytes| {
-        let message_reader =
-            capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
-        let data = message_reader
-            .get_root::<rust_serialization_benchmark::datasets::mk48::cp::updates::Reader>()
-            .unwrap();
-        for update in data.get_updates().unwrap().iter() {
-            black_box(update.get_score());
-        }
-    });
+    bench_capnp::bench(
+        BENCH,
+        c,
+        &data,
+        |bytes| {
+            let message_reader =
+                capnp::serialize::read_message_from_flat_slice(bytes, Default::default()).unwrap();
+            let data = message_reader
+                .get_root::<rust_serialization_benchmark::datasets::mk48::cp::updates::Reader>()
+                .unwrap();
+            for update in data.get_updates().unwrap().iter() {
+                black_box(update.get_score());
+            }
+        },
+        |message| {
+            let data = message
+                .get_root::<rust_serialization_benchmark::datasets::mk48::cp::updates::Builder>()
+                .unwrap();
+            let mut updates = data.get_updates().unwrap();
+            for index in 0..updates.len() {
+                let mut update = updates.reborrow().get(index);
+                let score = update.reborrow().get_score();
+                update.set_score(score * 2);
+            }
+        },
+    );
 
     #[cfg(feature = "cbor4ii")]
     bench_cbor4ii::bench(BENCH, c, &data);
@@ -761,6 +1035,28 @@ fn bench_mk48(c: &mut Criterion) {
                 black_box(update.score());
             }
         },
+        |bytes| unsafe {
+            let updates = flatbuffers::root_unchecked::<
+                rust_serialization_benchmark::datasets::mk48::fb::Updates,
+            >(bytes)
+            .updates();
+            let elements = bench_flatbuffers::loc_of(bytes, updates.bytes());
+            let mut written = true;
+            for index in 0..updates.len() {
+                let update = bench_flatbuffers::table_loc(bytes, elements, index);
+                if let Some(score) = bench_flatbuffers::field_loc(
+                    bytes,
+                    update,
+                    rust_serialization_benchmark::datasets::mk48::fb::Update::VT_SCORE,
+                ) {
+                    let value = flatbuffers::read_scalar_at::<u32>(bytes, score);
+                    flatbuffers::emplace_scalar(&mut bytes[score..], value * 2);
+                } else {
+                    written = false;
+                }
+            }
+            written
+        },
     );
 
     #[cfg(feature = "flexbuffers")]
@@ -860,6 +1156,16 @@ fn bench_mk48(c: &mut Criterion) {
 
     #[cfg(feature = "wiring")]
     bench_wiring::bench(BENCH, c, &data);
+
+    evolution::check(BENCH, &data);
+    partial::bench(BENCH, c, &data);
+    interop::check_mk48(BENCH, &data);
+    determinism::check_mk48(BENCH, &data);
+    stream::bench_mk48(BENCH, c, &data);
+    #[cfg(feature = "measure-compression")]
+    dictionary::bench_mk48(BENCH, c, &data);
+    #[cfg(feature = "async")]
+    loopback::bench_mk48(BENCH, c, &data);
 }
 
 #[cfg(feature = "pprof")]
@@ -10,6 +10,10 @@ fn capnpc_compile_dataset(name: &'static str) -> capnp::Result<()> {
     #[cfg(windows)]
     command.capnp_executable("prebuilt/capnp.exe");
     command.file(format!("src/datasets/{0}/{0}.capnp", name));
+    let evolution_schema = format!("src/datasets/{0}/{0}_evolution.capnp", name);
+    if Path::new(&evolution_schema).exists() {
+        command.file(evolution_schema);
+    }
     command.output_path(".");
     command.default_parent_module(vec!["datasets".into(), name.into()]);
     command.run()
@@ -22,9 +26,16 @@ fn flatc_compile_dataset(name: &'static str) -> flatc_rust::Result<()> {
     #[cfg(not(windows))]
     let flatc = flatc_rust::Flatc::from_env_path();
 
+    let schema = format!("./src/datasets/{0}/{0}.fbs", name);
+    let evolution_schema = format!("./src/datasets/{0}/{0}_evolution.fbs", name);
+    let mut inputs = vec![Path::new(&schema)];
+    if Path::new(&evolution_schema).exists() {
+        inputs.push(Path::new(&evolution_schema));
+    }
+
     flatc.run(flatc_rust::Args {
         lang: "rust",
-        inputs: &[Path::new(&format!("./src/datasets/{0}/{0}.fbs", name))],
+        inputs: &inputs,
         out_dir: Path::new(&format!("./src/datasets/{}", name)),
         extra: &["--gen-onefile"],
         ..Default::default()
@@ -40,8 +51,11 @@ fn prost_compile_dataset(name: &'static str) -> std::io::Result<()> {
     prost_config.protoc_arg("--experimental_allow_proto3_optional");
     prost_config.out_dir(format!("./src/datasets/{name}"));
     prost_config.compile_protos(
-        &[format!("./src/datasets/{name}/{name}.proto").as_str()],
-        &["src"],
+        &[
+            format!("./src/datasets/{name}/{name}.proto"),
+            format!("./src/datasets/{name}/{name}_evolution.proto"),
+        ],
+        &[format!("./src/datasets/{name}/")],
     )
 }
 
@@ -54,7 +68,10 @@ fn protobuf_compile_dataset(name: &'static str) -> std::io::Result<()> {
         .protoc()
         .protoc_extra_arg("--experimental_allow_proto3_optional")
         .out_dir(format!("./src/datasets/{name}/{name}_protobuf"))
-        .inputs(&[format!("./src/datasets/{name}/{name}.proto")])
+        .inputs(&[
+            format!("./src/datasets/{name}/{name}.proto"),
+            format!("./src/datasets/{name}/{name}_evolution.proto"),
+        ])
         .include(format!("./src/datasets/{name}/"))
         .run()
         .unwrap();
@@ -62,6 +79,22 @@ fn protobuf_compile_dataset(name: &'static str) -> std::io::Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "regenerate-protobuf")]
+fn protobuf_compile_determinism() -> std::io::Result<()> {
+    if cfg!(windows) && env::var("PROTOC").is_err() {
+        env::set_var("PROTOC", "./prebuilt/protoc.exe");
+    }
+    protobuf_codegen::Codegen::new()
+        .protoc()
+        .out_dir("./src/determinism/determinism_protobuf")
+        .input("./src/determinism/determinism.proto")
+        .include("./src/determinism/")
+        .run()
+        .unwrap();
+
+    Ok(())
+}
+
 fn main() {
     #[cfg(any(
         feature = "regenerate-capnp",
@@ -81,5 +114,7 @@ fn main() {
             #[cfg(feature = "regenerate-protobuf")]
             protobuf_compile_dataset(name).unwrap();
         }
+        #[cfg(feature = "regenerate-protobuf")]
+        protobuf_compile_determinism().unwrap();
     }
 }
@@ -28,6 +28,8 @@ pub struct CalcRow {
     pub feature: String,
     pub limit: Bottleneck,
     pub messages_per_second: f32,
+    /// Messages per second measured over the loopback transport, which doesn't compress.
+    pub loopback_messages_per_second: Option<f32>,
     pub relative: f32,
 }
 
@@ -52,6 +54,7 @@ pub fn calc(
             let Row {
                 feature,
                 serialize,
+                loopback,
                 compression,
                 ..
             } = r;
@@ -60,9 +63,10 @@ pub fn calc(
             compression
                 .into_iter()
                 .filter(|(c, _)| compression_set.contains(*c))
-                .map(move |(compression, entry)| {
+                .filter_map(move |(compression, entry)| {
                     let compressed_size = entry.size;
                     let compress_time = entry.compress.map(|v| v as f32);
+                    let decompress_time = entry.decompress.map(|v| v as f32);
 
                     // TODO this assumes that inbound bandwidth is equivalent to outbound bandwidth which isn't the case for many VPS.
                     let limit_size = bandwidth_per_second
@@ -71,9 +75,11 @@ pub fn calc(
                     // Use measured compress_time if it exists, or estimate it with Compression::serialize_seconds.
                     let serialize_seconds = serialize
                         + compress_time
-                            .unwrap_or_else(|| compression.serialize_seconds(uncompressed_size));
-                    let deserialize_seconds =
-                        deserialize + compression.deserialize_seconds(uncompressed_size);
+                            .or_else(|| compression.serialize_seconds(uncompressed_size))?;
+                    // Likewise for decompress_time and Compression::deserialize_seconds.
+                    let deserialize_seconds = deserialize
+                        + decompress_time
+                            .or_else(|| compression.deserialize_seconds(uncompressed_size))?;
                     let limit_speed = cpus
                         / match mode {
                             Mode::Serialize => serialize_seconds,
@@ -86,13 +92,14 @@ pub fn calc(
                     } else {
                         (limit_speed, Bottleneck::Cpu)
                     };
-                    CalcRow {
+                    Some(CalcRow {
                         compression,
                         feature: feature.clone(),
                         limit,
                         messages_per_second: benchmarks_per_second * messages_per_benchmark as f32,
+                        loopback_messages_per_second: loopback.map(|seconds| 1.0 / seconds),
                         relative: 0.0,
-                    }
+                    })
                 })
         })
         .collect();
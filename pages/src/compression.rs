@@ -5,11 +5,20 @@ use std::fmt::{Display, Formatter};
 pub type CompressionMap<T> = Map<Compression, T>;
 pub type CompressionSet = Set<Compression>;
 
+/// Variants are named after the compressors in `measure-compression` and
+/// `measure-compression-extra`, whose results are looked up by [`Compression`]'s [`Display`] name.
 #[derive(Copy, Clone, Debug, IntoEnumIterator, Key)]
 pub enum Compression {
     None,
+    Lz4,
+    Snappy,
     Zlib,
+    Zstd1,
     Zstd,
+    Zstd9,
+    Zstd19,
+    Brotli,
+    Xz,
 }
 
 impl Compression {
@@ -25,31 +34,37 @@ impl Compression {
         !self.is_none()
     }
 
-    pub fn serialize_seconds(self, bytes: u64) -> f32 {
-        // TODO real benchmarks (since speed is different on different data and cpus).
+    /// Estimates compression time for results which predate compression benchmarks.
+    pub fn serialize_seconds(self, bytes: u64) -> Option<f32> {
         const SCALE: f32 = 0.5;
         const ZLIB_MBPS: f32 = 31.375 * SCALE;
         const ZSTD_MBPS: f32 = 202.985 * SCALE;
 
-        bytes as f32
-            * match self {
-                Self::None => 0.0,
-                Self::Zlib => 1.0 / (ZLIB_MBPS * 1_000_000.0),
-                Self::Zstd => 1.0 / (ZSTD_MBPS * 1_000_000.0),
-            }
+        Some(
+            bytes as f32
+                * match self {
+                    Self::None => 0.0,
+                    Self::Zlib => 1.0 / (ZLIB_MBPS * 1_000_000.0),
+                    Self::Zstd => 1.0 / (ZSTD_MBPS * 1_000_000.0),
+                    _ => return None,
+                },
+        )
     }
 
-    pub fn deserialize_seconds(self, bytes: u64) -> f32 {
-        // TODO real benchmarks (since speed is different on different data and cpus).
+    /// Estimates decompression time for results which predate decompression benchmarks.
+    pub fn deserialize_seconds(self, bytes: u64) -> Option<f32> {
         const ZLIB_MBPS: f32 = 400.0;
         const ZSTD_MBPS: f32 = 1360.0;
 
-        bytes as f32
-            * match self {
-                Self::None => 0.0,
-                Self::Zlib => 1.0 / (ZLIB_MBPS * 1_000_000.0),
-                Self::Zstd => 1.0 / (ZSTD_MBPS * 1_000_000.0),
-            }
+        Some(
+            bytes as f32
+                * match self {
+                    Self::None => 0.0,
+                    Self::Zlib => 1.0 / (ZLIB_MBPS * 1_000_000.0),
+                    Self::Zstd => 1.0 / (ZSTD_MBPS * 1_000_000.0),
+                    _ => return None,
+                },
+        )
     }
 }
 
@@ -60,8 +75,15 @@ impl Display for Compression {
             "{}",
             match self {
                 Self::None => "",
+                Self::Lz4 => "lz4",
+                Self::Snappy => "snappy",
                 Self::Zlib => "zlib",
+                Self::Zstd1 => "zstd1",
                 Self::Zstd => "zstd",
+                Self::Zstd9 => "zstd9",
+                Self::Zstd19 => "zstd19",
+                Self::Brotli => "brotli",
+                Self::Xz => "xz",
             }
         )
     }
@@ -55,7 +55,7 @@ macro_rules! on_event {
 
 #[function_component(Benchmark)]
 fn benchmark() -> Html {
-    let results: Results = serde_json::from_str(RAW).unwrap();
+    let results = Results::from_json(RAW).unwrap();
     let datasets: Vec<_> = results.datasets.keys().collect();
 
     let bandwidth_state = Var::with_map(use_state(|| "1".to_owned()), step_zero_down);
@@ -253,6 +253,7 @@ fn benchmark() -> Html {
                     <th> { format!("{}/s", message_name) } </th>
                     <th> { "Relative" } </th>
                     <th> { "Bottleneck" } </th>
+                    <th title="Measured by sending uncompressed messages through an async framed codec, if benchmarked"> { format!("Loopback {}/s", message_name) } </th>
                 </tr>
                 {
                     rows.iter().map(|row| {
@@ -263,6 +264,7 @@ fn benchmark() -> Html {
                                 <td> { format_float(row.messages_per_second, 3) } </td>
                                 <td> { format!("{}%", (row.relative * 100.0) as u32) } </td>
                                 <td> { row.limit.to_string() } </td>
+                                <td> { row.loopback_messages_per_second.map(|v| format_float(v, 3)).unwrap_or_default() } </td>
                             </tr>
                         }
                     }).collect::<Html>()
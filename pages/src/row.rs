@@ -6,6 +6,8 @@ pub struct Row {
     pub feature: String,
     pub serialize: f32,
     pub deserialize: Option<f32>,
+    /// Time to send one message through the `async` loopback transport.
+    pub loopback: Option<f32>,
     pub compression: CompressionMap<CompressionEntry>,
 }
 
@@ -13,6 +15,7 @@ pub struct Row {
 pub struct CompressionEntry {
     pub size: u64,
     pub compress: Option<f64>,
+    pub decompress: Option<f64>,
 }
 
 type Error = &'static str;
@@ -32,10 +35,15 @@ fn unwrap_seconds(bench: &Bench) -> Result<Option<f64>> {
     }
 }
 
+/// Like [`unwrap_seconds`], but older results may not have the column at all.
+fn optional_seconds(bench: Option<&Bench>) -> Result<Option<f64>> {
+    bench.map_or(Ok(None), unwrap_seconds)
+}
+
 impl TryFrom<(&String, &Feature)> for Row {
     type Error = Error;
 
-    fn try_from((feature, Feature { benches }): (&String, &Feature)) -> Result<Self> {
+    fn try_from((feature, Feature { benches, .. }): (&String, &Feature)) -> Result<Self> {
         let col = |key: &'static str| -> Result<&Bench> { benches.get(key).ok_or(key) };
 
         let serialize = unwrap_seconds(col("serialize")?)?.ok_or("no serialize primary")? as f32;
@@ -43,6 +51,7 @@ impl TryFrom<(&String, &Feature)> for Row {
             .ok()
             .and_then(|v| unwrap_seconds(v).ok().flatten())
             .map(|v| v as f32);
+        let loopback = optional_seconds(benches.get("loopback"))?.map(|v| v as f32);
 
         let mut compression = CompressionMap::default();
         compression.insert(
@@ -50,27 +59,30 @@ impl TryFrom<(&String, &Feature)> for Row {
             CompressionEntry {
                 size: unwrap_bytes(col("size")?)?,
                 compress: None,
+                decompress: None,
             },
         );
-        compression.insert(
-            Compression::Zlib,
-            CompressionEntry {
-                size: unwrap_bytes(col("zlib")?)?,
-                compress: None,
-            },
-        );
-        compression.insert(
-            Compression::Zstd,
-            CompressionEntry {
-                size: unwrap_bytes(col("zstd")?)?,
-                compress: unwrap_seconds(col("zstd_time")?)?,
-            },
-        );
+        for c in Compression::iter().filter(|c| c.is_some()) {
+            let name = c.to_string();
+            // Results may predate this compressor or have been run without measure-compression-extra
+            let Some(size) = benches.get(&name) else {
+                continue;
+            };
+            compression.insert(
+                c,
+                CompressionEntry {
+                    size: unwrap_bytes(size)?,
+                    compress: optional_seconds(benches.get(&format!("{name}_time")))?,
+                    decompress: optional_seconds(benches.get(&format!("{name}_decompress_time")))?,
+                },
+            );
+        }
 
         Ok(Self {
             feature: feature.clone(),
             serialize,
             deserialize,
+            loopback,
             compression,
         })
     }
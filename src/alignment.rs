@@ -0,0 +1,115 @@
+//! Zero-copy access from misaligned buffers.
+//!
+//! Buffers received from the network can start at any address, but zero-copy libraries may need
+//! theirs aligned. Each library's encoded buffer is copied to several offsets past an aligned
+//! address and accessed there with validation. Libraries that reject it are tried again on a copy
+//! in an aligned buffer, and making that copy is benchmarked.
+
+use core::fmt;
+
+use criterion::{black_box, measurement::WallTime, BenchmarkGroup};
+
+/// The largest alignment that buffers are checked against.
+const MAX_ALIGN: usize = 16;
+
+/// The offsets past an address aligned to [`MAX_ALIGN`] that buffers are accessed at.
+const OFFSETS: [usize; 4] = [1, 2, 4, 8];
+
+/// How a library handled a buffer at a misaligned offset.
+pub enum Outcome {
+    /// The buffer was accessed in place.
+    Ok,
+    /// The buffer was rejected in place but accessed after copying it into an aligned buffer.
+    Copy,
+    /// The buffer was rejected even after copying it into an aligned buffer.
+    Error,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Ok => write!(f, "ok"),
+            Outcome::Copy => write!(f, "copy"),
+            Outcome::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A buffer aligned to [`MAX_ALIGN`], for libraries that have no aligned buffer type of their own.
+pub struct Realigned {
+    chunks: Vec<Chunk>,
+    len: usize,
+}
+
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct Chunk([u8; MAX_ALIGN]);
+
+impl Realigned {
+    /// Copies `bytes` into a new aligned buffer.
+    pub fn new(bytes: &[u8]) -> Self {
+        let mut chunks = vec![Chunk([0; MAX_ALIGN]); bytes.len().div_ceil(MAX_ALIGN)];
+        for (chunk, bytes) in chunks.iter_mut().zip(bytes.chunks(MAX_ALIGN)) {
+            chunk.0[..bytes.len()].copy_from_slice(bytes);
+        }
+        Self {
+            chunks,
+            len: bytes.len(),
+        }
+    }
+}
+
+impl AsRef<[u8]> for Realigned {
+    fn as_ref(&self) -> &[u8] {
+        // Safety: chunks are plain bytes, and the first `len` of them were copied in
+        unsafe { core::slice::from_raw_parts(self.chunks.as_ptr().cast::<u8>(), self.len) }
+    }
+}
+
+/// Copies `bytes` to `offset` bytes past an address aligned to [`MAX_ALIGN`]. Returns the buffer
+/// holding them and where in it they start.
+fn misaligned(bytes: &[u8], offset: usize) -> (Vec<u8>, usize) {
+    let mut buffer = vec![0; bytes.len() + 2 * MAX_ALIGN];
+    let start = buffer.as_ptr().align_offset(MAX_ALIGN) + offset;
+    buffer[start..start + bytes.len()].copy_from_slice(bytes);
+    (buffer, start)
+}
+
+/// Checks whether `access` accepts `bytes` at each misaligned offset, and prints the outcome for
+/// each offset. `access` must validate the buffer, since it is handed misaligned buffers, and
+/// `realign` copies a rejected buffer into an aligned one for `access` to try again.
+pub fn check<B: AsRef<[u8]>>(
+    name: &str,
+    lib: &str,
+    bytes: &[u8],
+    access: impl Fn(&[u8]) -> bool,
+    realign: impl Fn(&[u8]) -> B,
+) {
+    for offset in OFFSETS {
+        let (buffer, start) = misaligned(bytes, offset);
+        let bytes = &buffer[start..start + bytes.len()];
+
+        let outcome = if access(bytes) {
+            Outcome::Ok
+        } else if access(realign(bytes).as_ref()) {
+            Outcome::Copy
+        } else {
+            Outcome::Error
+        };
+        crate::bench_check(name, lib, &format!("alignment/offset_{offset}"), outcome);
+    }
+}
+
+/// Benchmarks copying `bytes` from a misaligned offset into an aligned buffer with `realign`.
+pub fn bench_realign<B>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    bytes: &[u8],
+    realign: impl Fn(&[u8]) -> B,
+) {
+    let (buffer, start) = misaligned(bytes, 1);
+    let bytes = &buffer[start..start + bytes.len()];
+
+    group.bench_function("realign", |b| {
+        b.iter(|| black_box(realign(black_box(bytes))))
+    });
+}
@@ -45,11 +45,13 @@ where
         })
     });
 
-    crate::bench_size(name, "bilrost", deserialize_buffer.as_slice());
+    crate::bench_slice_only(name, "bilrost");
 
     assert!(T::decode(&*deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "bilrost", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
@@ -32,7 +32,14 @@ where
         })
     });
 
-    crate::bench_size(name, "bincode", buffer);
+    crate::bench_io(
+        &mut group,
+        data,
+        |mut writer, data| {
+            bincode::encode_into_std_write(data, &mut writer, conf).unwrap();
+        },
+        |mut reader| bincode::decode_from_std_read(&mut reader, conf).unwrap(),
+    );
 
     assert!(
         bincode::decode_from_slice::<T, _>(black_box(buffer), conf)
@@ -42,6 +49,8 @@ where
     );
 
     group.finish();
+
+    crate::bench_size(c, name, "bincode", buffer);
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
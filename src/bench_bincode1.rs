@@ -28,11 +28,18 @@ where
         })
     });
 
-    crate::bench_size(name, "bincode1", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| bincode1::serialize_into(writer, data).unwrap(),
+        |reader| bincode1::deserialize_from(reader).unwrap(),
+    );
 
     assert!(bincode1::deserialize::<'_, T>(black_box(&deserialize_buffer)).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "bincode1", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
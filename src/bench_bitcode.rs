@@ -23,11 +23,13 @@ where
         })
     });
 
-    crate::bench_size(name, "bitcode", &encoded);
+    crate::bench_slice_only(name, "bitcode");
 
     assert!(buffer.decode::<T>(&encoded).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "bitcode", &encoded);
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
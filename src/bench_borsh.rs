@@ -26,11 +26,18 @@ where
         })
     });
 
-    crate::bench_size(name, "borsh", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |mut writer, data| data.serialize(&mut writer).unwrap(),
+        |mut reader| T::deserialize_reader(&mut reader).unwrap(),
+    );
 
     assert!(T::deserialize(&mut deserialize_buffer.as_slice()).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "borsh", deserialize_buffer.as_slice());
 }
 
 // borsh does not support borrowed decoding: https://github.com/near/borsh-rs/issues/90
@@ -1,6 +1,12 @@
-use capnp::{message::ScratchSpaceHeapAllocator, serialize::read_message_from_flat_slice};
+use capnp::{
+    message::{HeapAllocator, ScratchSpaceHeapAllocator},
+    serialize::read_message_from_flat_slice,
+};
 use criterion::{black_box, Criterion};
 
+use crate::alignment::{self, Realigned};
+use crate::mmap::{self, MappedFile};
+
 pub trait Serialize<'a> {
     type Reader: capnp::traits::FromPointerReader<'a>;
     type Builder: capnp::traits::FromPointerBuilder<'a>;
@@ -8,10 +14,17 @@ pub trait Serialize<'a> {
     fn serialize_capnp(&self, builder: &mut Self::Builder);
 }
 
-pub fn bench<T, R>(name: &'static str, c: &mut Criterion, data: &T, read: R)
+/// Reads an owned value back out of the struct that [`Serialize`] wrote, for the checks that
+/// compare decoded values with the originals.
+pub trait Deserialize<'a>: Serialize<'a> + Sized {
+    fn deserialize_capnp(reader: Self::Reader) -> capnp::Result<Self>;
+}
+
+pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
 where
     T: for<'a> Serialize<'a>,
     R: Fn(&mut &[u8]),
+    U: Fn(&mut capnp::message::Builder<HeapAllocator>),
 {
     const BUFFER_LEN: usize = 1_000_000;
 
@@ -57,7 +70,102 @@ where
         })
     });
 
-    crate::bench_size(name, "capnp", deserialize_buffer.as_slice());
+    // Reading a message from a stream copies it into owned segments, which are then accessed like
+    // the in-memory buffer
+    crate::bench_io_with_check(
+        &mut group,
+        data,
+        |writer, data| {
+            let mut builder = capnp::message::Builder::new_default();
+            data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+            capnp::serialize::write_message(writer, &builder).unwrap();
+        },
+        |reader| {
+            let message_reader =
+                capnp::serialize::read_message(reader, Default::default()).unwrap();
+            black_box(message_reader.get_root::<T::Reader>().unwrap());
+            message_reader
+        },
+        |message_reader| message_reader.get_root::<T::Reader>().is_ok(),
+    );
+
+    let file = MappedFile::new(&deserialize_buffer, align_of::<capnp::Word>());
+
+    mmap::bench(
+        &mut group,
+        "mmap_access (validated on-demand with error)",
+        &file,
+        |mut bytes| {
+            let message_reader =
+                read_message_from_flat_slice(&mut bytes, Default::default()).unwrap();
+            let reader = message_reader.get_root::<T::Reader>().unwrap();
+            black_box(reader);
+        },
+    );
+
+    mmap::bench(
+        &mut group,
+        "mmap_read (validated on-demand with error)",
+        &file,
+        |mut bytes| read(&mut bytes),
+    );
+
+    drop(file);
+
+    alignment::check(
+        name,
+        "capnp",
+        &deserialize_buffer,
+        |mut bytes| {
+            let Ok(message_reader) = read_message_from_flat_slice(&mut bytes, Default::default())
+            else {
+                return false;
+            };
+            let root = message_reader.get_root::<T::Reader>();
+            root.is_ok()
+        },
+        Realigned::new,
+    );
+    alignment::bench_realign(&mut group, &deserialize_buffer, Realigned::new);
+
+    // Messages can only be mutated through a builder, which owns its segments. This isn't an
+    // update of a serialized buffer, so it isn't compared with the other updates.
+    let mut update_builder = capnp::message::Builder::new_default();
+    data.serialize_capnp(&mut update_builder.init_root::<T::Builder>());
+    group.bench_function("update_builder", |b| {
+        b.iter(|| {
+            update(black_box(&mut update_builder));
+            black_box(());
+        })
+    });
+
+    group.finish();
+
+    crate::bench_size(c, name, "capnp", deserialize_buffer.as_slice());
+}
+
+pub fn bench_random_access<T>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    len: usize,
+    lookup: impl Fn(&mut &[u8], usize),
+) where
+    T: for<'a> Serialize<'a>,
+{
+    let mut group = c.benchmark_group(format!("{}/capnp", name));
+
+    let mut buffer = Vec::new();
+    let mut builder = capnp::message::Builder::new_default();
+    data.serialize_capnp(&mut builder.init_root::<T::Builder>());
+    capnp::serialize::write_message(&mut buffer, &builder).unwrap();
+
+    crate::random_access::bench(
+        &mut group,
+        "random_access (validated on-demand with error)",
+        len,
+        |index| lookup(black_box(&mut buffer.as_slice()), index),
+    );
 
     group.finish();
 }
@@ -28,11 +28,18 @@ where
         })
     });
 
-    crate::bench_size(name, "cbor4ii", deserialize_buffer.as_slice());
+    crate::bench_buf_io(
+        &mut group,
+        data,
+        |writer, data| cbor4ii::serde::to_writer(writer, data).unwrap(),
+        |reader| cbor4ii::serde::from_reader(reader).unwrap(),
+    );
 
     assert!(cbor4ii::serde::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "cbor4ii", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
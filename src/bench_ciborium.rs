@@ -33,11 +33,18 @@ where
         })
     });
 
-    crate::bench_size(name, "ciborium", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| ciborium::ser::into_writer(data, writer).unwrap(),
+        |reader| ciborium::de::from_reader(reader).unwrap(),
+    );
 
     assert!(ciborium::de::from_reader::<T, _>(deserialize_buffer.as_slice()).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "ciborium", deserialize_buffer.as_slice());
 }
 
 // ciborium only provides reading implementations that require DeserializeOwned, and thus won't
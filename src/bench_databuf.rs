@@ -26,11 +26,16 @@ where
         })
     });
 
-    crate::bench_size(name, "databuf", deserialize_buffer.as_slice());
+    // databuf only decodes from slices
+    crate::bench_writer(&mut group, data, |mut writer, data| {
+        data.encode::<LE>(&mut writer).unwrap()
+    });
 
     assert!(T::from_bytes::<LE>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "databuf", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
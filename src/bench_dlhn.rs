@@ -37,7 +37,15 @@ where
         })
     });
 
-    crate::bench_size(name, "dlhn", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |mut writer, data| {
+            data.serialize(&mut dlhn::ser::Serializer::new(&mut writer))
+                .unwrap()
+        },
+        |mut reader| T::deserialize(&mut dlhn::de::Deserializer::new(&mut reader)).unwrap(),
+    );
 
     assert!(
         <T>::deserialize(&mut dlhn::de::Deserializer::new(
@@ -48,6 +56,8 @@ where
     );
 
     group.finish();
+
+    crate::bench_size(c, name, "dlhn", deserialize_buffer.as_slice());
 }
 
 // dlhn both has a deserialize api that takes `std::io::Read` and has not yet implemented decoding
@@ -1,5 +1,11 @@
 use criterion::{black_box, Criterion};
-use flatbuffers::{FlatBufferBuilder, Follow, Verifiable, WIPOffset};
+use flatbuffers::{
+    emplace_scalar, read_scalar_at, EndianScalar, FlatBufferBuilder, Follow, SimpleToVerifyInSlice,
+    Table, UOffsetT, VOffsetT, Verifiable, WIPOffset, SIZE_UOFFSET,
+};
+
+use crate::alignment;
+use crate::mmap::{self, MappedFile};
 
 pub trait Serialize<'a> {
     type Target: 'a + Follow<'a> + Verifiable;
@@ -9,12 +15,19 @@ pub trait Serialize<'a> {
         'a: 'b;
 }
 
+/// Reads an owned value back out of the table that [`Serialize`] wrote, for the checks that
+/// compare decoded values with the originals.
+pub trait Deserialize<'a>: Serialize<'a> + Sized {
+    fn deserialize_fb(target: <Self::Target as Follow<'a>>::Inner) -> Self;
+}
+
 pub fn bench<T>(
     name: &'static str,
     c: &mut Criterion,
     data: &T,
     read_unverified: impl Fn(&[u8]),
     read_verified: impl Fn(&[u8]),
+    update: impl Fn(&mut [u8]) -> bool,
 ) where
     T: for<'a> Serialize<'a>,
 {
@@ -68,9 +81,161 @@ pub fn bench<T>(
         })
     });
 
-    crate::bench_size(name, "flatbuffers", deserialize_buffer);
+    // flatbuffers reads its scalars unaligned, so buffers can be anywhere
+    let file = MappedFile::new(deserialize_buffer, 1);
+
+    mmap::bench(
+        &mut group,
+        "mmap_access (unvalidated)",
+        &file,
+        |bytes| unsafe {
+            black_box(flatbuffers::root_unchecked::<<T as Serialize<'_>>::Target>(
+                bytes,
+            ));
+        },
+    );
+
+    mmap::bench(
+        &mut group,
+        "mmap_access (validated upfront with error)",
+        &file,
+        |bytes| {
+            black_box(flatbuffers::root::<<T as Serialize<'_>>::Target>(bytes).unwrap());
+        },
+    );
+
+    mmap::bench(
+        &mut group,
+        "mmap_read (unvalidated)",
+        &file,
+        &read_unverified,
+    );
+
+    mmap::bench(
+        &mut group,
+        "mmap_read (validated upfront with error)",
+        &file,
+        &read_verified,
+    );
+
+    drop(file);
+
+    alignment::check(
+        name,
+        "flatbuffers",
+        deserialize_buffer,
+        |bytes| flatbuffers::root::<<T as Serialize<'_>>::Target>(bytes).is_ok(),
+        <[u8]>::to_vec,
+    );
+
+    // Fields with their default values aren't stored unless forced, and can't be overwritten in
+    // place. `update` returns whether it wrote every field, so one that was skipped fails here
+    // instead of being benchmarked as a no-op.
+    let mut update_fbb = FlatBufferBuilder::new();
+    update_fbb.force_defaults(true);
+    let root = data.serialize_fb(&mut update_fbb);
+    update_fbb.finish(root, None);
+    let mut update_buffer = update_fbb.finished_data().to_vec();
+    assert!(
+        update(&mut update_buffer),
+        "the update didn't write every field"
+    );
+
+    group.bench_function("update (unvalidated)", |b| {
+        b.iter(|| black_box(update(black_box(update_buffer.as_mut_slice()))))
+    });
+
+    crate::bench_slice_only(name, "flatbuffers");
 
     group.finish();
+
+    crate::bench_size(c, name, "flatbuffers", deserialize_buffer);
+}
+
+pub fn bench_random_access<T>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    len: usize,
+    lookup: impl Fn(&[u8], usize),
+) where
+    T: for<'a> Serialize<'a>,
+{
+    let mut group = c.benchmark_group(format!("{}/flatbuffers", name));
+
+    let mut fbb = FlatBufferBuilder::new();
+    let root = data.serialize_fb(&mut fbb);
+    fbb.finish(root, None);
+    let buffer = fbb.finished_data();
+
+    crate::random_access::bench(&mut group, "random_access (unvalidated)", len, |index| {
+        lookup(black_box(buffer), index)
+    });
+
+    group.finish();
+}
+
+/// Returns the location of `slice` in `buffer`. Readers borrow the buffer, so this turns what they
+/// found into locations that can be mutated once they are dropped.
+pub fn loc_of(buffer: &[u8], slice: &[u8]) -> usize {
+    slice.as_ptr() as usize - buffer.as_ptr() as usize
+}
+
+/// Returns the location of the table at `index` in the vector of tables whose elements start at
+/// `elements`.
+///
+/// # Safety
+///
+/// `elements` must be the location of the elements of a vector of tables with more than `index`
+/// elements.
+pub unsafe fn table_loc(buffer: &[u8], elements: usize, index: usize) -> usize {
+    let loc = elements + index * SIZE_UOFFSET;
+    loc + read_scalar_at::<UOffsetT>(buffer, loc) as usize
+}
+
+/// Returns the location of `field` in the table at `table`, or `None` if the field is not stored
+/// because it has its default value.
+///
+/// # Safety
+///
+/// `table` must be the location of a table.
+pub unsafe fn field_loc(buffer: &[u8], table: usize, field: VOffsetT) -> Option<usize> {
+    let offset = Table::new(buffer, table).vtable().get(field);
+    (offset != 0).then(|| table + offset as usize)
+}
+
+/// Overwrites a scalar field of the table at `table`, like the `mutate_*` methods that `flatc`
+/// generates for C++. Fields that are not stored can't be mutated, so this returns whether the
+/// field was written.
+///
+/// # Safety
+///
+/// `table` must be the location of a table, and `field` must have type `T`.
+pub unsafe fn mutate_scalar<T: EndianScalar>(
+    buffer: &mut [u8],
+    table: usize,
+    field: VOffsetT,
+    value: T,
+) -> bool {
+    match field_loc(buffer, table, field) {
+        Some(loc) => {
+            emplace_scalar(&mut buffer[loc..loc + size_of::<T::Scalar>()], value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Returns the struct at `loc` mutably, so it can be updated with its generated setters.
+///
+/// # Safety
+///
+/// `loc` must be the location of a `T`.
+pub unsafe fn struct_mut<T: SimpleToVerifyInSlice>(buffer: &mut [u8], loc: usize) -> &mut T {
+    // Generated structs wrap byte arrays, so they can be at any location
+    assert_eq!(align_of::<T>(), 1);
+    let bytes = &mut buffer[loc..loc + size_of::<T>()];
+    &mut *bytes.as_mut_ptr().cast::<T>()
 }
 
 // flatbuffers is a pseudo-zerocopy library with incremental decoding and does not support borrowed
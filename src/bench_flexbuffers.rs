@@ -33,7 +33,9 @@ where
         })
     });
 
-    crate::bench_size(name, "flexbuffers", deserialize_buffer);
+    crate::bench_slice_only(name, "flexbuffers");
 
     group.finish();
+
+    crate::bench_size(c, name, "flexbuffers", deserialize_buffer);
 }
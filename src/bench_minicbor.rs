@@ -27,11 +27,16 @@ where
         })
     });
 
-    crate::bench_size(name, "minicbor", deserialize_buffer.as_slice());
+    // minicbor only decodes from slices
+    crate::bench_writer(&mut group, data, |writer, data| {
+        minicbor::encode(data, minicbor::encode::write::Writer::new(writer)).unwrap()
+    });
 
     assert!(minicbor::decode::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "minicbor", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
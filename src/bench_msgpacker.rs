@@ -28,11 +28,13 @@ where
         })
     });
 
-    crate::bench_size(name, "msgpacker", &deserialize_buffer);
+    crate::bench_slice_only(name, "msgpacker");
 
     assert!(T::unpack(&deserialize_buffer).unwrap().1 == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "msgpacker", &deserialize_buffer);
 }
 
 // msgpacker does not seem to support borrowed decoding.
@@ -31,11 +31,16 @@ where
         })
     });
 
-    crate::bench_size(name, "nachricht-serde", deserialize_buffer.as_slice());
+    // nachricht-serde only deserializes from slices
+    crate::bench_writer(&mut group, data, |writer, data| {
+        nachricht_serde::to_writer(writer, data).unwrap()
+    });
 
     assert!(nachricht_serde::from_bytes::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "nachricht-serde", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
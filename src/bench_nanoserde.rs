@@ -22,11 +22,13 @@ where
         })
     });
 
-    crate::bench_size(name, "nanoserde", deserialize_buffer.as_slice());
+    crate::bench_slice_only(name, "nanoserde");
 
     assert!(<T as DeBin>::deserialize_bin(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "nanoserde", deserialize_buffer.as_slice());
 }
 
 // TODO: nanoserde implements json & ron encodings as well as the binary encoding, so we could
@@ -4,6 +4,9 @@ use nibblecode::{
     to_bytes, to_bytes_in_unchecked, Serialize,
 };
 
+use crate::alignment::{self, Realigned};
+use crate::mmap::{self, MappedFile};
+
 pub fn bench<T, R, U>(name: &'static str, c: &mut Criterion, data: &T, read: R, update: U)
 where
     T: Serialize<Archived: PartialEq<T>> + PartialEq,
@@ -44,6 +47,45 @@ where
         })
     });
 
+    let file = MappedFile::new(&buffer, align_of::<T::Archived>());
+
+    mmap::bench(&mut group, "mmap_access (unvalidated)", &file, |bytes| {
+        black_box(unsafe { access_unchecked::<T>(bytes) });
+    });
+
+    mmap::bench(
+        &mut group,
+        "mmap_access (validated upfront with error)",
+        &file,
+        |bytes| {
+            black_box(access::<T>(bytes).unwrap());
+        },
+    );
+
+    mmap::bench(&mut group, "mmap_read (unvalidated)", &file, |bytes| {
+        read(unsafe { access_unchecked::<T>(bytes) });
+    });
+
+    mmap::bench(
+        &mut group,
+        "mmap_read (validated upfront with error)",
+        &file,
+        |bytes| {
+            read(access::<T>(bytes).unwrap());
+        },
+    );
+
+    drop(file);
+
+    alignment::check(
+        name,
+        "nibblecode",
+        &buffer,
+        |bytes| access::<T>(bytes).is_ok_and(|value| value == data),
+        Realigned::new,
+    );
+    alignment::bench_realign(&mut group, &buffer, Realigned::new);
+
     let mut update_buffer = buffer.clone();
     group.bench_function("update (unvalidated)", |b| {
         b.iter(|| {
@@ -52,9 +94,33 @@ where
         })
     });
 
-    crate::bench_size(name, "nibblecode", &buffer);
+    crate::bench_slice_only(name, "nibblecode");
 
     assert!(access::<T>(buffer.as_ref()).unwrap() == data);
 
     group.finish();
+
+    crate::bench_size(c, name, "nibblecode", &buffer);
+}
+
+pub fn bench_random_access<T, L>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    len: usize,
+    lookup: L,
+) where
+    T: Serialize,
+    L: Fn(&T::Archived, usize),
+{
+    let mut group = c.benchmark_group(format!("{}/nibblecode", name));
+
+    let buffer = to_bytes(data).unwrap();
+
+    crate::random_access::bench(&mut group, "random_access (unvalidated)", len, |index| {
+        let value = unsafe { access_unchecked::<T>(black_box(&*buffer)) };
+        lookup(value, index);
+    });
+
+    group.finish();
 }
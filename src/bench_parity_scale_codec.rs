@@ -1,5 +1,5 @@
 use criterion::{black_box, Criterion};
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, IoReader};
 
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
@@ -25,11 +25,18 @@ where
         })
     });
 
-    crate::bench_size(name, "parity-scale-codec", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |mut writer, data| data.encode_to(&mut writer),
+        |reader| T::decode(&mut IoReader(reader)).unwrap(),
+    );
 
     assert!(T::decode(&mut deserialize_buffer.as_slice()).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "parity-scale-codec", deserialize_buffer.as_slice());
 }
 
 // parity_scale_codec does not appear to support borrowed decoding.
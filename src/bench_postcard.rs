@@ -28,11 +28,26 @@ where
         })
     });
 
-    crate::bench_size(name, "postcard", deserialize_buffer.as_slice());
+    // Strings and bytes are read into the scratch buffer before they're deserialized
+    let mut scratch = vec![0; BUFFER_LEN];
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| {
+            postcard::to_io(data, writer).unwrap();
+        },
+        |reader| {
+            postcard::from_io((reader, scratch.as_mut_slice()))
+                .unwrap()
+                .0
+        },
+    );
 
     assert!(postcard::from_bytes::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "postcard", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
@@ -25,11 +25,18 @@ where
         })
     });
 
-    crate::bench_size(name, "pot", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| pot::to_writer(data, writer).unwrap(),
+        |reader| pot::from_reader(reader).unwrap(),
+    );
 
     assert!(pot::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "pot", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
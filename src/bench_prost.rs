@@ -43,11 +43,13 @@ where
         })
     });
 
-    crate::bench_size(name, "prost", deserialize_buffer.as_slice());
+    crate::bench_slice_only(name, "prost");
 
     assert!(<T::Message>::decode(&*deserialize_buffer).unwrap().into() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "prost", deserialize_buffer.as_slice());
 }
 
 // prost does not support borrowed decoding.
@@ -47,7 +47,12 @@ where
         })
     });
 
-    crate::bench_size(name, "protobuf", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| data.serialize_pb().write_to_writer(writer).unwrap(),
+        |reader| T::Message::parse_from_reader(reader).unwrap().into(),
+    );
 
     assert!(
         T::Message::parse_from_bytes(&deserialize_buffer)
@@ -57,4 +62,6 @@ where
     );
 
     group.finish();
+
+    crate::bench_size(c, name, "protobuf", deserialize_buffer.as_slice());
 }
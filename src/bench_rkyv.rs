@@ -13,6 +13,9 @@ use rkyv::{
     Archive, Deserialize, Serialize,
 };
 
+use crate::alignment;
+use crate::mmap::{self, MappedFile};
+
 pub type BenchSerializer<'a> = HighSerializer<AlignedVec, ArenaHandle<'a>, Failure>;
 pub type BenchDeserializer = HighDeserializer<Failure>;
 pub type BenchValidator<'a> = HighValidator<'a, Failure>;
@@ -62,6 +65,50 @@ where
         })
     });
 
+    let file = MappedFile::new(&buffer, <AlignedVec>::ALIGNMENT);
+
+    mmap::bench(&mut group, "mmap_access (unvalidated)", &file, |bytes| {
+        black_box(unsafe { access_unchecked::<T::Archived>(bytes) });
+    });
+
+    mmap::bench(
+        &mut group,
+        "mmap_access (validated upfront with error)",
+        &file,
+        |bytes| {
+            black_box(access::<T::Archived, Failure>(bytes).unwrap());
+        },
+    );
+
+    mmap::bench(&mut group, "mmap_read (unvalidated)", &file, |bytes| {
+        read(unsafe { access_unchecked::<T::Archived>(bytes) });
+    });
+
+    mmap::bench(
+        &mut group,
+        "mmap_read (validated upfront with error)",
+        &file,
+        |bytes| {
+            read(access::<T::Archived, Failure>(bytes).unwrap());
+        },
+    );
+
+    drop(file);
+
+    let realign = |bytes: &[u8]| {
+        let mut buffer = <AlignedVec>::with_capacity(bytes.len());
+        buffer.extend_from_slice(bytes);
+        buffer
+    };
+    alignment::check(
+        name,
+        "rkyv",
+        &buffer,
+        |bytes| from_bytes::<T, Failure>(bytes).is_ok_and(|value| value == *data),
+        realign,
+    );
+    alignment::bench_realign(&mut group, &buffer, realign);
+
     let mut update_buffer = buffer.clone();
     group.bench_function("update (unvalidated)", |b| {
         b.iter(|| {
@@ -86,11 +133,35 @@ where
         })
     });
 
-    crate::bench_size(name, "rkyv", &buffer);
+    crate::bench_slice_only(name, "rkyv");
 
     assert!(from_bytes::<T, Failure>(buffer.as_ref()).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "rkyv", &buffer);
+}
+
+pub fn bench_random_access<T, L>(
+    name: &'static str,
+    c: &mut Criterion,
+    data: &T,
+    len: usize,
+    lookup: L,
+) where
+    T: Archive + for<'a> Serialize<BenchSerializer<'a>>,
+    L: Fn(&T::Archived, usize),
+{
+    let mut group = c.benchmark_group(format!("{}/rkyv", name));
+
+    let buffer = to_bytes_in(data, AlignedVec::new()).unwrap();
+
+    crate::random_access::bench(&mut group, "random_access (unvalidated)", len, |index| {
+        let value = unsafe { access_unchecked::<T::Archived>(black_box(buffer.as_ref())) };
+        lookup(value, index);
+    });
+
+    group.finish();
 }
 
 // rkyv is a true zero-copy decoding, rather than implementing borrowed decoding.
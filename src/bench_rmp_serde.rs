@@ -31,11 +31,18 @@ where
         })
     });
 
-    crate::bench_size(name, "rmp-serde", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| rmp_serde::encode::write(writer, data).unwrap(),
+        |reader| rmp_serde::from_read(reader).unwrap(),
+    );
 
     assert!(rmp_serde::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "rmp-serde", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
@@ -29,11 +29,18 @@ where
         })
     });
 
-    crate::bench_size(name, "ron", deserialize_buffer.as_ref());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| ron::Options::default().to_io_writer(writer, data).unwrap(),
+        |reader| ron::de::from_reader(reader).unwrap(),
+    );
 
     assert!(ron::de::from_bytes::<T>(deserialize_buffer.as_ref()).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "ron", deserialize_buffer.as_ref());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
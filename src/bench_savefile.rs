@@ -27,13 +27,20 @@ where
         })
     });
 
-    crate::bench_size(name, "savefile", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |mut writer, data| savefile::save_noschema(&mut writer, 0, data).unwrap(),
+        |mut reader| savefile::load_noschema(&mut reader, 0).unwrap(),
+    );
 
     assert!(
         savefile::load_noschema::<T>(&mut Cursor::new(&deserialize_buffer), 0).unwrap() == *data
     );
 
     group.finish();
+
+    crate::bench_size(c, name, "savefile", deserialize_buffer.as_slice());
 }
 
 // savefile does not support structs which contain references at all, and so cannot perform borrowed
@@ -27,11 +27,18 @@ where
         })
     });
 
-    crate::bench_size(name, "serde_bare", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| serde_bare::to_writer(writer, data).unwrap(),
+        |reader| serde_bare::from_reader(reader).unwrap(),
+    );
 
     assert!(serde_bare::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "serde_bare", deserialize_buffer.as_slice());
 }
 
 // serde_bare does not seem to have support for borrowed decoding.
@@ -28,11 +28,18 @@ where
         })
     });
 
-    crate::bench_size(name, "serde_cbor", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| serde_cbor::to_writer(writer, data).unwrap(),
+        |reader| serde_cbor::from_reader(reader).unwrap(),
+    );
 
     assert!(serde_cbor::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "serde_cbor", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
@@ -27,11 +27,18 @@ where
         })
     });
 
-    crate::bench_size(name, "serde_json", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| serde_json::to_writer(writer, data).unwrap(),
+        |reader| serde_json::from_reader(reader).unwrap(),
+    );
 
     assert!(serde_json::from_slice::<T>(&deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "serde_json", deserialize_buffer.as_slice());
 }
 
 // serde_json does not support borrowed decoding.
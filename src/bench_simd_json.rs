@@ -36,7 +36,18 @@ where
         )
     });
 
-    crate::bench_size(name, "simd-json", deserialize_buffer.as_slice());
+    // simd-json parses in place, so like `simd_json::from_reader` this reads everything first
+    let mut read_buffer = Vec::with_capacity(BUFFER_LEN);
+    crate::bench_io(
+        &mut group,
+        data,
+        |mut writer, data| data.json_write(&mut writer).unwrap(),
+        |reader| {
+            read_buffer.clear();
+            reader.read_to_end(&mut read_buffer).unwrap();
+            T::from_slice_with_buffers(read_buffer.as_mut_slice(), &mut buffers).unwrap()
+        },
+    );
 
     assert!(
         T::from_slice_with_buffers(deserialize_buffer.clone().as_mut_slice(), &mut buffers)
@@ -45,6 +56,8 @@ where
     );
 
     group.finish();
+
+    crate::bench_size(c, name, "simd-json", deserialize_buffer.as_slice());
 }
 
 // simd_json does not support borrowed decoding.
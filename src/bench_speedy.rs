@@ -3,9 +3,9 @@ use criterion::{black_box, Criterion};
 use speedy::{Endianness, Readable, Writable};
 
 #[cfg(target_endian = "little")]
-const CONTEXT: Endianness = Endianness::LittleEndian;
+pub const CONTEXT: Endianness = Endianness::LittleEndian;
 #[cfg(target_endian = "big")]
-const CONTEXT: Endianness = Endianness::BigEndian;
+pub const CONTEXT: Endianness = Endianness::BigEndian;
 
 pub fn bench<T>(name: &'static str, c: &mut Criterion, data: &T)
 where
@@ -35,11 +35,18 @@ where
         })
     });
 
-    crate::bench_size(name, "speedy", deserialize_buffer.as_slice());
+    crate::bench_io(
+        &mut group,
+        data,
+        |writer, data| data.write_to_stream_with_ctx(CONTEXT, writer).unwrap(),
+        |reader| T::read_from_stream_unbuffered_with_ctx(CONTEXT, reader).unwrap(),
+    );
 
     assert!(T::read_from_buffer_with_ctx(CONTEXT, &deserialize_buffer).unwrap() == *data);
 
     group.finish();
+
+    crate::bench_size(c, name, "speedy", deserialize_buffer.as_slice());
 }
 
 pub fn bench_borrowable<T>(name: &'static str, c: &mut Criterion, data: &T)
@@ -0,0 +1,690 @@
+//! Single-value encoding and decoding for the libraries that take part in correctness checks.
+//!
+//! The benchmark modules are written for speed and each has its own shape, which makes them
+//! unsuitable for checks that need to run the same experiment against many libraries. Each library
+//! here gets a marker type which implements [`Codec`] for every type it can round-trip, and
+//! [`Encode`] for every type it can write. Schema-based libraries can write more types than they can
+//! read back into owned values.
+
+use core::fmt;
+#[allow(unused_imports)]
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Whether a library documents a canonical encoding.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CanonicalMode {
+    /// Every encoding is canonical.
+    Always,
+    /// Canonical encodings are available through a separate API or configuration.
+    OptIn,
+    /// No canonical encoding is documented.
+    None,
+}
+
+impl fmt::Display for CanonicalMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Always => "always",
+            Self::OptIn => "opt-in",
+            Self::None => "none",
+        })
+    }
+}
+
+pub trait Encode<T> {
+    /// Whether the library documents a canonical encoding, either always or as an option.
+    const CANONICAL_MODE: CanonicalMode = CanonicalMode::None;
+
+    fn encode(value: &T) -> Vec<u8>;
+}
+
+pub trait Codec<T>: Encode<T> {
+    fn decode(bytes: &[u8]) -> Result<T, String>;
+}
+
+/// Encodes a value, treating panics during encoding as errors. Libraries panic here when they
+/// don't support a type at all.
+pub fn try_encode<C: Encode<T>, T>(value: &T) -> Result<Vec<u8>, String> {
+    catch_panic(|| Ok(C::encode(value)))
+}
+
+/// Decodes a value, treating panics during decoding as errors. Some conversions out of generated
+/// types unwrap required fields, so a panic is a legitimate way to reject data.
+pub fn try_decode<C: Codec<T>, T>(bytes: &[u8]) -> Result<T, String> {
+    catch_panic(|| C::decode(bytes))
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    result.unwrap_or_else(|_| Err("panicked".to_owned()))
+}
+
+/// Calls `$callback::<Codec>(lib, args...)` once for every enabled library whose [`Codec`] works
+/// with the derives shared by all of the check types. The libraries in [`for_each_other_codec`]
+/// and the zero-copy libraries have codecs too, but are left out because they need per-type
+/// support.
+macro_rules! for_each_codec {
+    ($callback:ident, $($arg:expr),* $(,)?) => {
+        #[cfg(feature = "bilrost")]
+        $callback::<$crate::codec::Bilrost>("bilrost", $($arg),*);
+        #[cfg(feature = "bincode")]
+        $callback::<$crate::codec::Bincode>("bincode", $($arg),*);
+        #[cfg(feature = "bincode1")]
+        $callback::<$crate::codec::Bincode1>("bincode1", $($arg),*);
+        #[cfg(feature = "borsh")]
+        $callback::<$crate::codec::Borsh>("borsh", $($arg),*);
+        #[cfg(feature = "cbor4ii")]
+        $callback::<$crate::codec::Cbor4ii>("cbor4ii", $($arg),*);
+        #[cfg(feature = "ciborium")]
+        $callback::<$crate::codec::Ciborium>("ciborium", $($arg),*);
+        #[cfg(feature = "dlhn")]
+        $callback::<$crate::codec::Dlhn>("dlhn", $($arg),*);
+        #[cfg(feature = "flexbuffers")]
+        $callback::<$crate::codec::Flexbuffers>("flexbuffers", $($arg),*);
+        #[cfg(feature = "minicbor")]
+        $callback::<$crate::codec::Minicbor>("minicbor", $($arg),*);
+        #[cfg(feature = "nachricht-serde")]
+        $callback::<$crate::codec::NachrichtSerde>("nachricht-serde", $($arg),*);
+        #[cfg(feature = "pot")]
+        $callback::<$crate::codec::Pot>("pot", $($arg),*);
+        #[cfg(feature = "postcard")]
+        $callback::<$crate::codec::Postcard>("postcard", $($arg),*);
+        #[cfg(feature = "prost")]
+        $callback::<$crate::codec::Prost>("prost", $($arg),*);
+        #[cfg(feature = "rmp-serde")]
+        $callback::<$crate::codec::RmpSerde>("rmp-serde", $($arg),*);
+        #[cfg(feature = "ron")]
+        $callback::<$crate::codec::Ron>("ron", $($arg),*);
+        #[cfg(feature = "serde_bare")]
+        $callback::<$crate::codec::SerdeBare>("serde_bare", $($arg),*);
+        #[cfg(feature = "serde-brief")]
+        $callback::<$crate::codec::SerdeBrief>("serde-brief", $($arg),*);
+        #[cfg(feature = "serde_cbor")]
+        $callback::<$crate::codec::SerdeCbor>("serde_cbor", $($arg),*);
+        #[cfg(feature = "serde_json")]
+        $callback::<$crate::codec::SerdeJson>("serde_json", $($arg),*);
+    };
+}
+
+pub(crate) use for_each_codec;
+
+/// Like [`for_each_codec`], for the libraries that can round-trip the datasets but not the check
+/// types, which don't derive their traits.
+macro_rules! for_each_other_codec {
+    ($callback:ident, $($arg:expr),* $(,)?) => {
+        #[cfg(feature = "bitcode")]
+        $callback::<$crate::codec::Bitcode>("bitcode", $($arg),*);
+        #[cfg(feature = "databuf")]
+        $callback::<$crate::codec::Databuf>("databuf", $($arg),*);
+        #[cfg(feature = "msgpacker")]
+        $callback::<$crate::codec::Msgpacker>("msgpacker", $($arg),*);
+        #[cfg(feature = "nanoserde")]
+        $callback::<$crate::codec::Nanoserde>("nanoserde", $($arg),*);
+        #[cfg(feature = "protobuf")]
+        $callback::<$crate::codec::Protobuf>("protobuf", $($arg),*);
+        #[cfg(feature = "savefile")]
+        $callback::<$crate::codec::Savefile>("savefile", $($arg),*);
+        #[cfg(feature = "scale")]
+        $callback::<$crate::codec::Scale>("scale", $($arg),*);
+        #[cfg(feature = "simd-json")]
+        $callback::<$crate::codec::SimdJson>("simd-json", $($arg),*);
+        #[cfg(feature = "speedy")]
+        $callback::<$crate::codec::Speedy>("speedy", $($arg),*);
+        #[cfg(feature = "wiring")]
+        $callback::<$crate::codec::Wiring>("wiring", $($arg),*);
+    };
+}
+
+pub(crate) use for_each_other_codec;
+
+macro_rules! serde_codec {
+    ($feature:literal, $codec:ident, |$value:ident| $encode:expr, |$bytes:ident| $decode:expr $(,)?) => {
+        #[cfg(feature = $feature)]
+        pub struct $codec;
+
+        #[cfg(feature = $feature)]
+        impl<T: Serialize> Encode<T> for $codec {
+            fn encode($value: &T) -> Vec<u8> {
+                $encode
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: Serialize + DeserializeOwned> Codec<T> for $codec {
+            fn decode($bytes: &[u8]) -> Result<T, String> {
+                $decode.map_err(|e| e.to_string())
+            }
+        }
+    };
+}
+
+serde_codec!(
+    "bincode1",
+    Bincode1,
+    |value| bincode1::serialize(value).unwrap(),
+    |bytes| bincode1::deserialize::<T>(bytes),
+);
+
+serde_codec!(
+    "cbor4ii",
+    Cbor4ii,
+    |value| {
+        let mut buffer = Vec::new();
+        cbor4ii::serde::to_writer(&mut buffer, value).unwrap();
+        buffer
+    },
+    |bytes| cbor4ii::serde::from_slice::<T>(bytes),
+);
+
+serde_codec!(
+    "ciborium",
+    Ciborium,
+    |value| {
+        let mut buffer = Vec::new();
+        ciborium::ser::into_writer(value, &mut buffer).unwrap();
+        buffer
+    },
+    |bytes| ciborium::de::from_reader::<T, _>(bytes),
+);
+
+serde_codec!(
+    "dlhn",
+    Dlhn,
+    |value| {
+        let mut buffer = Vec::new();
+        value
+            .serialize(&mut dlhn::ser::Serializer::new(&mut buffer))
+            .unwrap();
+        buffer
+    },
+    |bytes| T::deserialize(&mut dlhn::de::Deserializer::new(&mut &*bytes)),
+);
+
+serde_codec!(
+    "flexbuffers",
+    Flexbuffers,
+    |value| {
+        let mut serializer = flexbuffers::FlexbufferSerializer::new();
+        value.serialize(&mut serializer).unwrap();
+        serializer.view().to_vec()
+    },
+    |bytes| flexbuffers::Reader::get_root(bytes)
+        .map_err(|e| e.to_string())
+        .and_then(|reader| T::deserialize(reader).map_err(|e| e.to_string())),
+);
+
+serde_codec!(
+    "nachricht-serde",
+    NachrichtSerde,
+    |value| {
+        let mut buffer = Vec::new();
+        nachricht_serde::to_writer(&mut buffer, value).unwrap();
+        buffer
+    },
+    |bytes| nachricht_serde::from_bytes::<T>(bytes),
+);
+
+serde_codec!("pot", Pot, |value| pot::to_vec(value).unwrap(), |bytes| {
+    pot::from_slice::<T>(bytes)
+},);
+
+serde_codec!(
+    "postcard",
+    Postcard,
+    |value| postcard::to_allocvec(value).unwrap(),
+    |bytes| postcard::from_bytes::<T>(bytes),
+);
+
+serde_codec!(
+    "rmp-serde",
+    RmpSerde,
+    |value| {
+        let mut buffer = Vec::new();
+        rmp_serde::encode::write(&mut buffer, value).unwrap();
+        buffer
+    },
+    |bytes| rmp_serde::from_slice::<T>(bytes),
+);
+
+serde_codec!(
+    "ron",
+    Ron,
+    |value| {
+        let mut buffer = String::new();
+        ron::ser::to_writer(&mut buffer, value).unwrap();
+        buffer.into_bytes()
+    },
+    |bytes| ron::de::from_bytes::<T>(bytes),
+);
+
+serde_codec!(
+    "serde_bare",
+    SerdeBare,
+    |value| {
+        let mut buffer = Vec::new();
+        serde_bare::to_writer(&mut buffer, value).unwrap();
+        buffer
+    },
+    |bytes| serde_bare::from_slice::<T>(bytes),
+);
+
+serde_codec!(
+    "serde-brief",
+    SerdeBrief,
+    |value| serde_brief::to_vec(value).unwrap(),
+    |bytes| serde_brief::from_slice::<T>(bytes),
+);
+
+serde_codec!(
+    "serde_cbor",
+    SerdeCbor,
+    |value| serde_cbor::to_vec(value).unwrap(),
+    |bytes| serde_cbor::from_slice::<T>(bytes),
+);
+
+serde_codec!(
+    "serde_json",
+    SerdeJson,
+    |value| serde_json::to_vec(value).unwrap(),
+    |bytes| serde_json::from_slice::<T>(bytes),
+);
+
+#[cfg(feature = "bilrost")]
+pub struct Bilrost;
+
+#[cfg(feature = "bilrost")]
+impl<T: bilrost::Message> Encode<T> for Bilrost {
+    // Bilrost's distinguished decoding (the "Distinguished decoding" section of its crate
+    // documentation) rejects non-canonical data, and messages that support it always encode
+    // canonically. It only covers `BTreeMap`, so the `HashMap` in the determinism checks can't
+    // exercise it.
+    const CANONICAL_MODE: CanonicalMode = CanonicalMode::OptIn;
+
+    fn encode(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        value.encode(&mut buffer).unwrap();
+        buffer
+    }
+}
+
+#[cfg(feature = "bilrost")]
+impl<T: bilrost::OwnedMessage> Codec<T> for Bilrost {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::decode(bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "bincode")]
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl<T: bincode::Encode> Encode<T> for Bincode {
+    fn encode(value: &T) -> Vec<u8> {
+        bincode::encode_to_vec(value, bincode::config::standard()).unwrap()
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<T: bincode::Encode + bincode::Decode<()>> Codec<T> for Bincode {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        // Misread lengths would otherwise be allocated up front and abort the process. The limit
+        // still leaves room for whole datasets.
+        let config = bincode::config::standard().with_limit::<{ 1 << 28 }>();
+        bincode::decode_from_slice(bytes, config)
+            .map(|(value, _)| value)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "bitcode")]
+pub struct Bitcode;
+
+#[cfg(feature = "bitcode")]
+impl<T: bitcode::Encode> Encode<T> for Bitcode {
+    fn encode(value: &T) -> Vec<u8> {
+        bitcode::encode(value)
+    }
+}
+
+#[cfg(feature = "bitcode")]
+impl<T: bitcode::Encode + bitcode::DecodeOwned> Codec<T> for Bitcode {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        bitcode::decode(bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "borsh")]
+pub struct Borsh;
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshSerialize> Encode<T> for Borsh {
+    // The borsh specification (https://borsh.io) requires maps to be written in key order, and
+    // `BorshSerialize` for `HashMap` sorts its entries before writing them
+    const CANONICAL_MODE: CanonicalMode = CanonicalMode::Always;
+
+    fn encode(value: &T) -> Vec<u8> {
+        borsh::to_vec(value).unwrap()
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshSerialize + borsh::BorshDeserialize> Codec<T> for Borsh {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::deserialize(&mut &*bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "capnp")]
+pub struct Capnp;
+
+#[cfg(feature = "capnp")]
+impl<T: for<'a> crate::bench_capnp::Serialize<'a>> Encode<T> for Capnp {
+    // The "Canonicalization" section of the Cap'n Proto encoding specification defines a canonical
+    // form, which `capnp::message::Reader::canonicalize` produces from any message
+    const CANONICAL_MODE: CanonicalMode = CanonicalMode::OptIn;
+
+    fn encode(value: &T) -> Vec<u8> {
+        let mut builder = capnp::message::Builder::new_default();
+        value.serialize_capnp(&mut builder.init_root());
+        capnp::serialize::write_message_to_words(&builder)
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<T: for<'a> crate::bench_capnp::Deserialize<'a>> Codec<T> for Capnp {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        let message = capnp::serialize::read_message(&mut &*bytes, Default::default())
+            .map_err(|e| e.to_string())?;
+        message
+            .get_root()
+            .and_then(T::deserialize_capnp)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "databuf")]
+pub struct Databuf;
+
+#[cfg(feature = "databuf")]
+impl<T: databuf::Encode> Encode<T> for Databuf {
+    fn encode(value: &T) -> Vec<u8> {
+        value.to_bytes::<{ databuf::config::num::LE }>()
+    }
+}
+
+#[cfg(feature = "databuf")]
+impl<T: databuf::Encode + for<'de> databuf::Decode<'de>> Codec<T> for Databuf {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::from_bytes::<{ databuf::config::num::LE }>(bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+pub struct Flatbuffers;
+
+#[cfg(feature = "flatbuffers")]
+impl<T: for<'a> crate::bench_flatbuffers::Serialize<'a>> Encode<T> for Flatbuffers {
+    fn encode(value: &T) -> Vec<u8> {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let root = value.serialize_fb(&mut fbb);
+        fbb.finish(root, None);
+        fbb.finished_data().to_vec()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<T: for<'a> crate::bench_flatbuffers::Deserialize<'a>> Codec<T> for Flatbuffers {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        use crate::bench_flatbuffers::Serialize;
+
+        flatbuffers::root::<<T as Serialize<'_>>::Target>(bytes)
+            .map(T::deserialize_fb)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "minicbor")]
+pub struct Minicbor;
+
+#[cfg(feature = "minicbor")]
+impl<T: minicbor::Encode<()>> Encode<T> for Minicbor {
+    fn encode(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        minicbor::encode(value, &mut buffer).unwrap();
+        buffer
+    }
+}
+
+#[cfg(feature = "minicbor")]
+impl<T: minicbor::Encode<()> + for<'de> minicbor::Decode<'de, ()>> Codec<T> for Minicbor {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        minicbor::decode::<T>(bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "nanoserde")]
+pub struct Nanoserde;
+
+#[cfg(feature = "nanoserde")]
+impl<T: nanoserde::SerBin> Encode<T> for Nanoserde {
+    fn encode(value: &T) -> Vec<u8> {
+        value.serialize_bin()
+    }
+}
+
+#[cfg(feature = "nanoserde")]
+impl<T: nanoserde::SerBin + nanoserde::DeBin> Codec<T> for Nanoserde {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::deserialize_bin(bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "prost")]
+pub struct Prost;
+
+#[cfg(feature = "prost")]
+impl<T: crate::bench_prost::Serialize> Encode<T> for Prost {
+    fn encode(value: &T) -> Vec<u8> {
+        use prost::Message;
+
+        value.serialize_pb().encode_to_vec()
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<T: crate::bench_prost::Serialize> Codec<T> for Prost {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        use prost::Message;
+
+        T::Message::decode(bytes)
+            .map(Into::into)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "msgpacker")]
+pub struct Msgpacker;
+
+#[cfg(feature = "msgpacker")]
+impl<T: msgpacker::Packable> Encode<T> for Msgpacker {
+    fn encode(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        value.pack(&mut buffer);
+        buffer
+    }
+}
+
+#[cfg(feature = "msgpacker")]
+impl<T> Codec<T> for Msgpacker
+where
+    T: msgpacker::Packable + msgpacker::Unpackable,
+    <T as msgpacker::Unpackable>::Error: core::fmt::Debug,
+{
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::unpack(bytes)
+            .map(|(_, value)| value)
+            .map_err(|e| format!("{:?}", e))
+    }
+}
+
+#[cfg(feature = "protobuf")]
+pub struct Protobuf;
+
+#[cfg(feature = "protobuf")]
+impl<T: crate::bench_protobuf::Serialize> Encode<T> for Protobuf {
+    fn encode(value: &T) -> Vec<u8> {
+        use protobuf::Message;
+
+        value.serialize_pb().write_to_bytes().unwrap()
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl<T: crate::bench_protobuf::Serialize> Codec<T> for Protobuf {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        use protobuf::Message;
+
+        T::Message::parse_from_bytes(bytes)
+            .map(Into::into)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+pub struct Rkyv;
+
+#[cfg(feature = "rkyv")]
+impl<T> Encode<T> for Rkyv
+where
+    T: for<'a> rkyv::Serialize<crate::bench_rkyv::BenchSerializer<'a>>,
+{
+    fn encode(value: &T) -> Vec<u8> {
+        rkyv::to_bytes(value).unwrap().to_vec()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T> Codec<T> for Rkyv
+where
+    T: rkyv::Archive + for<'a> rkyv::Serialize<crate::bench_rkyv::BenchSerializer<'a>>,
+    T::Archived: for<'a> rkyv::bytecheck::CheckBytes<crate::bench_rkyv::BenchValidator<'a>>
+        + rkyv::Deserialize<T, crate::bench_rkyv::BenchDeserializer>,
+{
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        // rkyv needs the buffer to be aligned for its archived types
+        let mut buffer = rkyv::util::AlignedVec::<16>::with_capacity(bytes.len());
+        buffer.extend_from_slice(bytes);
+        rkyv::from_bytes::<T, rkyv::rancor::Failure>(&buffer).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "savefile")]
+pub struct Savefile;
+
+#[cfg(feature = "savefile")]
+impl<T: savefile::Serialize + savefile::WithSchema> Encode<T> for Savefile {
+    fn encode(value: &T) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        savefile::save_noschema(&mut buffer, 0, value).unwrap();
+        buffer
+    }
+}
+
+#[cfg(feature = "savefile")]
+impl<T> Codec<T> for Savefile
+where
+    T: savefile::Serialize + savefile::Deserialize + savefile::WithSchema,
+{
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        savefile::load_noschema(&mut &*bytes, 0).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "scale")]
+pub struct Scale;
+
+#[cfg(feature = "scale")]
+impl<T: parity_scale_codec::Encode> Encode<T> for Scale {
+    fn encode(value: &T) -> Vec<u8> {
+        value.encode()
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<T: parity_scale_codec::Encode + parity_scale_codec::Decode> Codec<T> for Scale {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::decode(&mut &*bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "simd-json")]
+pub struct SimdJson;
+
+#[cfg(feature = "simd-json")]
+impl<T: simd_json_derive::Serialize> Encode<T> for SimdJson {
+    fn encode(value: &T) -> Vec<u8> {
+        value.json_vec().unwrap()
+    }
+}
+
+#[cfg(feature = "simd-json")]
+impl<T> Codec<T> for SimdJson
+where
+    T: simd_json_derive::Serialize + for<'de> simd_json_derive::Deserialize<'de>,
+{
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        // simd-json parses in place
+        let mut buffer = bytes.to_vec();
+        T::from_slice(&mut buffer).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "speedy")]
+pub struct Speedy;
+
+#[cfg(feature = "speedy")]
+impl<T: speedy::Writable<speedy::Endianness>> Encode<T> for Speedy {
+    fn encode(value: &T) -> Vec<u8> {
+        value
+            .write_to_vec_with_ctx(crate::bench_speedy::CONTEXT)
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "speedy")]
+impl<T> Codec<T> for Speedy
+where
+    T: speedy::Writable<speedy::Endianness> + for<'a> speedy::Readable<'a, speedy::Endianness>,
+{
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        T::read_from_buffer_with_ctx(crate::bench_speedy::CONTEXT, bytes).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "wiring")]
+pub struct Wiring;
+
+#[cfg(feature = "wiring")]
+impl<T: wiring::prelude::Wiring> Encode<T> for Wiring {
+    fn encode(value: &T) -> Vec<u8> {
+        use wiring::prelude::BufWire;
+
+        let mut buffer = Vec::new();
+        BufWire::new(&mut buffer).wire(value).unwrap();
+        buffer
+    }
+}
+
+#[cfg(feature = "wiring")]
+impl<T: wiring::prelude::Wiring + wiring::prelude::Unwiring> Codec<T> for Wiring {
+    fn decode(bytes: &[u8]) -> Result<T, String> {
+        use wiring::prelude::BufUnWire;
+
+        BufUnWire::new(bytes).unwire().map_err(|e| e.to_string())
+    }
+}
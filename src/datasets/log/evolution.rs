@@ -0,0 +1,663 @@
+//! Newer versions of [`Log`] for the schema evolution checks.
+
+#[cfg(feature = "flatbuffers")]
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+#[cfg(feature = "capnp")]
+use super::log_evolution_capnp as cp_v2;
+#[cfg(feature = "flatbuffers")]
+use super::log_evolution_fb::log_evolution as fb_v2;
+#[cfg(feature = "prost")]
+use super::log_prost::evolution as pb_v2;
+#[cfg(feature = "protobuf")]
+use super::log_protobuf::log_evolution as rpb_v2;
+use super::{Address, Log, Logs};
+#[cfg(feature = "capnp")]
+use crate::bench_capnp;
+#[cfg(feature = "flatbuffers")]
+use crate::bench_flatbuffers;
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+use crate::codec::{for_each_codec, Codec};
+use crate::evolution::{self, Change, Evolution};
+
+/// [`Log`] with an optional `referrer` field added at the end.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LogAddField {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub address: Address,
+    #[cfg_attr(feature = "minicbor", b(1))]
+    pub identity: String,
+    #[cfg_attr(feature = "minicbor", b(2))]
+    pub userid: String,
+    #[cfg_attr(feature = "minicbor", b(3))]
+    pub date: String,
+    #[cfg_attr(feature = "minicbor", b(4))]
+    pub request: String,
+    #[cfg_attr(feature = "minicbor", n(5))]
+    pub code: u16,
+    #[cfg_attr(feature = "minicbor", n(6))]
+    pub size: u64,
+    #[cfg_attr(feature = "minicbor", b(7))]
+    pub referrer: Option<String>,
+}
+
+impl Evolution<Log> for LogAddField {
+    const CHANGE: Change = Change::AddField;
+
+    fn upgrade(old: &Log) -> Self {
+        Self {
+            address: old.address,
+            identity: old.identity.clone(),
+            userid: old.userid.clone(),
+            date: old.date.clone(),
+            request: old.request.clone(),
+            code: old.code,
+            size: old.size,
+            referrer: Some("https://www.rust-lang.org/".to_owned()),
+        }
+    }
+
+    fn agrees(&self, old: &Log) -> bool {
+        self.address == old.address
+            && self.identity == old.identity
+            && self.userid == old.userid
+            && self.date == old.date
+            && self.request == old.request
+            && self.code == old.code
+            && self.size == old.size
+    }
+}
+
+/// [`Log`] with the `identity` field removed.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LogRemoveField {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub address: Address,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", b(2))]
+    pub userid: String,
+    #[cfg_attr(feature = "minicbor", b(3))]
+    pub date: String,
+    #[cfg_attr(feature = "minicbor", b(4))]
+    pub request: String,
+    #[cfg_attr(feature = "minicbor", n(5))]
+    pub code: u16,
+    #[cfg_attr(feature = "minicbor", n(6))]
+    pub size: u64,
+}
+
+impl Evolution<Log> for LogRemoveField {
+    const CHANGE: Change = Change::RemoveField;
+
+    fn upgrade(old: &Log) -> Self {
+        Self {
+            address: old.address,
+            userid: old.userid.clone(),
+            date: old.date.clone(),
+            request: old.request.clone(),
+            code: old.code,
+            size: old.size,
+        }
+    }
+
+    fn agrees(&self, old: &Log) -> bool {
+        self.address == old.address
+            && self.userid == old.userid
+            && self.date == old.date
+            && self.request == old.request
+            && self.code == old.code
+            && self.size == old.size
+    }
+}
+
+/// [`Log`] with the numeric fields moved to the front. Tagged formats keep the original tags.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LogReorderFields {
+    #[cfg_attr(feature = "bilrost", bilrost(6))]
+    #[cfg_attr(feature = "minicbor", n(5))]
+    pub code: u16,
+    #[cfg_attr(feature = "bilrost", bilrost(7))]
+    #[cfg_attr(feature = "minicbor", n(6))]
+    pub size: u64,
+    #[cfg_attr(feature = "bilrost", bilrost(1))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub address: Address,
+    #[cfg_attr(feature = "bilrost", bilrost(2))]
+    #[cfg_attr(feature = "minicbor", b(1))]
+    pub identity: String,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", b(2))]
+    pub userid: String,
+    #[cfg_attr(feature = "bilrost", bilrost(4))]
+    #[cfg_attr(feature = "minicbor", b(3))]
+    pub date: String,
+    #[cfg_attr(feature = "bilrost", bilrost(5))]
+    #[cfg_attr(feature = "minicbor", b(4))]
+    pub request: String,
+}
+
+impl Evolution<Log> for LogReorderFields {
+    const CHANGE: Change = Change::ReorderFields;
+
+    fn upgrade(old: &Log) -> Self {
+        Self {
+            code: old.code,
+            size: old.size,
+            address: old.address,
+            identity: old.identity.clone(),
+            userid: old.userid.clone(),
+            date: old.date.clone(),
+            request: old.request.clone(),
+        }
+    }
+
+    fn agrees(&self, old: &Log) -> bool {
+        self.address == old.address
+            && self.identity == old.identity
+            && self.userid == old.userid
+            && self.date == old.date
+            && self.request == old.request
+            && self.code == old.code
+            && self.size == old.size
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for LogAddField {
+    type Reader = cp_v2::log_add_field::Reader<'a>;
+    type Builder = cp_v2::log_add_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        self.address
+            .serialize_capnp(&mut builder.reborrow().init_address());
+        builder.set_identity(Reader(self.identity.as_bytes()));
+        builder.set_userid(Reader(self.userid.as_bytes()));
+        builder.set_date(Reader(self.date.as_bytes()));
+        builder.set_request(Reader(self.request.as_bytes()));
+        builder.set_code(self.code);
+        builder.set_size(self.size);
+        let mut referrer = builder.reborrow().init_referrer();
+        if let Some(ref value) = self.referrer {
+            referrer.set_some(Reader(value.as_bytes()));
+        } else {
+            referrer.set_none(());
+        }
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for LogAddField {
+    fn deserialize_capnp(log: Self::Reader) -> capnp::Result<Self> {
+        use cp_v2::log_add_field::referrer;
+
+        Ok(LogAddField {
+            address: Address::deserialize_capnp(log.get_address()?)?,
+            identity: log.get_identity()?.to_string()?,
+            userid: log.get_userid()?.to_string()?,
+            date: log.get_date()?.to_string()?,
+            request: log.get_request()?.to_string()?,
+            code: log.get_code(),
+            size: log.get_size(),
+            referrer: match log.get_referrer().which()? {
+                referrer::None(()) => None,
+                referrer::Some(value) => Some(value?.to_string()?),
+            },
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for LogRemoveField {
+    type Reader = cp_v2::log_remove_field::Reader<'a>;
+    type Builder = cp_v2::log_remove_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        self.address
+            .serialize_capnp(&mut builder.reborrow().init_address());
+        builder.set_userid(Reader(self.userid.as_bytes()));
+        builder.set_date(Reader(self.date.as_bytes()));
+        builder.set_request(Reader(self.request.as_bytes()));
+        builder.set_code(self.code);
+        builder.set_size(self.size);
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for LogRemoveField {
+    fn deserialize_capnp(log: Self::Reader) -> capnp::Result<Self> {
+        Ok(LogRemoveField {
+            address: Address::deserialize_capnp(log.get_address()?)?,
+            userid: log.get_userid()?.to_string()?,
+            date: log.get_date()?.to_string()?,
+            request: log.get_request()?.to_string()?,
+            code: log.get_code(),
+            size: log.get_size(),
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for LogReorderFields {
+    type Reader = cp_v2::log_reorder_fields::Reader<'a>;
+    type Builder = cp_v2::log_reorder_fields::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        builder.set_code(self.code);
+        builder.set_size(self.size);
+        self.address
+            .serialize_capnp(&mut builder.reborrow().init_address());
+        builder.set_identity(Reader(self.identity.as_bytes()));
+        builder.set_userid(Reader(self.userid.as_bytes()));
+        builder.set_date(Reader(self.date.as_bytes()));
+        builder.set_request(Reader(self.request.as_bytes()));
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for LogReorderFields {
+    fn deserialize_capnp(log: Self::Reader) -> capnp::Result<Self> {
+        Ok(LogReorderFields {
+            code: log.get_code(),
+            size: log.get_size(),
+            address: Address::deserialize_capnp(log.get_address()?)?,
+            identity: log.get_identity()?.to_string()?,
+            userid: log.get_userid()?.to_string()?,
+            date: log.get_date()?.to_string()?,
+            request: log.get_request()?.to_string()?,
+        })
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl From<Address> for fb_v2::Address {
+    fn from(value: Address) -> Self {
+        Self::new(value.x0, value.x1, value.x2, value.x3)
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl From<&fb_v2::Address> for Address {
+    fn from(value: &fb_v2::Address) -> Self {
+        Address {
+            x0: value.x0(),
+            x1: value.x1(),
+            x2: value.x2(),
+            x3: value.x3(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for LogAddField {
+    type Target = fb_v2::LogAddField<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let address = self.address.into();
+
+        let identity = fbb.create_string(&self.identity);
+        let userid = fbb.create_string(&self.userid);
+        let date = fbb.create_string(&self.date);
+        let request = fbb.create_string(&self.request);
+        let referrer = self
+            .referrer
+            .as_ref()
+            .map(|referrer| fbb.create_string(referrer));
+
+        let mut builder = fb_v2::LogAddFieldBuilder::new(fbb);
+        builder.add_address(&address);
+        builder.add_identity(identity);
+        builder.add_userid(userid);
+        builder.add_date(date);
+        builder.add_request(request);
+        builder.add_code(self.code);
+        builder.add_size(self.size);
+        if let Some(referrer) = referrer {
+            builder.add_referrer(referrer);
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for LogAddField {
+    fn deserialize_fb(log: fb_v2::LogAddField<'a>) -> Self {
+        LogAddField {
+            address: log.address().unwrap().into(),
+            identity: log.identity().to_owned(),
+            userid: log.userid().to_owned(),
+            date: log.date().to_owned(),
+            request: log.request().to_owned(),
+            code: log.code(),
+            size: log.size(),
+            referrer: log.referrer().map(str::to_owned),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for LogRemoveField {
+    type Target = fb_v2::LogRemoveField<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let address = self.address.into();
+
+        let userid = fbb.create_string(&self.userid);
+        let date = fbb.create_string(&self.date);
+        let request = fbb.create_string(&self.request);
+
+        let mut builder = fb_v2::LogRemoveFieldBuilder::new(fbb);
+        builder.add_address(&address);
+        builder.add_userid(userid);
+        builder.add_date(date);
+        builder.add_request(request);
+        builder.add_code(self.code);
+        builder.add_size(self.size);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for LogRemoveField {
+    fn deserialize_fb(log: fb_v2::LogRemoveField<'a>) -> Self {
+        LogRemoveField {
+            address: log.address().unwrap().into(),
+            userid: log.userid().to_owned(),
+            date: log.date().to_owned(),
+            request: log.request().to_owned(),
+            code: log.code(),
+            size: log.size(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for LogReorderFields {
+    type Target = fb_v2::LogReorderFields<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let address = self.address.into();
+
+        let identity = fbb.create_string(&self.identity);
+        let userid = fbb.create_string(&self.userid);
+        let date = fbb.create_string(&self.date);
+        let request = fbb.create_string(&self.request);
+
+        let mut builder = fb_v2::LogReorderFieldsBuilder::new(fbb);
+        builder.add_code(self.code);
+        builder.add_size(self.size);
+        builder.add_address(&address);
+        builder.add_identity(identity);
+        builder.add_userid(userid);
+        builder.add_date(date);
+        builder.add_request(request);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for LogReorderFields {
+    fn deserialize_fb(log: fb_v2::LogReorderFields<'a>) -> Self {
+        LogReorderFields {
+            code: log.code(),
+            size: log.size(),
+            address: log.address().unwrap().into(),
+            identity: log.identity().to_owned(),
+            userid: log.userid().to_owned(),
+            date: log.date().to_owned(),
+            request: log.request().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for LogAddField {
+    type Message = pb_v2::LogAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            address: Some(self.address.serialize_pb()),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code as u32,
+            size: self.size,
+            referrer: self.referrer.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::LogAddField> for LogAddField {
+    fn from(value: pb_v2::LogAddField) -> Self {
+        LogAddField {
+            address: value.address.unwrap().into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+            referrer: value.referrer,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for LogRemoveField {
+    type Message = pb_v2::LogRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            address: Some(self.address.serialize_pb()),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code as u32,
+            size: self.size,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::LogRemoveField> for LogRemoveField {
+    fn from(value: pb_v2::LogRemoveField) -> Self {
+        LogRemoveField {
+            address: value.address.unwrap().into(),
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for LogReorderFields {
+    type Message = pb_v2::LogReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            code: self.code as u32,
+            size: self.size,
+            address: Some(self.address.serialize_pb()),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::LogReorderFields> for LogReorderFields {
+    fn from(value: pb_v2::LogReorderFields) -> Self {
+        LogReorderFields {
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+            address: value.address.unwrap().into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for LogAddField {
+    type Message = rpb_v2::LogAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            address: protobuf::MessageField::some(self.address.serialize_pb()),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code as u32,
+            size: self.size,
+            referrer: self.referrer.clone(),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::LogAddField> for LogAddField {
+    fn from(value: rpb_v2::LogAddField) -> Self {
+        LogAddField {
+            address: value.address.unwrap().into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+            referrer: value.referrer,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for LogRemoveField {
+    type Message = rpb_v2::LogRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            address: protobuf::MessageField::some(self.address.serialize_pb()),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            code: self.code as u32,
+            size: self.size,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::LogRemoveField> for LogRemoveField {
+    fn from(value: rpb_v2::LogRemoveField) -> Self {
+        LogRemoveField {
+            address: value.address.unwrap().into(),
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for LogReorderFields {
+    type Message = rpb_v2::LogReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            code: self.code as u32,
+            size: self.size,
+            address: protobuf::MessageField::some(self.address.serialize_pb()),
+            identity: self.identity.clone(),
+            userid: self.userid.clone(),
+            date: self.date.clone(),
+            request: self.request.clone(),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::LogReorderFields> for LogReorderFields {
+    fn from(value: rpb_v2::LogReorderFields) -> Self {
+        LogReorderFields {
+            code: value.code.try_into().unwrap(),
+            size: value.size,
+            address: value.address.unwrap().into(),
+            identity: value.identity,
+            userid: value.userid,
+            date: value.date,
+            request: value.request,
+        }
+    }
+}
+
+pub fn check(name: &str, data: &Logs) {
+    for_each_codec!(check_codec, name, &data.logs);
+    #[cfg(feature = "capnp")]
+    check_codec::<crate::codec::Capnp>("capnp", name, &data.logs);
+    #[cfg(feature = "flatbuffers")]
+    check_codec::<crate::codec::Flatbuffers>("flatbuffers", name, &data.logs);
+    #[cfg(feature = "protobuf")]
+    check_codec::<crate::codec::Protobuf>("protobuf", name, &data.logs);
+}
+
+fn check_codec<C>(lib: &str, name: &str, logs: &[Log])
+where
+    C: Codec<Log> + Codec<LogAddField> + Codec<LogRemoveField> + Codec<LogReorderFields>,
+{
+    evolution::check::<C, Log, LogAddField>(name, lib, logs);
+    evolution::check::<C, Log, LogRemoveField>(name, lib, logs);
+    evolution::check::<C, Log, LogReorderFields>(name, lib, logs);
+}
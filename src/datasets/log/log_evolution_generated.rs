@@ -0,0 +1,822 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod log_evolution {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+// struct Address, aligned to 1
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Address(pub [u8; 4]);
+impl Default for Address { 
+  fn default() -> Self { 
+    Self([0; 4])
+  }
+}
+impl core::fmt::Debug for Address {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Address")
+      .field("x0", &self.x0())
+      .field("x1", &self.x1())
+      .field("x2", &self.x2())
+      .field("x3", &self.x3())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Address {}
+impl<'a> flatbuffers::Follow<'a> for Address {
+  type Inner = &'a Address;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a Address>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Address {
+  type Inner = &'a Address;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<Address>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for Address {
+    type Output = Address;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const Address as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(1)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Address {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Address {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    x0: u8,
+    x1: u8,
+    x2: u8,
+    x3: u8,
+  ) -> Self {
+    let mut s = Self([0; 4]);
+    s.set_x0(x0);
+    s.set_x1(x1);
+    s.set_x2(x2);
+    s.set_x3(x3);
+    s
+  }
+
+  pub fn x0(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x0(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn x1(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[1..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x1(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[1..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn x2(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[2..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x2(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[2..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn x3(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[3..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x3(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[3..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum LogAddFieldOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LogAddField<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LogAddField<'a> {
+  type Inner = LogAddField<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LogAddField<'a> {
+  pub const VT_ADDRESS: flatbuffers::VOffsetT = 4;
+  pub const VT_IDENTITY: flatbuffers::VOffsetT = 6;
+  pub const VT_USERID: flatbuffers::VOffsetT = 8;
+  pub const VT_DATE: flatbuffers::VOffsetT = 10;
+  pub const VT_REQUEST: flatbuffers::VOffsetT = 12;
+  pub const VT_CODE: flatbuffers::VOffsetT = 14;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 16;
+  pub const VT_REFERRER: flatbuffers::VOffsetT = 18;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LogAddField { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LogAddFieldArgs<'args>
+  ) -> flatbuffers::WIPOffset<LogAddField<'bldr>> {
+    let mut builder = LogAddFieldBuilder::new(_fbb);
+    builder.add_size(args.size);
+    if let Some(x) = args.referrer { builder.add_referrer(x); }
+    if let Some(x) = args.request { builder.add_request(x); }
+    if let Some(x) = args.date { builder.add_date(x); }
+    if let Some(x) = args.userid { builder.add_userid(x); }
+    if let Some(x) = args.identity { builder.add_identity(x); }
+    if let Some(x) = args.address { builder.add_address(x); }
+    builder.add_code(args.code);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn address(&self) -> Option<&'a Address> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Address>(LogAddField::VT_ADDRESS, None)}
+  }
+  #[inline]
+  pub fn identity(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogAddField::VT_IDENTITY, None).unwrap()}
+  }
+  #[inline]
+  pub fn userid(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogAddField::VT_USERID, None).unwrap()}
+  }
+  #[inline]
+  pub fn date(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogAddField::VT_DATE, None).unwrap()}
+  }
+  #[inline]
+  pub fn request(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogAddField::VT_REQUEST, None).unwrap()}
+  }
+  #[inline]
+  pub fn code(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(LogAddField::VT_CODE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn size(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(LogAddField::VT_SIZE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn referrer(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogAddField::VT_REFERRER, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for LogAddField<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Address>("address", Self::VT_ADDRESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("identity", Self::VT_IDENTITY, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("userid", Self::VT_USERID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("date", Self::VT_DATE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("request", Self::VT_REQUEST, true)?
+     .visit_field::<u16>("code", Self::VT_CODE, false)?
+     .visit_field::<u64>("size", Self::VT_SIZE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("referrer", Self::VT_REFERRER, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LogAddFieldArgs<'a> {
+    pub address: Option<&'a Address>,
+    pub identity: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub userid: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub date: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub request: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub code: u16,
+    pub size: u64,
+    pub referrer: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for LogAddFieldArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LogAddFieldArgs {
+      address: None,
+      identity: None, // required field
+      userid: None, // required field
+      date: None, // required field
+      request: None, // required field
+      code: 0,
+      size: 0,
+      referrer: None,
+    }
+  }
+}
+
+pub struct LogAddFieldBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LogAddFieldBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_address(&mut self, address: &Address) {
+    self.fbb_.push_slot_always::<&Address>(LogAddField::VT_ADDRESS, address);
+  }
+  #[inline]
+  pub fn add_identity(&mut self, identity: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogAddField::VT_IDENTITY, identity);
+  }
+  #[inline]
+  pub fn add_userid(&mut self, userid: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogAddField::VT_USERID, userid);
+  }
+  #[inline]
+  pub fn add_date(&mut self, date: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogAddField::VT_DATE, date);
+  }
+  #[inline]
+  pub fn add_request(&mut self, request: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogAddField::VT_REQUEST, request);
+  }
+  #[inline]
+  pub fn add_code(&mut self, code: u16) {
+    self.fbb_.push_slot::<u16>(LogAddField::VT_CODE, code, 0);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: u64) {
+    self.fbb_.push_slot::<u64>(LogAddField::VT_SIZE, size, 0);
+  }
+  #[inline]
+  pub fn add_referrer(&mut self, referrer: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogAddField::VT_REFERRER, referrer);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LogAddFieldBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LogAddFieldBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LogAddField<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, LogAddField::VT_IDENTITY,"identity");
+    self.fbb_.required(o, LogAddField::VT_USERID,"userid");
+    self.fbb_.required(o, LogAddField::VT_DATE,"date");
+    self.fbb_.required(o, LogAddField::VT_REQUEST,"request");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LogAddField<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LogAddField");
+      ds.field("address", &self.address());
+      ds.field("identity", &self.identity());
+      ds.field("userid", &self.userid());
+      ds.field("date", &self.date());
+      ds.field("request", &self.request());
+      ds.field("code", &self.code());
+      ds.field("size", &self.size());
+      ds.field("referrer", &self.referrer());
+      ds.finish()
+  }
+}
+pub enum LogRemoveFieldOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LogRemoveField<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LogRemoveField<'a> {
+  type Inner = LogRemoveField<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LogRemoveField<'a> {
+  pub const VT_ADDRESS: flatbuffers::VOffsetT = 4;
+  pub const VT_USERID: flatbuffers::VOffsetT = 8;
+  pub const VT_DATE: flatbuffers::VOffsetT = 10;
+  pub const VT_REQUEST: flatbuffers::VOffsetT = 12;
+  pub const VT_CODE: flatbuffers::VOffsetT = 14;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LogRemoveField { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LogRemoveFieldArgs<'args>
+  ) -> flatbuffers::WIPOffset<LogRemoveField<'bldr>> {
+    let mut builder = LogRemoveFieldBuilder::new(_fbb);
+    builder.add_size(args.size);
+    if let Some(x) = args.request { builder.add_request(x); }
+    if let Some(x) = args.date { builder.add_date(x); }
+    if let Some(x) = args.userid { builder.add_userid(x); }
+    if let Some(x) = args.address { builder.add_address(x); }
+    builder.add_code(args.code);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn address(&self) -> Option<&'a Address> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Address>(LogRemoveField::VT_ADDRESS, None)}
+  }
+  #[inline]
+  pub fn userid(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogRemoveField::VT_USERID, None).unwrap()}
+  }
+  #[inline]
+  pub fn date(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogRemoveField::VT_DATE, None).unwrap()}
+  }
+  #[inline]
+  pub fn request(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogRemoveField::VT_REQUEST, None).unwrap()}
+  }
+  #[inline]
+  pub fn code(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(LogRemoveField::VT_CODE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn size(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(LogRemoveField::VT_SIZE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for LogRemoveField<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Address>("address", Self::VT_ADDRESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("userid", Self::VT_USERID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("date", Self::VT_DATE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("request", Self::VT_REQUEST, true)?
+     .visit_field::<u16>("code", Self::VT_CODE, false)?
+     .visit_field::<u64>("size", Self::VT_SIZE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LogRemoveFieldArgs<'a> {
+    pub address: Option<&'a Address>,
+    pub userid: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub date: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub request: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub code: u16,
+    pub size: u64,
+}
+impl<'a> Default for LogRemoveFieldArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LogRemoveFieldArgs {
+      address: None,
+      userid: None, // required field
+      date: None, // required field
+      request: None, // required field
+      code: 0,
+      size: 0,
+    }
+  }
+}
+
+pub struct LogRemoveFieldBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LogRemoveFieldBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_address(&mut self, address: &Address) {
+    self.fbb_.push_slot_always::<&Address>(LogRemoveField::VT_ADDRESS, address);
+  }
+  #[inline]
+  pub fn add_userid(&mut self, userid: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogRemoveField::VT_USERID, userid);
+  }
+  #[inline]
+  pub fn add_date(&mut self, date: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogRemoveField::VT_DATE, date);
+  }
+  #[inline]
+  pub fn add_request(&mut self, request: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogRemoveField::VT_REQUEST, request);
+  }
+  #[inline]
+  pub fn add_code(&mut self, code: u16) {
+    self.fbb_.push_slot::<u16>(LogRemoveField::VT_CODE, code, 0);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: u64) {
+    self.fbb_.push_slot::<u64>(LogRemoveField::VT_SIZE, size, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LogRemoveFieldBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LogRemoveFieldBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LogRemoveField<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, LogRemoveField::VT_USERID,"userid");
+    self.fbb_.required(o, LogRemoveField::VT_DATE,"date");
+    self.fbb_.required(o, LogRemoveField::VT_REQUEST,"request");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LogRemoveField<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LogRemoveField");
+      ds.field("address", &self.address());
+      ds.field("userid", &self.userid());
+      ds.field("date", &self.date());
+      ds.field("request", &self.request());
+      ds.field("code", &self.code());
+      ds.field("size", &self.size());
+      ds.finish()
+  }
+}
+pub enum LogReorderFieldsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct LogReorderFields<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for LogReorderFields<'a> {
+  type Inner = LogReorderFields<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> LogReorderFields<'a> {
+  pub const VT_ADDRESS: flatbuffers::VOffsetT = 4;
+  pub const VT_IDENTITY: flatbuffers::VOffsetT = 6;
+  pub const VT_USERID: flatbuffers::VOffsetT = 8;
+  pub const VT_DATE: flatbuffers::VOffsetT = 10;
+  pub const VT_REQUEST: flatbuffers::VOffsetT = 12;
+  pub const VT_CODE: flatbuffers::VOffsetT = 14;
+  pub const VT_SIZE: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    LogReorderFields { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args LogReorderFieldsArgs<'args>
+  ) -> flatbuffers::WIPOffset<LogReorderFields<'bldr>> {
+    let mut builder = LogReorderFieldsBuilder::new(_fbb);
+    builder.add_size(args.size);
+    if let Some(x) = args.request { builder.add_request(x); }
+    if let Some(x) = args.date { builder.add_date(x); }
+    if let Some(x) = args.userid { builder.add_userid(x); }
+    if let Some(x) = args.identity { builder.add_identity(x); }
+    if let Some(x) = args.address { builder.add_address(x); }
+    builder.add_code(args.code);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn address(&self) -> Option<&'a Address> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Address>(LogReorderFields::VT_ADDRESS, None)}
+  }
+  #[inline]
+  pub fn identity(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogReorderFields::VT_IDENTITY, None).unwrap()}
+  }
+  #[inline]
+  pub fn userid(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogReorderFields::VT_USERID, None).unwrap()}
+  }
+  #[inline]
+  pub fn date(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogReorderFields::VT_DATE, None).unwrap()}
+  }
+  #[inline]
+  pub fn request(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(LogReorderFields::VT_REQUEST, None).unwrap()}
+  }
+  #[inline]
+  pub fn code(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(LogReorderFields::VT_CODE, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn size(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(LogReorderFields::VT_SIZE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for LogReorderFields<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Address>("address", Self::VT_ADDRESS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("identity", Self::VT_IDENTITY, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("userid", Self::VT_USERID, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("date", Self::VT_DATE, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("request", Self::VT_REQUEST, true)?
+     .visit_field::<u16>("code", Self::VT_CODE, false)?
+     .visit_field::<u64>("size", Self::VT_SIZE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct LogReorderFieldsArgs<'a> {
+    pub address: Option<&'a Address>,
+    pub identity: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub userid: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub date: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub request: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub code: u16,
+    pub size: u64,
+}
+impl<'a> Default for LogReorderFieldsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    LogReorderFieldsArgs {
+      address: None,
+      identity: None, // required field
+      userid: None, // required field
+      date: None, // required field
+      request: None, // required field
+      code: 0,
+      size: 0,
+    }
+  }
+}
+
+pub struct LogReorderFieldsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LogReorderFieldsBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_address(&mut self, address: &Address) {
+    self.fbb_.push_slot_always::<&Address>(LogReorderFields::VT_ADDRESS, address);
+  }
+  #[inline]
+  pub fn add_identity(&mut self, identity: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogReorderFields::VT_IDENTITY, identity);
+  }
+  #[inline]
+  pub fn add_userid(&mut self, userid: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogReorderFields::VT_USERID, userid);
+  }
+  #[inline]
+  pub fn add_date(&mut self, date: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogReorderFields::VT_DATE, date);
+  }
+  #[inline]
+  pub fn add_request(&mut self, request: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(LogReorderFields::VT_REQUEST, request);
+  }
+  #[inline]
+  pub fn add_code(&mut self, code: u16) {
+    self.fbb_.push_slot::<u16>(LogReorderFields::VT_CODE, code, 0);
+  }
+  #[inline]
+  pub fn add_size(&mut self, size: u64) {
+    self.fbb_.push_slot::<u64>(LogReorderFields::VT_SIZE, size, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LogReorderFieldsBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    LogReorderFieldsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<LogReorderFields<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, LogReorderFields::VT_IDENTITY,"identity");
+    self.fbb_.required(o, LogReorderFields::VT_USERID,"userid");
+    self.fbb_.required(o, LogReorderFields::VT_DATE,"date");
+    self.fbb_.required(o, LogReorderFields::VT_REQUEST,"request");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for LogReorderFields<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("LogReorderFields");
+      ds.field("address", &self.address());
+      ds.field("identity", &self.identity());
+      ds.field("userid", &self.userid());
+      ds.field("date", &self.date());
+      ds.field("request", &self.request());
+      ds.field("code", &self.code());
+      ds.field("size", &self.size());
+      ds.finish()
+  }
+}
+}  // pub mod log_evolution
+
@@ -0,0 +1,772 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `log_evolution.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:prost.log.evolution.LogAddField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LogAddField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.address)
+    pub address: ::protobuf::MessageField<super::log::Address>,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.identity)
+    pub identity: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.userid)
+    pub userid: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.date)
+    pub date: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.request)
+    pub request: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.code)
+    pub code: u32,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.size)
+    pub size: u64,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogAddField.referrer)
+    pub referrer: ::std::option::Option<::std::string::String>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.log.evolution.LogAddField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LogAddField {
+    fn default() -> &'a LogAddField {
+        <LogAddField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogAddField {
+    pub fn new() -> LogAddField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(8);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::log::Address>(
+            "address",
+            |m: &LogAddField| { &m.address },
+            |m: &mut LogAddField| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "identity",
+            |m: &LogAddField| { &m.identity },
+            |m: &mut LogAddField| { &mut m.identity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "userid",
+            |m: &LogAddField| { &m.userid },
+            |m: &mut LogAddField| { &mut m.userid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "date",
+            |m: &LogAddField| { &m.date },
+            |m: &mut LogAddField| { &mut m.date },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "request",
+            |m: &LogAddField| { &m.request },
+            |m: &mut LogAddField| { &mut m.request },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &LogAddField| { &m.code },
+            |m: &mut LogAddField| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &LogAddField| { &m.size },
+            |m: &mut LogAddField| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "referrer",
+            |m: &LogAddField| { &m.referrer },
+            |m: &mut LogAddField| { &mut m.referrer },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LogAddField>(
+            "LogAddField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LogAddField {
+    const NAME: &'static str = "LogAddField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.address)?;
+                },
+                18 => {
+                    self.identity = is.read_string()?;
+                },
+                26 => {
+                    self.userid = is.read_string()?;
+                },
+                34 => {
+                    self.date = is.read_string()?;
+                },
+                42 => {
+                    self.request = is.read_string()?;
+                },
+                48 => {
+                    self.code = is.read_uint32()?;
+                },
+                56 => {
+                    self.size = is.read_uint64()?;
+                },
+                66 => {
+                    self.referrer = ::std::option::Option::Some(is.read_string()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.identity.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.identity);
+        }
+        if !self.userid.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.userid);
+        }
+        if !self.date.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.date);
+        }
+        if !self.request.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.request);
+        }
+        if self.code != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.code);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.size);
+        }
+        if let Some(v) = self.referrer.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.address.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.identity.is_empty() {
+            os.write_string(2, &self.identity)?;
+        }
+        if !self.userid.is_empty() {
+            os.write_string(3, &self.userid)?;
+        }
+        if !self.date.is_empty() {
+            os.write_string(4, &self.date)?;
+        }
+        if !self.request.is_empty() {
+            os.write_string(5, &self.request)?;
+        }
+        if self.code != 0 {
+            os.write_uint32(6, self.code)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(7, self.size)?;
+        }
+        if let Some(v) = self.referrer.as_ref() {
+            os.write_string(8, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LogAddField {
+        LogAddField::new()
+    }
+
+    fn clear(&mut self) {
+        self.address.clear();
+        self.identity.clear();
+        self.userid.clear();
+        self.date.clear();
+        self.request.clear();
+        self.code = 0;
+        self.size = 0;
+        self.referrer = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LogAddField {
+        static instance: LogAddField = LogAddField {
+            address: ::protobuf::MessageField::none(),
+            identity: ::std::string::String::new(),
+            userid: ::std::string::String::new(),
+            date: ::std::string::String::new(),
+            request: ::std::string::String::new(),
+            code: 0,
+            size: 0,
+            referrer: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LogAddField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LogAddField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LogAddField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogAddField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.log.evolution.LogRemoveField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LogRemoveField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.log.evolution.LogRemoveField.address)
+    pub address: ::protobuf::MessageField<super::log::Address>,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogRemoveField.userid)
+    pub userid: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogRemoveField.date)
+    pub date: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogRemoveField.request)
+    pub request: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogRemoveField.code)
+    pub code: u32,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogRemoveField.size)
+    pub size: u64,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.log.evolution.LogRemoveField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LogRemoveField {
+    fn default() -> &'a LogRemoveField {
+        <LogRemoveField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogRemoveField {
+    pub fn new() -> LogRemoveField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(6);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::log::Address>(
+            "address",
+            |m: &LogRemoveField| { &m.address },
+            |m: &mut LogRemoveField| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "userid",
+            |m: &LogRemoveField| { &m.userid },
+            |m: &mut LogRemoveField| { &mut m.userid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "date",
+            |m: &LogRemoveField| { &m.date },
+            |m: &mut LogRemoveField| { &mut m.date },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "request",
+            |m: &LogRemoveField| { &m.request },
+            |m: &mut LogRemoveField| { &mut m.request },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &LogRemoveField| { &m.code },
+            |m: &mut LogRemoveField| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &LogRemoveField| { &m.size },
+            |m: &mut LogRemoveField| { &mut m.size },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LogRemoveField>(
+            "LogRemoveField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LogRemoveField {
+    const NAME: &'static str = "LogRemoveField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.address)?;
+                },
+                26 => {
+                    self.userid = is.read_string()?;
+                },
+                34 => {
+                    self.date = is.read_string()?;
+                },
+                42 => {
+                    self.request = is.read_string()?;
+                },
+                48 => {
+                    self.code = is.read_uint32()?;
+                },
+                56 => {
+                    self.size = is.read_uint64()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.userid.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.userid);
+        }
+        if !self.date.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.date);
+        }
+        if !self.request.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.request);
+        }
+        if self.code != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.code);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.size);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.address.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.userid.is_empty() {
+            os.write_string(3, &self.userid)?;
+        }
+        if !self.date.is_empty() {
+            os.write_string(4, &self.date)?;
+        }
+        if !self.request.is_empty() {
+            os.write_string(5, &self.request)?;
+        }
+        if self.code != 0 {
+            os.write_uint32(6, self.code)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(7, self.size)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LogRemoveField {
+        LogRemoveField::new()
+    }
+
+    fn clear(&mut self) {
+        self.address.clear();
+        self.userid.clear();
+        self.date.clear();
+        self.request.clear();
+        self.code = 0;
+        self.size = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LogRemoveField {
+        static instance: LogRemoveField = LogRemoveField {
+            address: ::protobuf::MessageField::none(),
+            userid: ::std::string::String::new(),
+            date: ::std::string::String::new(),
+            request: ::std::string::String::new(),
+            code: 0,
+            size: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LogRemoveField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LogRemoveField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LogRemoveField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogRemoveField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.log.evolution.LogReorderFields)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct LogReorderFields {
+    // message fields
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.code)
+    pub code: u32,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.size)
+    pub size: u64,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.address)
+    pub address: ::protobuf::MessageField<super::log::Address>,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.identity)
+    pub identity: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.userid)
+    pub userid: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.date)
+    pub date: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.log.evolution.LogReorderFields.request)
+    pub request: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.log.evolution.LogReorderFields.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a LogReorderFields {
+    fn default() -> &'a LogReorderFields {
+        <LogReorderFields as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LogReorderFields {
+    pub fn new() -> LogReorderFields {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(7);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &LogReorderFields| { &m.code },
+            |m: &mut LogReorderFields| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &LogReorderFields| { &m.size },
+            |m: &mut LogReorderFields| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::log::Address>(
+            "address",
+            |m: &LogReorderFields| { &m.address },
+            |m: &mut LogReorderFields| { &mut m.address },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "identity",
+            |m: &LogReorderFields| { &m.identity },
+            |m: &mut LogReorderFields| { &mut m.identity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "userid",
+            |m: &LogReorderFields| { &m.userid },
+            |m: &mut LogReorderFields| { &mut m.userid },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "date",
+            |m: &LogReorderFields| { &m.date },
+            |m: &mut LogReorderFields| { &mut m.date },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "request",
+            |m: &LogReorderFields| { &m.request },
+            |m: &mut LogReorderFields| { &mut m.request },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LogReorderFields>(
+            "LogReorderFields",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for LogReorderFields {
+    const NAME: &'static str = "LogReorderFields";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                48 => {
+                    self.code = is.read_uint32()?;
+                },
+                56 => {
+                    self.size = is.read_uint64()?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.address)?;
+                },
+                18 => {
+                    self.identity = is.read_string()?;
+                },
+                26 => {
+                    self.userid = is.read_string()?;
+                },
+                34 => {
+                    self.date = is.read_string()?;
+                },
+                42 => {
+                    self.request = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.code != 0 {
+            my_size += ::protobuf::rt::uint32_size(6, self.code);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::uint64_size(7, self.size);
+        }
+        if let Some(v) = self.address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if !self.identity.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.identity);
+        }
+        if !self.userid.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.userid);
+        }
+        if !self.date.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.date);
+        }
+        if !self.request.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.request);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.code != 0 {
+            os.write_uint32(6, self.code)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(7, self.size)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if !self.identity.is_empty() {
+            os.write_string(2, &self.identity)?;
+        }
+        if !self.userid.is_empty() {
+            os.write_string(3, &self.userid)?;
+        }
+        if !self.date.is_empty() {
+            os.write_string(4, &self.date)?;
+        }
+        if !self.request.is_empty() {
+            os.write_string(5, &self.request)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> LogReorderFields {
+        LogReorderFields::new()
+    }
+
+    fn clear(&mut self) {
+        self.code = 0;
+        self.size = 0;
+        self.address.clear();
+        self.identity.clear();
+        self.userid.clear();
+        self.date.clear();
+        self.request.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static LogReorderFields {
+        static instance: LogReorderFields = LogReorderFields {
+            code: 0,
+            size: 0,
+            address: ::protobuf::MessageField::none(),
+            identity: ::std::string::String::new(),
+            userid: ::std::string::String::new(),
+            date: ::std::string::String::new(),
+            request: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for LogReorderFields {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("LogReorderFields").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for LogReorderFields {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LogReorderFields {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x13log_evolution.proto\x12\x13prost.log.evolution\x1a\tlog.proto\"\
+    \xf3\x01\n\x0bLogAddField\x12,\n\x07address\x18\x01\x20\x01(\x0b2\x12.pr\
+    ost.log.AddressR\x07address\x12\x1a\n\x08identity\x18\x02\x20\x01(\tR\
+    \x08identity\x12\x16\n\x06userid\x18\x03\x20\x01(\tR\x06userid\x12\x12\n\
+    \x04date\x18\x04\x20\x01(\tR\x04date\x12\x18\n\x07request\x18\x05\x20\
+    \x01(\tR\x07request\x12\x12\n\x04code\x18\x06\x20\x01(\rR\x04code\x12\
+    \x12\n\x04size\x18\x07\x20\x01(\x04R\x04size\x12\x1f\n\x08referrer\x18\
+    \x08\x20\x01(\tH\0R\x08referrer\x88\x01\x01B\x0b\n\t_referrer\"\xbc\x01\
+    \n\x0eLogRemoveField\x12,\n\x07address\x18\x01\x20\x01(\x0b2\x12.prost.l\
+    og.AddressR\x07address\x12\x16\n\x06userid\x18\x03\x20\x01(\tR\x06userid\
+    \x12\x12\n\x04date\x18\x04\x20\x01(\tR\x04date\x12\x18\n\x07request\x18\
+    \x05\x20\x01(\tR\x07request\x12\x12\n\x04code\x18\x06\x20\x01(\rR\x04cod\
+    e\x12\x12\n\x04size\x18\x07\x20\x01(\x04R\x04sizeJ\x04\x08\x02\x10\x03R\
+    \x08identity\"\xca\x01\n\x10LogReorderFields\x12\x12\n\x04code\x18\x06\
+    \x20\x01(\rR\x04code\x12\x12\n\x04size\x18\x07\x20\x01(\x04R\x04size\x12\
+    ,\n\x07address\x18\x01\x20\x01(\x0b2\x12.prost.log.AddressR\x07address\
+    \x12\x1a\n\x08identity\x18\x02\x20\x01(\tR\x08identity\x12\x16\n\x06user\
+    id\x18\x03\x20\x01(\tR\x06userid\x12\x12\n\x04date\x18\x04\x20\x01(\tR\
+    \x04date\x12\x18\n\x07request\x18\x05\x20\x01(\tR\x07requestb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::log::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(3);
+            messages.push(LogAddField::generated_message_descriptor_data());
+            messages.push(LogRemoveField::generated_message_descriptor_data());
+            messages.push(LogReorderFields::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -1,3 +1,4 @@
 // @generated
 
 pub mod log;
+pub mod log_evolution;
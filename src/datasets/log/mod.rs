@@ -1,14 +1,28 @@
+pub mod evolution;
 #[cfg(feature = "capnp")]
 pub mod log_capnp;
+#[cfg(feature = "capnp")]
+pub mod log_evolution_capnp;
+#[cfg(feature = "flatbuffers")]
+#[path = "log_evolution_generated.rs"]
+#[allow(unused_imports, clippy::all)]
+pub mod log_evolution_fb;
 #[cfg(feature = "flatbuffers")]
 #[path = "log_generated.rs"]
 #[allow(unused_imports, clippy::all)]
 pub mod log_fb;
 #[cfg(feature = "prost")]
-#[path = "prost.log.rs"]
-pub mod log_prost;
+pub mod log_prost {
+    include!("prost.log.rs");
+
+    /// Newer versions of some messages, used by the schema evolution checks.
+    pub mod evolution {
+        include!("prost.log.evolution.rs");
+    }
+}
 #[cfg(feature = "protobuf")]
 pub mod log_protobuf;
+pub mod partial;
 
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -146,6 +160,18 @@ impl From<Address> for fb::Address {
     }
 }
 
+#[cfg(feature = "flatbuffers")]
+impl From<&fb::Address> for Address {
+    fn from(value: &fb::Address) -> Self {
+        Address {
+            x0: value.x0(),
+            x1: value.x1(),
+            x2: value.x2(),
+            x3: value.x3(),
+        }
+    }
+}
+
 #[cfg(feature = "capnp")]
 impl<'a> bench_capnp::Serialize<'a> for Address {
     type Reader = cp::address::Reader<'a>;
@@ -160,6 +186,18 @@ impl<'a> bench_capnp::Serialize<'a> for Address {
     }
 }
 
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Address {
+    fn deserialize_capnp(address: Self::Reader) -> capnp::Result<Self> {
+        Ok(Address {
+            x0: address.get_x0(),
+            x1: address.get_x1(),
+            x2: address.get_x2(),
+            x3: address.get_x3(),
+        })
+    }
+}
+
 #[cfg(feature = "prost")]
 impl bench_prost::Serialize for Address {
     type Message = log_prost::Address;
@@ -402,6 +440,21 @@ impl<'a> bench_flatbuffers::Serialize<'a> for Log {
     }
 }
 
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for Log {
+    fn deserialize_fb(log: fb::Log<'a>) -> Self {
+        Log {
+            address: log.address().unwrap().into(),
+            identity: log.identity().to_owned(),
+            userid: log.userid().to_owned(),
+            date: log.date().to_owned(),
+            request: log.request().to_owned(),
+            code: log.code(),
+            size: log.size(),
+        }
+    }
+}
+
 #[cfg(feature = "capnp")]
 impl<'a> bench_capnp::Serialize<'a> for Log {
     type Reader = cp::log::Reader<'a>;
@@ -422,6 +475,21 @@ impl<'a> bench_capnp::Serialize<'a> for Log {
     }
 }
 
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Log {
+    fn deserialize_capnp(log: Self::Reader) -> capnp::Result<Self> {
+        Ok(Log {
+            address: Address::deserialize_capnp(log.get_address()?)?,
+            identity: log.get_identity()?.to_string()?,
+            userid: log.get_userid()?.to_string()?,
+            date: log.get_date()?.to_string()?,
+            request: log.get_request()?.to_string()?,
+            code: log.get_code(),
+            size: log.get_size(),
+        })
+    }
+}
+
 #[cfg(feature = "prost")]
 impl bench_prost::Serialize for Log {
     type Message = log_prost::Log;
@@ -0,0 +1,159 @@
+//! [`Logs`] with only the fields read by the zero-copy `read` benchmarks.
+
+use criterion::{black_box, Criterion};
+
+#[cfg(feature = "prost")]
+use super::log_prost;
+use super::{Address, Logs};
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+use crate::codec::{for_each_codec, for_each_other_codec, Codec};
+use crate::partial::{self, Partial};
+
+/// [`Log`](super::Log) with only `address`, `code` and `size`.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LogRead {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub address: Address,
+    #[cfg_attr(feature = "bilrost", bilrost(6))]
+    #[cfg_attr(feature = "minicbor", n(5))]
+    pub code: u16,
+    #[cfg_attr(feature = "bilrost", bilrost(7))]
+    #[cfg_attr(feature = "minicbor", n(6))]
+    pub size: u64,
+}
+
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct LogsRead {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub logs: Vec<LogRead>,
+}
+
+impl Partial<Logs> for LogsRead {
+    fn read_full(full: &Logs) {
+        for log in full.logs.iter() {
+            black_box(&log.address);
+            black_box(log.code);
+            black_box(log.size);
+        }
+    }
+
+    fn agrees(&self, full: &Logs) -> bool {
+        self.logs.len() == full.logs.len()
+            && self.logs.iter().zip(full.logs.iter()).all(|(read, log)| {
+                read.address == log.address && read.code == log.code && read.size == log.size
+            })
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    fn read_flexbuffers(root: flexbuffers::Reader<&[u8]>) -> Self {
+        let logs = root.as_map().idx("logs").as_vector();
+        Self {
+            logs: logs
+                .iter()
+                .map(|log| {
+                    let log = log.as_map();
+                    let address = log.idx("address").as_map();
+                    LogRead {
+                        address: Address {
+                            x0: address.idx("x0").as_u8(),
+                            x1: address.idx("x1").as_u8(),
+                            x2: address.idx("x2").as_u8(),
+                            x3: address.idx("x3").as_u8(),
+                        },
+                        code: log.idx("code").as_u16(),
+                        size: log.idx("size").as_u64(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_read {
+    use super::log_prost::Address;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct LogRead {
+        #[prost(message, optional, tag = "1")]
+        pub address: Option<Address>,
+        #[prost(uint32, tag = "6")]
+        pub code: u32,
+        #[prost(uint64, tag = "7")]
+        pub size: u64,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct LogsRead {
+        #[prost(message, repeated, tag = "1")]
+        pub logs: Vec<LogRead>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for LogsRead {
+    type Message = prost_read::LogsRead;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            logs: self
+                .logs
+                .iter()
+                .map(|log| prost_read::LogRead {
+                    address: Some(log.address.serialize_pb()),
+                    code: log.code as u32,
+                    size: log.size,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<prost_read::LogsRead> for LogsRead {
+    fn from(value: prost_read::LogsRead) -> Self {
+        LogsRead {
+            logs: value
+                .logs
+                .into_iter()
+                .map(|log| LogRead {
+                    address: log.address.unwrap().into(),
+                    code: log.code.try_into().unwrap(),
+                    size: log.size,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn bench(name: &str, c: &mut Criterion, data: &Logs) {
+    for_each_codec!(bench_codec, name, c, data);
+    for_each_other_codec!(bench_other_codec, name, c, data);
+}
+
+fn bench_codec<C>(lib: &str, name: &str, c: &mut Criterion, data: &Logs)
+where
+    C: Codec<Logs> + Codec<LogsRead>,
+{
+    partial::bench::<C, Logs, LogsRead>(name, lib, c, data);
+}
+
+fn bench_other_codec<C: Codec<Logs>>(lib: &str, name: &str, c: &mut Criterion, data: &Logs) {
+    partial::bench_full::<C, Logs, LogsRead>(name, lib, c, data);
+}
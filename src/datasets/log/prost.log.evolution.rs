@@ -0,0 +1,52 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogAddField {
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::Address>,
+    #[prost(string, tag = "2")]
+    pub identity: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub userid: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub date: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub request: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "6")]
+    pub code: u32,
+    #[prost(uint64, tag = "7")]
+    pub size: u64,
+    #[prost(string, optional, tag = "8")]
+    pub referrer: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogRemoveField {
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::Address>,
+    #[prost(string, tag = "3")]
+    pub userid: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub date: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub request: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "6")]
+    pub code: u32,
+    #[prost(uint64, tag = "7")]
+    pub size: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogReorderFields {
+    #[prost(uint32, tag = "6")]
+    pub code: u32,
+    #[prost(uint64, tag = "7")]
+    pub size: u64,
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::Address>,
+    #[prost(string, tag = "2")]
+    pub identity: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub userid: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub date: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub request: ::prost::alloc::string::String,
+}
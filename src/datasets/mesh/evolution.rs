@@ -0,0 +1,398 @@
+//! Newer versions of [`Triangle`] for the schema evolution checks.
+
+#[cfg(feature = "capnp")]
+use super::mesh_evolution_capnp as cp_v2;
+#[cfg(feature = "prost")]
+use super::mesh_prost::evolution as pb_v2;
+#[cfg(feature = "protobuf")]
+use super::mesh_protobuf::mesh_evolution as rpb_v2;
+use super::{Mesh, Triangle, Vector3};
+#[cfg(feature = "capnp")]
+use crate::bench_capnp;
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+use crate::codec::{for_each_codec, Codec};
+use crate::evolution::{self, Change, Evolution};
+
+/// [`Triangle`] with an optional `color` field added at the end.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct TriangleAddField {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub v0: Vector3,
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub v1: Vector3,
+    #[cfg_attr(feature = "minicbor", n(2))]
+    pub v2: Vector3,
+    #[cfg_attr(feature = "minicbor", n(3))]
+    pub normal: Vector3,
+    #[cfg_attr(feature = "minicbor", n(4))]
+    pub color: Option<u32>,
+}
+
+impl Evolution<Triangle> for TriangleAddField {
+    const CHANGE: Change = Change::AddField;
+
+    fn upgrade(old: &Triangle) -> Self {
+        Self {
+            v0: old.v0,
+            v1: old.v1,
+            v2: old.v2,
+            normal: old.normal,
+            color: Some(0xdea584),
+        }
+    }
+
+    fn agrees(&self, old: &Triangle) -> bool {
+        self.v0 == old.v0 && self.v1 == old.v1 && self.v2 == old.v2 && self.normal == old.normal
+    }
+}
+
+/// [`Triangle`] with the `normal` field removed.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct TriangleRemoveField {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub v0: Vector3,
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub v1: Vector3,
+    #[cfg_attr(feature = "minicbor", n(2))]
+    pub v2: Vector3,
+}
+
+impl Evolution<Triangle> for TriangleRemoveField {
+    const CHANGE: Change = Change::RemoveField;
+
+    fn upgrade(old: &Triangle) -> Self {
+        Self {
+            v0: old.v0,
+            v1: old.v1,
+            v2: old.v2,
+        }
+    }
+
+    fn agrees(&self, old: &Triangle) -> bool {
+        self.v0 == old.v0 && self.v1 == old.v1 && self.v2 == old.v2
+    }
+}
+
+/// [`Triangle`] with the `normal` field moved to the front. Tagged formats keep the original tags.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct TriangleReorderFields {
+    #[cfg_attr(feature = "bilrost", bilrost(4))]
+    #[cfg_attr(feature = "minicbor", n(3))]
+    pub normal: Vector3,
+    #[cfg_attr(feature = "bilrost", bilrost(1))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub v0: Vector3,
+    #[cfg_attr(feature = "bilrost", bilrost(2))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub v1: Vector3,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", n(2))]
+    pub v2: Vector3,
+}
+
+impl Evolution<Triangle> for TriangleReorderFields {
+    const CHANGE: Change = Change::ReorderFields;
+
+    fn upgrade(old: &Triangle) -> Self {
+        Self {
+            normal: old.normal,
+            v0: old.v0,
+            v1: old.v1,
+            v2: old.v2,
+        }
+    }
+
+    fn agrees(&self, old: &Triangle) -> bool {
+        self.v0 == old.v0 && self.v1 == old.v1 && self.v2 == old.v2 && self.normal == old.normal
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for TriangleAddField {
+    type Reader = cp_v2::triangle_add_field::Reader<'a>;
+    type Builder = cp_v2::triangle_add_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        self.v0.serialize_capnp(&mut builder.reborrow().init_v0());
+        self.v1.serialize_capnp(&mut builder.reborrow().init_v1());
+        self.v2.serialize_capnp(&mut builder.reborrow().init_v2());
+        self.normal
+            .serialize_capnp(&mut builder.reborrow().init_normal());
+        let mut color = builder.reborrow().init_color();
+        if let Some(value) = self.color {
+            color.set_some(value);
+        } else {
+            color.set_none(());
+        }
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for TriangleAddField {
+    fn deserialize_capnp(triangle: Self::Reader) -> capnp::Result<Self> {
+        use cp_v2::triangle_add_field::color;
+
+        Ok(TriangleAddField {
+            v0: Vector3::deserialize_capnp(triangle.get_v0()?)?,
+            v1: Vector3::deserialize_capnp(triangle.get_v1()?)?,
+            v2: Vector3::deserialize_capnp(triangle.get_v2()?)?,
+            normal: Vector3::deserialize_capnp(triangle.get_normal()?)?,
+            color: match triangle.get_color().which()? {
+                color::None(()) => None,
+                color::Some(value) => Some(value),
+            },
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for TriangleRemoveField {
+    type Reader = cp_v2::triangle_remove_field::Reader<'a>;
+    type Builder = cp_v2::triangle_remove_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        self.v0.serialize_capnp(&mut builder.reborrow().init_v0());
+        self.v1.serialize_capnp(&mut builder.reborrow().init_v1());
+        self.v2.serialize_capnp(&mut builder.reborrow().init_v2());
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for TriangleRemoveField {
+    fn deserialize_capnp(triangle: Self::Reader) -> capnp::Result<Self> {
+        Ok(TriangleRemoveField {
+            v0: Vector3::deserialize_capnp(triangle.get_v0()?)?,
+            v1: Vector3::deserialize_capnp(triangle.get_v1()?)?,
+            v2: Vector3::deserialize_capnp(triangle.get_v2()?)?,
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for TriangleReorderFields {
+    type Reader = cp_v2::triangle_reorder_fields::Reader<'a>;
+    type Builder = cp_v2::triangle_reorder_fields::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        self.normal
+            .serialize_capnp(&mut builder.reborrow().init_normal());
+        self.v0.serialize_capnp(&mut builder.reborrow().init_v0());
+        self.v1.serialize_capnp(&mut builder.reborrow().init_v1());
+        self.v2.serialize_capnp(&mut builder.reborrow().init_v2());
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for TriangleReorderFields {
+    fn deserialize_capnp(triangle: Self::Reader) -> capnp::Result<Self> {
+        Ok(TriangleReorderFields {
+            normal: Vector3::deserialize_capnp(triangle.get_normal()?)?,
+            v0: Vector3::deserialize_capnp(triangle.get_v0()?)?,
+            v1: Vector3::deserialize_capnp(triangle.get_v1()?)?,
+            v2: Vector3::deserialize_capnp(triangle.get_v2()?)?,
+        })
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for TriangleAddField {
+    type Message = pb_v2::TriangleAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            v0: Some(self.v0.serialize_pb()),
+            v1: Some(self.v1.serialize_pb()),
+            v2: Some(self.v2.serialize_pb()),
+            normal: Some(self.normal.serialize_pb()),
+            color: self.color,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::TriangleAddField> for TriangleAddField {
+    fn from(value: pb_v2::TriangleAddField) -> Self {
+        TriangleAddField {
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+            normal: value.normal.unwrap().into(),
+            color: value.color,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for TriangleRemoveField {
+    type Message = pb_v2::TriangleRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            v0: Some(self.v0.serialize_pb()),
+            v1: Some(self.v1.serialize_pb()),
+            v2: Some(self.v2.serialize_pb()),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::TriangleRemoveField> for TriangleRemoveField {
+    fn from(value: pb_v2::TriangleRemoveField) -> Self {
+        TriangleRemoveField {
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for TriangleReorderFields {
+    type Message = pb_v2::TriangleReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            normal: Some(self.normal.serialize_pb()),
+            v0: Some(self.v0.serialize_pb()),
+            v1: Some(self.v1.serialize_pb()),
+            v2: Some(self.v2.serialize_pb()),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::TriangleReorderFields> for TriangleReorderFields {
+    fn from(value: pb_v2::TriangleReorderFields) -> Self {
+        TriangleReorderFields {
+            normal: value.normal.unwrap().into(),
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for TriangleAddField {
+    type Message = rpb_v2::TriangleAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            v0: protobuf::MessageField::some(self.v0.serialize_pb()),
+            v1: protobuf::MessageField::some(self.v1.serialize_pb()),
+            v2: protobuf::MessageField::some(self.v2.serialize_pb()),
+            normal: protobuf::MessageField::some(self.normal.serialize_pb()),
+            color: self.color,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::TriangleAddField> for TriangleAddField {
+    fn from(value: rpb_v2::TriangleAddField) -> Self {
+        TriangleAddField {
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+            normal: value.normal.unwrap().into(),
+            color: value.color,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for TriangleRemoveField {
+    type Message = rpb_v2::TriangleRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            v0: protobuf::MessageField::some(self.v0.serialize_pb()),
+            v1: protobuf::MessageField::some(self.v1.serialize_pb()),
+            v2: protobuf::MessageField::some(self.v2.serialize_pb()),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::TriangleRemoveField> for TriangleRemoveField {
+    fn from(value: rpb_v2::TriangleRemoveField) -> Self {
+        TriangleRemoveField {
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for TriangleReorderFields {
+    type Message = rpb_v2::TriangleReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            normal: protobuf::MessageField::some(self.normal.serialize_pb()),
+            v0: protobuf::MessageField::some(self.v0.serialize_pb()),
+            v1: protobuf::MessageField::some(self.v1.serialize_pb()),
+            v2: protobuf::MessageField::some(self.v2.serialize_pb()),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::TriangleReorderFields> for TriangleReorderFields {
+    fn from(value: rpb_v2::TriangleReorderFields) -> Self {
+        TriangleReorderFields {
+            normal: value.normal.unwrap().into(),
+            v0: value.v0.unwrap().into(),
+            v1: value.v1.unwrap().into(),
+            v2: value.v2.unwrap().into(),
+        }
+    }
+}
+
+pub fn check(name: &str, data: &Mesh) {
+    for_each_codec!(check_codec, name, &data.triangles);
+    #[cfg(feature = "capnp")]
+    check_codec::<crate::codec::Capnp>("capnp", name, &data.triangles);
+    #[cfg(feature = "protobuf")]
+    check_codec::<crate::codec::Protobuf>("protobuf", name, &data.triangles);
+}
+
+fn check_codec<C>(lib: &str, name: &str, triangles: &[Triangle])
+where
+    C: Codec<Triangle>
+        + Codec<TriangleAddField>
+        + Codec<TriangleRemoveField>
+        + Codec<TriangleReorderFields>,
+{
+    evolution::check::<C, Triangle, TriangleAddField>(name, lib, triangles);
+    evolution::check::<C, Triangle, TriangleRemoveField>(name, lib, triangles);
+    evolution::check::<C, Triangle, TriangleReorderFields>(name, lib, triangles);
+}
@@ -0,0 +1,614 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `mesh_evolution.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:prost.mesh.evolution.TriangleAddField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TriangleAddField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleAddField.v0)
+    pub v0: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleAddField.v1)
+    pub v1: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleAddField.v2)
+    pub v2: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleAddField.normal)
+    pub normal: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleAddField.color)
+    pub color: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mesh.evolution.TriangleAddField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TriangleAddField {
+    fn default() -> &'a TriangleAddField {
+        <TriangleAddField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TriangleAddField {
+    pub fn new() -> TriangleAddField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(5);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v0",
+            |m: &TriangleAddField| { &m.v0 },
+            |m: &mut TriangleAddField| { &mut m.v0 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v1",
+            |m: &TriangleAddField| { &m.v1 },
+            |m: &mut TriangleAddField| { &mut m.v1 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v2",
+            |m: &TriangleAddField| { &m.v2 },
+            |m: &mut TriangleAddField| { &mut m.v2 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "normal",
+            |m: &TriangleAddField| { &m.normal },
+            |m: &mut TriangleAddField| { &mut m.normal },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "color",
+            |m: &TriangleAddField| { &m.color },
+            |m: &mut TriangleAddField| { &mut m.color },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TriangleAddField>(
+            "TriangleAddField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TriangleAddField {
+    const NAME: &'static str = "TriangleAddField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v0)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v1)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v2)?;
+                },
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.normal)?;
+                },
+                40 => {
+                    self.color = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.v0.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.normal.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.color {
+            my_size += ::protobuf::rt::uint32_size(5, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.v0.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        if let Some(v) = self.normal.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.color {
+            os.write_uint32(5, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TriangleAddField {
+        TriangleAddField::new()
+    }
+
+    fn clear(&mut self) {
+        self.v0.clear();
+        self.v1.clear();
+        self.v2.clear();
+        self.normal.clear();
+        self.color = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TriangleAddField {
+        static instance: TriangleAddField = TriangleAddField {
+            v0: ::protobuf::MessageField::none(),
+            v1: ::protobuf::MessageField::none(),
+            v2: ::protobuf::MessageField::none(),
+            normal: ::protobuf::MessageField::none(),
+            color: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TriangleAddField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TriangleAddField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TriangleAddField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TriangleAddField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mesh.evolution.TriangleRemoveField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TriangleRemoveField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleRemoveField.v0)
+    pub v0: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleRemoveField.v1)
+    pub v1: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleRemoveField.v2)
+    pub v2: ::protobuf::MessageField<super::mesh::Vector3>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mesh.evolution.TriangleRemoveField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TriangleRemoveField {
+    fn default() -> &'a TriangleRemoveField {
+        <TriangleRemoveField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TriangleRemoveField {
+    pub fn new() -> TriangleRemoveField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v0",
+            |m: &TriangleRemoveField| { &m.v0 },
+            |m: &mut TriangleRemoveField| { &mut m.v0 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v1",
+            |m: &TriangleRemoveField| { &m.v1 },
+            |m: &mut TriangleRemoveField| { &mut m.v1 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v2",
+            |m: &TriangleRemoveField| { &m.v2 },
+            |m: &mut TriangleRemoveField| { &mut m.v2 },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TriangleRemoveField>(
+            "TriangleRemoveField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TriangleRemoveField {
+    const NAME: &'static str = "TriangleRemoveField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v0)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v1)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v2)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.v0.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.v0.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TriangleRemoveField {
+        TriangleRemoveField::new()
+    }
+
+    fn clear(&mut self) {
+        self.v0.clear();
+        self.v1.clear();
+        self.v2.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TriangleRemoveField {
+        static instance: TriangleRemoveField = TriangleRemoveField {
+            v0: ::protobuf::MessageField::none(),
+            v1: ::protobuf::MessageField::none(),
+            v2: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TriangleRemoveField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TriangleRemoveField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TriangleRemoveField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TriangleRemoveField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mesh.evolution.TriangleReorderFields)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct TriangleReorderFields {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleReorderFields.normal)
+    pub normal: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleReorderFields.v0)
+    pub v0: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleReorderFields.v1)
+    pub v1: ::protobuf::MessageField<super::mesh::Vector3>,
+    // @@protoc_insertion_point(field:prost.mesh.evolution.TriangleReorderFields.v2)
+    pub v2: ::protobuf::MessageField<super::mesh::Vector3>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mesh.evolution.TriangleReorderFields.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a TriangleReorderFields {
+    fn default() -> &'a TriangleReorderFields {
+        <TriangleReorderFields as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TriangleReorderFields {
+    pub fn new() -> TriangleReorderFields {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "normal",
+            |m: &TriangleReorderFields| { &m.normal },
+            |m: &mut TriangleReorderFields| { &mut m.normal },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v0",
+            |m: &TriangleReorderFields| { &m.v0 },
+            |m: &mut TriangleReorderFields| { &mut m.v0 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v1",
+            |m: &TriangleReorderFields| { &m.v1 },
+            |m: &mut TriangleReorderFields| { &mut m.v1 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_message_field_accessor::<_, super::mesh::Vector3>(
+            "v2",
+            |m: &TriangleReorderFields| { &m.v2 },
+            |m: &mut TriangleReorderFields| { &mut m.v2 },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TriangleReorderFields>(
+            "TriangleReorderFields",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for TriangleReorderFields {
+    const NAME: &'static str = "TriangleReorderFields";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                34 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.normal)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v0)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v1)?;
+                },
+                26 => {
+                    ::protobuf::rt::read_singular_message_into_field(is, &mut self.v2)?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.normal.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v0.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if let Some(v) = self.normal.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+        }
+        if let Some(v) = self.v0.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        }
+        if let Some(v) = self.v1.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        }
+        if let Some(v) = self.v2.as_ref() {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> TriangleReorderFields {
+        TriangleReorderFields::new()
+    }
+
+    fn clear(&mut self) {
+        self.normal.clear();
+        self.v0.clear();
+        self.v1.clear();
+        self.v2.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static TriangleReorderFields {
+        static instance: TriangleReorderFields = TriangleReorderFields {
+            normal: ::protobuf::MessageField::none(),
+            v0: ::protobuf::MessageField::none(),
+            v1: ::protobuf::MessageField::none(),
+            v2: ::protobuf::MessageField::none(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for TriangleReorderFields {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("TriangleReorderFields").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for TriangleReorderFields {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TriangleReorderFields {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14mesh_evolution.proto\x12\x14prost.mesh.evolution\x1a\nmesh.proto\"\
+    \xd3\x01\n\x10TriangleAddField\x12#\n\x02v0\x18\x01\x20\x01(\x0b2\x13.pr\
+    ost.mesh.Vector3R\x02v0\x12#\n\x02v1\x18\x02\x20\x01(\x0b2\x13.prost.mes\
+    h.Vector3R\x02v1\x12#\n\x02v2\x18\x03\x20\x01(\x0b2\x13.prost.mesh.Vecto\
+    r3R\x02v2\x12+\n\x06normal\x18\x04\x20\x01(\x0b2\x13.prost.mesh.Vector3R\
+    \x06normal\x12\x19\n\x05color\x18\x05\x20\x01(\rH\0R\x05color\x88\x01\
+    \x01B\x08\n\x06_color\"\x92\x01\n\x13TriangleRemoveField\x12#\n\x02v0\
+    \x18\x01\x20\x01(\x0b2\x13.prost.mesh.Vector3R\x02v0\x12#\n\x02v1\x18\
+    \x02\x20\x01(\x0b2\x13.prost.mesh.Vector3R\x02v1\x12#\n\x02v2\x18\x03\
+    \x20\x01(\x0b2\x13.prost.mesh.Vector3R\x02v2J\x04\x08\x04\x10\x05R\x06no\
+    rmal\"\xb3\x01\n\x15TriangleReorderFields\x12+\n\x06normal\x18\x04\x20\
+    \x01(\x0b2\x13.prost.mesh.Vector3R\x06normal\x12#\n\x02v0\x18\x01\x20\
+    \x01(\x0b2\x13.prost.mesh.Vector3R\x02v0\x12#\n\x02v1\x18\x02\x20\x01(\
+    \x0b2\x13.prost.mesh.Vector3R\x02v1\x12#\n\x02v2\x18\x03\x20\x01(\x0b2\
+    \x13.prost.mesh.Vector3R\x02v2b\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::mesh::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(3);
+            messages.push(TriangleAddField::generated_message_descriptor_data());
+            messages.push(TriangleRemoveField::generated_message_descriptor_data());
+            messages.push(TriangleReorderFields::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(0);
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
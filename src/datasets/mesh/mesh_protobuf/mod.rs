@@ -1,3 +1,4 @@
 // @generated
 
 pub mod mesh;
+pub mod mesh_evolution;
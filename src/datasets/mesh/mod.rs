@@ -1,14 +1,24 @@
+pub mod evolution;
 #[cfg(feature = "capnp")]
 pub mod mesh_capnp;
+#[cfg(feature = "capnp")]
+pub mod mesh_evolution_capnp;
 #[cfg(feature = "flatbuffers")]
 #[path = "mesh_generated.rs"]
 #[allow(unused_imports, clippy::all)]
 pub mod mesh_fb;
 #[cfg(feature = "prost")]
-#[path = "prost.mesh.rs"]
-pub mod mesh_prost;
+pub mod mesh_prost {
+    include!("prost.mesh.rs");
+
+    /// Newer versions of some messages, used by the schema evolution checks.
+    pub mod evolution {
+        include!("prost.mesh.evolution.rs");
+    }
+}
 #[cfg(feature = "protobuf")]
 pub mod mesh_protobuf;
+pub mod partial;
 
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -103,6 +113,17 @@ impl<'a> bench_capnp::Serialize<'a> for Vector3 {
     }
 }
 
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Vector3 {
+    fn deserialize_capnp(vector: Self::Reader) -> capnp::Result<Self> {
+        Ok(Vector3 {
+            x: vector.get_x(),
+            y: vector.get_y(),
+            z: vector.get_z(),
+        })
+    }
+}
+
 #[cfg(feature = "prost")]
 impl bench_prost::Serialize for Vector3 {
     type Message = mesh_prost::Vector3;
@@ -235,6 +256,18 @@ impl<'a> bench_capnp::Serialize<'a> for Triangle {
     }
 }
 
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Triangle {
+    fn deserialize_capnp(triangle: Self::Reader) -> capnp::Result<Self> {
+        Ok(Triangle {
+            v0: Vector3::deserialize_capnp(triangle.get_v0()?)?,
+            v1: Vector3::deserialize_capnp(triangle.get_v1()?)?,
+            v2: Vector3::deserialize_capnp(triangle.get_v2()?)?,
+            normal: Vector3::deserialize_capnp(triangle.get_normal()?)?,
+        })
+    }
+}
+
 #[cfg(feature = "prost")]
 impl bench_prost::Serialize for Triangle {
     type Message = mesh_prost::Triangle;
@@ -0,0 +1,142 @@
+//! [`Mesh`] with only the fields read by the zero-copy `read` benchmarks.
+
+use criterion::{black_box, Criterion};
+
+#[cfg(feature = "prost")]
+use super::mesh_prost;
+use super::{Mesh, Vector3};
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+use crate::codec::{for_each_codec, for_each_other_codec, Codec};
+use crate::partial::{self, Partial};
+
+/// [`Triangle`](super::Triangle) with only `normal`.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct TriangleRead {
+    #[cfg_attr(feature = "bilrost", bilrost(4))]
+    #[cfg_attr(feature = "minicbor", n(3))]
+    pub normal: Vector3,
+}
+
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct MeshRead {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub triangles: Vec<TriangleRead>,
+}
+
+impl Partial<Mesh> for MeshRead {
+    fn read_full(full: &Mesh) {
+        for triangle in full.triangles.iter() {
+            black_box(&triangle.normal);
+        }
+    }
+
+    fn agrees(&self, full: &Mesh) -> bool {
+        self.triangles.len() == full.triangles.len()
+            && self
+                .triangles
+                .iter()
+                .zip(full.triangles.iter())
+                .all(|(read, triangle)| read.normal == triangle.normal)
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    fn read_flexbuffers(root: flexbuffers::Reader<&[u8]>) -> Self {
+        let triangles = root.as_map().idx("triangles").as_vector();
+        Self {
+            triangles: triangles
+                .iter()
+                .map(|triangle| {
+                    let normal = triangle.as_map().idx("normal").as_map();
+                    TriangleRead {
+                        normal: Vector3 {
+                            x: normal.idx("x").as_f32(),
+                            y: normal.idx("y").as_f32(),
+                            z: normal.idx("z").as_f32(),
+                        },
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_read {
+    use super::mesh_prost::Vector3;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TriangleRead {
+        #[prost(message, optional, tag = "4")]
+        pub normal: Option<Vector3>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MeshRead {
+        #[prost(message, repeated, tag = "1")]
+        pub triangles: Vec<TriangleRead>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for MeshRead {
+    type Message = prost_read::MeshRead;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            triangles: self
+                .triangles
+                .iter()
+                .map(|triangle| prost_read::TriangleRead {
+                    normal: Some(triangle.normal.serialize_pb()),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<prost_read::MeshRead> for MeshRead {
+    fn from(value: prost_read::MeshRead) -> Self {
+        MeshRead {
+            triangles: value
+                .triangles
+                .into_iter()
+                .map(|triangle| TriangleRead {
+                    normal: triangle.normal.unwrap().into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn bench(name: &str, c: &mut Criterion, data: &Mesh) {
+    for_each_codec!(bench_codec, name, c, data);
+    for_each_other_codec!(bench_other_codec, name, c, data);
+}
+
+fn bench_codec<C>(lib: &str, name: &str, c: &mut Criterion, data: &Mesh)
+where
+    C: Codec<Mesh> + Codec<MeshRead>,
+{
+    partial::bench::<C, Mesh, MeshRead>(name, lib, c, data);
+}
+
+fn bench_other_codec<C: Codec<Mesh>>(lib: &str, name: &str, c: &mut Criterion, data: &Mesh) {
+    partial::bench_full::<C, Mesh, MeshRead>(name, lib, c, data);
+}
@@ -0,0 +1,34 @@
+// This file is @generated by prost-build.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TriangleAddField {
+    #[prost(message, optional, tag = "1")]
+    pub v0: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "2")]
+    pub v1: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "3")]
+    pub v2: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "4")]
+    pub normal: ::core::option::Option<super::Vector3>,
+    #[prost(uint32, optional, tag = "5")]
+    pub color: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TriangleRemoveField {
+    #[prost(message, optional, tag = "1")]
+    pub v0: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "2")]
+    pub v1: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "3")]
+    pub v2: ::core::option::Option<super::Vector3>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TriangleReorderFields {
+    #[prost(message, optional, tag = "4")]
+    pub normal: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "1")]
+    pub v0: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "2")]
+    pub v1: ::core::option::Option<super::Vector3>,
+    #[prost(message, optional, tag = "3")]
+    pub v2: ::core::option::Option<super::Vector3>,
+}
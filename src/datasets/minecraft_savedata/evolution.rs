@@ -0,0 +1,820 @@
+//! Newer versions of [`Item`] and [`GameType`] for the schema evolution checks.
+
+#[cfg(feature = "flatbuffers")]
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+#[cfg(feature = "capnp")]
+use super::cp;
+#[cfg(feature = "capnp")]
+use super::minecraft_savedata_evolution_capnp as cp_v2;
+#[cfg(feature = "flatbuffers")]
+use super::minecraft_savedata_evolution_fb::minecraft_savedata_evolution as fb_v2;
+#[cfg(feature = "prost")]
+use super::pb::{self, evolution as pb_v2};
+#[cfg(feature = "protobuf")]
+use super::rpb::{self, minecraft_savedata_evolution as rpb_v2};
+use super::{GameType, Item, Players};
+#[cfg(feature = "capnp")]
+use crate::bench_capnp;
+#[cfg(feature = "flatbuffers")]
+use crate::bench_flatbuffers;
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+use crate::codec::{for_each_codec, Codec};
+use crate::evolution::{self, Change, Evolution};
+
+/// [`Item`] with an optional `damage` field added at the end.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ItemAddField {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub count: i8,
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub slot: u8,
+    #[cfg_attr(feature = "minicbor", b(2))]
+    pub id: String,
+    #[cfg_attr(feature = "minicbor", n(3))]
+    pub damage: Option<u32>,
+}
+
+impl Evolution<Item> for ItemAddField {
+    const CHANGE: Change = Change::AddField;
+
+    fn upgrade(old: &Item) -> Self {
+        Self {
+            count: old.count,
+            slot: old.slot,
+            id: old.id.clone(),
+            damage: Some(1561),
+        }
+    }
+
+    fn agrees(&self, old: &Item) -> bool {
+        self.count == old.count && self.slot == old.slot && self.id == old.id
+    }
+}
+
+/// [`Item`] with the `slot` field removed.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ItemRemoveField {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(varint)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub count: i8,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", b(2))]
+    pub id: String,
+}
+
+impl Evolution<Item> for ItemRemoveField {
+    const CHANGE: Change = Change::RemoveField;
+
+    fn upgrade(old: &Item) -> Self {
+        Self {
+            count: old.count,
+            id: old.id.clone(),
+        }
+    }
+
+    fn agrees(&self, old: &Item) -> bool {
+        self.count == old.count && self.id == old.id
+    }
+}
+
+/// [`Item`] with the `id` field moved to the front. Tagged formats keep the original tags.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ItemReorderFields {
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", b(2))]
+    pub id: String,
+    #[cfg_attr(feature = "bilrost", bilrost(tag(1), encoding(varint)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub count: i8,
+    #[cfg_attr(feature = "bilrost", bilrost(tag(2), encoding(varint)))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub slot: u8,
+}
+
+impl Evolution<Item> for ItemReorderFields {
+    const CHANGE: Change = Change::ReorderFields;
+
+    fn upgrade(old: &Item) -> Self {
+        Self {
+            id: old.id.clone(),
+            count: old.count,
+            slot: old.slot,
+        }
+    }
+
+    fn agrees(&self, old: &Item) -> bool {
+        self.count == old.count && self.slot == old.slot && self.id == old.id
+    }
+}
+
+/// Carries a [`GameType`] so that enums can be sent through the same libraries as structs.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GameMode {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub game_type: GameType,
+}
+
+/// [`GameType`] with a `Hardcore` variant added at the end.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bilrost", derive(bilrost::Enumeration))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum GameTypeAddVariant {
+    #[cfg_attr(feature = "bilrost", bilrost(0))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    Survival,
+    #[cfg_attr(feature = "bilrost", bilrost(1))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    Creative,
+    #[cfg_attr(feature = "bilrost", bilrost(2))]
+    #[cfg_attr(feature = "minicbor", n(2))]
+    Adventure,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", n(3))]
+    Spectator,
+    #[cfg_attr(feature = "bilrost", bilrost(4))]
+    #[cfg_attr(feature = "minicbor", n(4))]
+    Hardcore,
+}
+
+impl From<GameType> for GameTypeAddVariant {
+    fn from(value: GameType) -> Self {
+        match value {
+            GameType::Survival => Self::Survival,
+            GameType::Creative => Self::Creative,
+            GameType::Adventure => Self::Adventure,
+            GameType::Spectator => Self::Spectator,
+        }
+    }
+}
+
+/// [`GameMode`] using [`GameTypeAddVariant`].
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GameModeAddVariant {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub game_type: GameTypeAddVariant,
+}
+
+impl Evolution<GameMode> for GameModeAddVariant {
+    const CHANGE: Change = Change::AddVariant;
+
+    fn upgrade(_: &GameMode) -> Self {
+        Self {
+            game_type: GameTypeAddVariant::Hardcore,
+        }
+    }
+
+    fn agrees(&self, old: &GameMode) -> bool {
+        self.game_type == GameTypeAddVariant::from(old.game_type)
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for ItemAddField {
+    type Reader = cp_v2::item_add_field::Reader<'a>;
+    type Builder = cp_v2::item_add_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        builder.set_count(self.count);
+        builder.set_slot(self.slot);
+        builder.set_id(Reader(self.id.as_bytes()));
+        let mut damage = builder.reborrow().init_damage();
+        if let Some(value) = self.damage {
+            damage.set_some(value);
+        } else {
+            damage.set_none(());
+        }
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for ItemAddField {
+    fn deserialize_capnp(item: Self::Reader) -> capnp::Result<Self> {
+        use cp_v2::item_add_field::damage;
+
+        Ok(ItemAddField {
+            count: item.get_count(),
+            slot: item.get_slot(),
+            id: item.get_id()?.to_string()?,
+            damage: match item.get_damage().which()? {
+                damage::None(()) => None,
+                damage::Some(value) => Some(value),
+            },
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for ItemRemoveField {
+    type Reader = cp_v2::item_remove_field::Reader<'a>;
+    type Builder = cp_v2::item_remove_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        builder.set_count(self.count);
+        builder.set_id(Reader(self.id.as_bytes()));
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for ItemRemoveField {
+    fn deserialize_capnp(item: Self::Reader) -> capnp::Result<Self> {
+        Ok(ItemRemoveField {
+            count: item.get_count(),
+            id: item.get_id()?.to_string()?,
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for ItemReorderFields {
+    type Reader = cp_v2::item_reorder_fields::Reader<'a>;
+    type Builder = cp_v2::item_reorder_fields::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use capnp::text::Reader;
+
+        builder.set_id(Reader(self.id.as_bytes()));
+        builder.set_count(self.count);
+        builder.set_slot(self.slot);
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for ItemReorderFields {
+    fn deserialize_capnp(item: Self::Reader) -> capnp::Result<Self> {
+        Ok(ItemReorderFields {
+            id: item.get_id()?.to_string()?,
+            count: item.get_count(),
+            slot: item.get_slot(),
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for GameMode {
+    type Reader = cp_v2::game_mode::Reader<'a>;
+    type Builder = cp_v2::game_mode::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        builder.set_game_type(self.game_type.into());
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for GameMode {
+    fn deserialize_capnp(game_mode: Self::Reader) -> capnp::Result<Self> {
+        let game_type = match game_mode.get_game_type()? {
+            cp::GameType::Survival => GameType::Survival,
+            cp::GameType::Creative => GameType::Creative,
+            cp::GameType::Adventure => GameType::Adventure,
+            cp::GameType::Spectator => GameType::Spectator,
+        };
+        Ok(GameMode { game_type })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for GameModeAddVariant {
+    type Reader = cp_v2::game_mode_add_variant::Reader<'a>;
+    type Builder = cp_v2::game_mode_add_variant::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use cp_v2::GameTypeAddVariant as Cp;
+
+        let game_type = match self.game_type {
+            GameTypeAddVariant::Survival => Cp::Survival,
+            GameTypeAddVariant::Creative => Cp::Creative,
+            GameTypeAddVariant::Adventure => Cp::Adventure,
+            GameTypeAddVariant::Spectator => Cp::Spectator,
+            GameTypeAddVariant::Hardcore => Cp::Hardcore,
+        };
+        builder.set_game_type(game_type);
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for GameModeAddVariant {
+    fn deserialize_capnp(game_mode: Self::Reader) -> capnp::Result<Self> {
+        use cp_v2::GameTypeAddVariant as Cp;
+
+        let game_type = match game_mode.get_game_type()? {
+            Cp::Survival => GameTypeAddVariant::Survival,
+            Cp::Creative => GameTypeAddVariant::Creative,
+            Cp::Adventure => GameTypeAddVariant::Adventure,
+            Cp::Spectator => GameTypeAddVariant::Spectator,
+            Cp::Hardcore => GameTypeAddVariant::Hardcore,
+        };
+        Ok(GameModeAddVariant { game_type })
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for ItemAddField {
+    type Target = fb_v2::ItemAddField<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let id = fbb.create_string(&self.id);
+
+        let mut builder = fb_v2::ItemAddFieldBuilder::new(fbb);
+        builder.add_count(self.count);
+        builder.add_slot(self.slot);
+        builder.add_id(id);
+        if let Some(damage) = self.damage {
+            builder.add_damage(damage);
+        }
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for ItemAddField {
+    fn deserialize_fb(item: fb_v2::ItemAddField<'a>) -> Self {
+        ItemAddField {
+            count: item.count(),
+            slot: item.slot(),
+            id: item.id().to_owned(),
+            damage: item.damage(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for ItemRemoveField {
+    type Target = fb_v2::ItemRemoveField<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let id = fbb.create_string(&self.id);
+
+        let mut builder = fb_v2::ItemRemoveFieldBuilder::new(fbb);
+        builder.add_count(self.count);
+        builder.add_id(id);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for ItemRemoveField {
+    fn deserialize_fb(item: fb_v2::ItemRemoveField<'a>) -> Self {
+        ItemRemoveField {
+            count: item.count(),
+            id: item.id().to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for ItemReorderFields {
+    type Target = fb_v2::ItemReorderFields<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let id = fbb.create_string(&self.id);
+
+        let mut builder = fb_v2::ItemReorderFieldsBuilder::new(fbb);
+        builder.add_id(id);
+        builder.add_count(self.count);
+        builder.add_slot(self.slot);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for ItemReorderFields {
+    fn deserialize_fb(item: fb_v2::ItemReorderFields<'a>) -> Self {
+        ItemReorderFields {
+            id: item.id().to_owned(),
+            count: item.count(),
+            slot: item.slot(),
+        }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for GameMode {
+    type Target = fb_v2::GameMode<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let game_type = match self.game_type {
+            GameType::Survival => fb_v2::GameType::Survival,
+            GameType::Creative => fb_v2::GameType::Creative,
+            GameType::Adventure => fb_v2::GameType::Adventure,
+            GameType::Spectator => fb_v2::GameType::Spectator,
+        };
+
+        let mut builder = fb_v2::GameModeBuilder::new(fbb);
+        builder.add_game_type(game_type);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for GameMode {
+    fn deserialize_fb(game_mode: fb_v2::GameMode<'a>) -> Self {
+        let game_type = match game_mode.game_type() {
+            fb_v2::GameType::Survival => GameType::Survival,
+            fb_v2::GameType::Creative => GameType::Creative,
+            fb_v2::GameType::Adventure => GameType::Adventure,
+            fb_v2::GameType::Spectator => GameType::Spectator,
+            game_type => panic!("invalid game type {:?}", game_type),
+        };
+        GameMode { game_type }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for GameModeAddVariant {
+    type Target = fb_v2::GameModeAddVariant<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        let game_type = match self.game_type {
+            GameTypeAddVariant::Survival => fb_v2::GameTypeAddVariant::Survival,
+            GameTypeAddVariant::Creative => fb_v2::GameTypeAddVariant::Creative,
+            GameTypeAddVariant::Adventure => fb_v2::GameTypeAddVariant::Adventure,
+            GameTypeAddVariant::Spectator => fb_v2::GameTypeAddVariant::Spectator,
+            GameTypeAddVariant::Hardcore => fb_v2::GameTypeAddVariant::Hardcore,
+        };
+
+        let mut builder = fb_v2::GameModeAddVariantBuilder::new(fbb);
+        builder.add_game_type(game_type);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for GameModeAddVariant {
+    fn deserialize_fb(game_mode: fb_v2::GameModeAddVariant<'a>) -> Self {
+        let game_type = match game_mode.game_type() {
+            fb_v2::GameTypeAddVariant::Survival => GameTypeAddVariant::Survival,
+            fb_v2::GameTypeAddVariant::Creative => GameTypeAddVariant::Creative,
+            fb_v2::GameTypeAddVariant::Adventure => GameTypeAddVariant::Adventure,
+            fb_v2::GameTypeAddVariant::Spectator => GameTypeAddVariant::Spectator,
+            fb_v2::GameTypeAddVariant::Hardcore => GameTypeAddVariant::Hardcore,
+            game_type => panic!("invalid game type {:?}", game_type),
+        };
+        GameModeAddVariant { game_type }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for ItemAddField {
+    type Message = pb_v2::ItemAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            count: self.count as i32,
+            slot: self.slot as u32,
+            id: self.id.clone(),
+            damage: self.damage,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::ItemAddField> for ItemAddField {
+    fn from(value: pb_v2::ItemAddField) -> Self {
+        ItemAddField {
+            count: value.count.try_into().unwrap(),
+            slot: value.slot.try_into().unwrap(),
+            id: value.id,
+            damage: value.damage,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for ItemRemoveField {
+    type Message = pb_v2::ItemRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            count: self.count as i32,
+            id: self.id.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::ItemRemoveField> for ItemRemoveField {
+    fn from(value: pb_v2::ItemRemoveField) -> Self {
+        ItemRemoveField {
+            count: value.count.try_into().unwrap(),
+            id: value.id,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for ItemReorderFields {
+    type Message = pb_v2::ItemReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            id: self.id.clone(),
+            count: self.count as i32,
+            slot: self.slot as u32,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::ItemReorderFields> for ItemReorderFields {
+    fn from(value: pb_v2::ItemReorderFields) -> Self {
+        ItemReorderFields {
+            id: value.id,
+            count: value.count.try_into().unwrap(),
+            slot: value.slot.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for GameMode {
+    type Message = pb_v2::GameMode;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            game_type: pb::GameType::from(self.game_type) as i32,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::GameMode> for GameMode {
+    fn from(value: pb_v2::GameMode) -> Self {
+        GameMode {
+            game_type: pb::GameType::try_from(value.game_type).unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for GameModeAddVariant {
+    type Message = pb_v2::GameModeAddVariant;
+
+    fn serialize_pb(&self) -> Self::Message {
+        use pb_v2::GameTypeAddVariant as Pb;
+
+        let game_type = match self.game_type {
+            GameTypeAddVariant::Survival => Pb::Survival,
+            GameTypeAddVariant::Creative => Pb::Creative,
+            GameTypeAddVariant::Adventure => Pb::Adventure,
+            GameTypeAddVariant::Spectator => Pb::Spectator,
+            GameTypeAddVariant::Hardcore => Pb::Hardcore,
+        };
+        Self::Message {
+            game_type: game_type as i32,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::GameModeAddVariant> for GameModeAddVariant {
+    fn from(value: pb_v2::GameModeAddVariant) -> Self {
+        use pb_v2::GameTypeAddVariant as Pb;
+
+        let game_type = match Pb::try_from(value.game_type).unwrap() {
+            Pb::Survival => GameTypeAddVariant::Survival,
+            Pb::Creative => GameTypeAddVariant::Creative,
+            Pb::Adventure => GameTypeAddVariant::Adventure,
+            Pb::Spectator => GameTypeAddVariant::Spectator,
+            Pb::Hardcore => GameTypeAddVariant::Hardcore,
+        };
+        GameModeAddVariant { game_type }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for ItemAddField {
+    type Message = rpb_v2::ItemAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            count: self.count as i32,
+            slot: self.slot as u32,
+            id: self.id.clone(),
+            damage: self.damage,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::ItemAddField> for ItemAddField {
+    fn from(value: rpb_v2::ItemAddField) -> Self {
+        ItemAddField {
+            count: value.count.try_into().unwrap(),
+            slot: value.slot.try_into().unwrap(),
+            id: value.id,
+            damage: value.damage,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for ItemRemoveField {
+    type Message = rpb_v2::ItemRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            count: self.count as i32,
+            id: self.id.clone(),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::ItemRemoveField> for ItemRemoveField {
+    fn from(value: rpb_v2::ItemRemoveField) -> Self {
+        ItemRemoveField {
+            count: value.count.try_into().unwrap(),
+            id: value.id,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for ItemReorderFields {
+    type Message = rpb_v2::ItemReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            id: self.id.clone(),
+            count: self.count as i32,
+            slot: self.slot as u32,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::ItemReorderFields> for ItemReorderFields {
+    fn from(value: rpb_v2::ItemReorderFields) -> Self {
+        ItemReorderFields {
+            id: value.id,
+            count: value.count.try_into().unwrap(),
+            slot: value.slot.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for GameMode {
+    type Message = rpb_v2::GameMode;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            game_type: protobuf::EnumOrUnknown::new(rpb::minecraft_savedata::GameType::from(
+                self.game_type,
+            )),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::GameMode> for GameMode {
+    fn from(value: rpb_v2::GameMode) -> Self {
+        GameMode {
+            game_type: value.game_type.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for GameModeAddVariant {
+    type Message = rpb_v2::GameModeAddVariant;
+
+    fn serialize_pb(&self) -> Self::Message {
+        use rpb_v2::GameTypeAddVariant as Pb;
+
+        let game_type = match self.game_type {
+            GameTypeAddVariant::Survival => Pb::SURVIVAL,
+            GameTypeAddVariant::Creative => Pb::CREATIVE,
+            GameTypeAddVariant::Adventure => Pb::ADVENTURE,
+            GameTypeAddVariant::Spectator => Pb::SPECTATOR,
+            GameTypeAddVariant::Hardcore => Pb::HARDCORE,
+        };
+        Self::Message {
+            game_type: protobuf::EnumOrUnknown::new(game_type),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::GameModeAddVariant> for GameModeAddVariant {
+    fn from(value: rpb_v2::GameModeAddVariant) -> Self {
+        use rpb_v2::GameTypeAddVariant as Pb;
+
+        let game_type = match value.game_type.unwrap() {
+            Pb::SURVIVAL => GameTypeAddVariant::Survival,
+            Pb::CREATIVE => GameTypeAddVariant::Creative,
+            Pb::ADVENTURE => GameTypeAddVariant::Adventure,
+            Pb::SPECTATOR => GameTypeAddVariant::Spectator,
+            Pb::HARDCORE => GameTypeAddVariant::Hardcore,
+        };
+        GameModeAddVariant { game_type }
+    }
+}
+
+pub fn check(name: &str, data: &Players) {
+    let items = data
+        .players
+        .iter()
+        .flat_map(|player| player.inventory.iter().cloned())
+        .collect::<Vec<_>>();
+    let game_modes = data
+        .players
+        .iter()
+        .map(|player| GameMode {
+            game_type: player.game_type,
+        })
+        .collect::<Vec<_>>();
+    for_each_codec!(check_codec, name, &items, &game_modes);
+    #[cfg(feature = "capnp")]
+    check_codec::<crate::codec::Capnp>("capnp", name, &items, &game_modes);
+    #[cfg(feature = "flatbuffers")]
+    check_codec::<crate::codec::Flatbuffers>("flatbuffers", name, &items, &game_modes);
+    #[cfg(feature = "protobuf")]
+    check_codec::<crate::codec::Protobuf>("protobuf", name, &items, &game_modes);
+}
+
+fn check_codec<C>(lib: &str, name: &str, items: &[Item], game_modes: &[GameMode])
+where
+    C: Codec<Item>
+        + Codec<ItemAddField>
+        + Codec<ItemRemoveField>
+        + Codec<ItemReorderFields>
+        + Codec<GameMode>
+        + Codec<GameModeAddVariant>,
+{
+    evolution::check::<C, Item, ItemAddField>(name, lib, items);
+    evolution::check::<C, Item, ItemRemoveField>(name, lib, items);
+    evolution::check::<C, Item, ItemReorderFields>(name, lib, items);
+    evolution::check::<C, GameMode, GameModeAddVariant>(name, lib, game_modes);
+}
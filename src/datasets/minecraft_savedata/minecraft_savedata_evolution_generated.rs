@@ -0,0 +1,802 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod minecraft_savedata_evolution {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_GAME_TYPE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_GAME_TYPE: i8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_GAME_TYPE: [GameType; 4] = [
+  GameType::Survival,
+  GameType::Creative,
+  GameType::Adventure,
+  GameType::Spectator,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct GameType(pub i8);
+#[allow(non_upper_case_globals)]
+impl GameType {
+  pub const Survival: Self = Self(0);
+  pub const Creative: Self = Self(1);
+  pub const Adventure: Self = Self(2);
+  pub const Spectator: Self = Self(3);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Survival,
+    Self::Creative,
+    Self::Adventure,
+    Self::Spectator,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Survival => Some("Survival"),
+      Self::Creative => Some("Creative"),
+      Self::Adventure => Some("Adventure"),
+      Self::Spectator => Some("Spectator"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for GameType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for GameType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for GameType {
+    type Output = GameType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for GameType {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for GameType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for GameType {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_GAME_TYPE_ADD_VARIANT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_GAME_TYPE_ADD_VARIANT: i8 = 4;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_GAME_TYPE_ADD_VARIANT: [GameTypeAddVariant; 5] = [
+  GameTypeAddVariant::Survival,
+  GameTypeAddVariant::Creative,
+  GameTypeAddVariant::Adventure,
+  GameTypeAddVariant::Spectator,
+  GameTypeAddVariant::Hardcore,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct GameTypeAddVariant(pub i8);
+#[allow(non_upper_case_globals)]
+impl GameTypeAddVariant {
+  pub const Survival: Self = Self(0);
+  pub const Creative: Self = Self(1);
+  pub const Adventure: Self = Self(2);
+  pub const Spectator: Self = Self(3);
+  pub const Hardcore: Self = Self(4);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 4;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Survival,
+    Self::Creative,
+    Self::Adventure,
+    Self::Spectator,
+    Self::Hardcore,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Survival => Some("Survival"),
+      Self::Creative => Some("Creative"),
+      Self::Adventure => Some("Adventure"),
+      Self::Spectator => Some("Spectator"),
+      Self::Hardcore => Some("Hardcore"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for GameTypeAddVariant {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for GameTypeAddVariant {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for GameTypeAddVariant {
+    type Output = GameTypeAddVariant;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for GameTypeAddVariant {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for GameTypeAddVariant {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for GameTypeAddVariant {}
+pub enum ItemAddFieldOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ItemAddField<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ItemAddField<'a> {
+  type Inner = ItemAddField<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ItemAddField<'a> {
+  pub const VT_COUNT: flatbuffers::VOffsetT = 4;
+  pub const VT_SLOT: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
+  pub const VT_DAMAGE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ItemAddField { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ItemAddFieldArgs<'args>
+  ) -> flatbuffers::WIPOffset<ItemAddField<'bldr>> {
+    let mut builder = ItemAddFieldBuilder::new(_fbb);
+    if let Some(x) = args.damage { builder.add_damage(x); }
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_slot(args.slot);
+    builder.add_count(args.count);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn count(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(ItemAddField::VT_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn slot(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(ItemAddField::VT_SLOT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ItemAddField::VT_ID, None).unwrap()}
+  }
+  #[inline]
+  pub fn damage(&self) -> Option<u32> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ItemAddField::VT_DAMAGE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ItemAddField<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i8>("count", Self::VT_COUNT, false)?
+     .visit_field::<u8>("slot", Self::VT_SLOT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .visit_field::<u32>("damage", Self::VT_DAMAGE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ItemAddFieldArgs<'a> {
+    pub count: i8,
+    pub slot: u8,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub damage: Option<u32>,
+}
+impl<'a> Default for ItemAddFieldArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ItemAddFieldArgs {
+      count: 0,
+      slot: 0,
+      id: None, // required field
+      damage: None,
+    }
+  }
+}
+
+pub struct ItemAddFieldBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ItemAddFieldBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_count(&mut self, count: i8) {
+    self.fbb_.push_slot::<i8>(ItemAddField::VT_COUNT, count, 0);
+  }
+  #[inline]
+  pub fn add_slot(&mut self, slot: u8) {
+    self.fbb_.push_slot::<u8>(ItemAddField::VT_SLOT, slot, 0);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ItemAddField::VT_ID, id);
+  }
+  #[inline]
+  pub fn add_damage(&mut self, damage: u32) {
+    self.fbb_.push_slot_always::<u32>(ItemAddField::VT_DAMAGE, damage);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ItemAddFieldBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ItemAddFieldBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ItemAddField<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ItemAddField::VT_ID,"id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ItemAddField<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ItemAddField");
+      ds.field("count", &self.count());
+      ds.field("slot", &self.slot());
+      ds.field("id", &self.id());
+      ds.field("damage", &self.damage());
+      ds.finish()
+  }
+}
+pub enum ItemRemoveFieldOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ItemRemoveField<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ItemRemoveField<'a> {
+  type Inner = ItemRemoveField<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ItemRemoveField<'a> {
+  pub const VT_COUNT: flatbuffers::VOffsetT = 4;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ItemRemoveField { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ItemRemoveFieldArgs<'args>
+  ) -> flatbuffers::WIPOffset<ItemRemoveField<'bldr>> {
+    let mut builder = ItemRemoveFieldBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_count(args.count);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn count(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(ItemRemoveField::VT_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ItemRemoveField::VT_ID, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ItemRemoveField<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i8>("count", Self::VT_COUNT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ItemRemoveFieldArgs<'a> {
+    pub count: i8,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ItemRemoveFieldArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ItemRemoveFieldArgs {
+      count: 0,
+      id: None, // required field
+    }
+  }
+}
+
+pub struct ItemRemoveFieldBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ItemRemoveFieldBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_count(&mut self, count: i8) {
+    self.fbb_.push_slot::<i8>(ItemRemoveField::VT_COUNT, count, 0);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ItemRemoveField::VT_ID, id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ItemRemoveFieldBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ItemRemoveFieldBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ItemRemoveField<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ItemRemoveField::VT_ID,"id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ItemRemoveField<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ItemRemoveField");
+      ds.field("count", &self.count());
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+pub enum ItemReorderFieldsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ItemReorderFields<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ItemReorderFields<'a> {
+  type Inner = ItemReorderFields<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ItemReorderFields<'a> {
+  pub const VT_COUNT: flatbuffers::VOffsetT = 4;
+  pub const VT_SLOT: flatbuffers::VOffsetT = 6;
+  pub const VT_ID: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ItemReorderFields { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ItemReorderFieldsArgs<'args>
+  ) -> flatbuffers::WIPOffset<ItemReorderFields<'bldr>> {
+    let mut builder = ItemReorderFieldsBuilder::new(_fbb);
+    if let Some(x) = args.id { builder.add_id(x); }
+    builder.add_slot(args.slot);
+    builder.add_count(args.count);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn count(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(ItemReorderFields::VT_COUNT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn slot(&self) -> u8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u8>(ItemReorderFields::VT_SLOT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn id(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ItemReorderFields::VT_ID, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ItemReorderFields<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i8>("count", Self::VT_COUNT, false)?
+     .visit_field::<u8>("slot", Self::VT_SLOT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("id", Self::VT_ID, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ItemReorderFieldsArgs<'a> {
+    pub count: i8,
+    pub slot: u8,
+    pub id: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ItemReorderFieldsArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ItemReorderFieldsArgs {
+      count: 0,
+      slot: 0,
+      id: None, // required field
+    }
+  }
+}
+
+pub struct ItemReorderFieldsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ItemReorderFieldsBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_count(&mut self, count: i8) {
+    self.fbb_.push_slot::<i8>(ItemReorderFields::VT_COUNT, count, 0);
+  }
+  #[inline]
+  pub fn add_slot(&mut self, slot: u8) {
+    self.fbb_.push_slot::<u8>(ItemReorderFields::VT_SLOT, slot, 0);
+  }
+  #[inline]
+  pub fn add_id(&mut self, id: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ItemReorderFields::VT_ID, id);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ItemReorderFieldsBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ItemReorderFieldsBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ItemReorderFields<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ItemReorderFields::VT_ID,"id");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ItemReorderFields<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ItemReorderFields");
+      ds.field("count", &self.count());
+      ds.field("slot", &self.slot());
+      ds.field("id", &self.id());
+      ds.finish()
+  }
+}
+pub enum GameModeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GameMode<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GameMode<'a> {
+  type Inner = GameMode<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GameMode<'a> {
+  pub const VT_GAME_TYPE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GameMode { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GameModeArgs
+  ) -> flatbuffers::WIPOffset<GameMode<'bldr>> {
+    let mut builder = GameModeBuilder::new(_fbb);
+    builder.add_game_type(args.game_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn game_type(&self) -> GameType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<GameType>(GameMode::VT_GAME_TYPE, Some(GameType::Survival)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GameMode<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<GameType>("game_type", Self::VT_GAME_TYPE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GameModeArgs {
+    pub game_type: GameType,
+}
+impl<'a> Default for GameModeArgs {
+  #[inline]
+  fn default() -> Self {
+    GameModeArgs {
+      game_type: GameType::Survival,
+    }
+  }
+}
+
+pub struct GameModeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GameModeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_game_type(&mut self, game_type: GameType) {
+    self.fbb_.push_slot::<GameType>(GameMode::VT_GAME_TYPE, game_type, GameType::Survival);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GameModeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GameModeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GameMode<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GameMode<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GameMode");
+      ds.field("game_type", &self.game_type());
+      ds.finish()
+  }
+}
+pub enum GameModeAddVariantOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct GameModeAddVariant<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for GameModeAddVariant<'a> {
+  type Inner = GameModeAddVariant<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> GameModeAddVariant<'a> {
+  pub const VT_GAME_TYPE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    GameModeAddVariant { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args GameModeAddVariantArgs
+  ) -> flatbuffers::WIPOffset<GameModeAddVariant<'bldr>> {
+    let mut builder = GameModeAddVariantBuilder::new(_fbb);
+    builder.add_game_type(args.game_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn game_type(&self) -> GameTypeAddVariant {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<GameTypeAddVariant>(GameModeAddVariant::VT_GAME_TYPE, Some(GameTypeAddVariant::Survival)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for GameModeAddVariant<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<GameTypeAddVariant>("game_type", Self::VT_GAME_TYPE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct GameModeAddVariantArgs {
+    pub game_type: GameTypeAddVariant,
+}
+impl<'a> Default for GameModeAddVariantArgs {
+  #[inline]
+  fn default() -> Self {
+    GameModeAddVariantArgs {
+      game_type: GameTypeAddVariant::Survival,
+    }
+  }
+}
+
+pub struct GameModeAddVariantBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GameModeAddVariantBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_game_type(&mut self, game_type: GameTypeAddVariant) {
+    self.fbb_.push_slot::<GameTypeAddVariant>(GameModeAddVariant::VT_GAME_TYPE, game_type, GameTypeAddVariant::Survival);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GameModeAddVariantBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    GameModeAddVariantBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<GameModeAddVariant<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for GameModeAddVariant<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("GameModeAddVariant");
+      ds.field("game_type", &self.game_type());
+      ds.finish()
+  }
+}
+}  // pub mod minecraft_savedata_evolution
+
@@ -0,0 +1,874 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `minecraft_savedata_evolution.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:prost.minecraft_savedata.evolution.ItemAddField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ItemAddField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemAddField.count)
+    pub count: i32,
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemAddField.slot)
+    pub slot: u32,
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemAddField.id)
+    pub id: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemAddField.damage)
+    pub damage: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.minecraft_savedata.evolution.ItemAddField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ItemAddField {
+    fn default() -> &'a ItemAddField {
+        <ItemAddField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ItemAddField {
+    pub fn new() -> ItemAddField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &ItemAddField| { &m.count },
+            |m: &mut ItemAddField| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "slot",
+            |m: &ItemAddField| { &m.slot },
+            |m: &mut ItemAddField| { &mut m.slot },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ItemAddField| { &m.id },
+            |m: &mut ItemAddField| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "damage",
+            |m: &ItemAddField| { &m.damage },
+            |m: &mut ItemAddField| { &mut m.damage },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ItemAddField>(
+            "ItemAddField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ItemAddField {
+    const NAME: &'static str = "ItemAddField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.count = is.read_int32()?;
+                },
+                16 => {
+                    self.slot = is.read_uint32()?;
+                },
+                26 => {
+                    self.id = is.read_string()?;
+                },
+                32 => {
+                    self.damage = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.count);
+        }
+        if self.slot != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.slot);
+        }
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.id);
+        }
+        if let Some(v) = self.damage {
+            my_size += ::protobuf::rt::uint32_size(4, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.count != 0 {
+            os.write_int32(1, self.count)?;
+        }
+        if self.slot != 0 {
+            os.write_uint32(2, self.slot)?;
+        }
+        if !self.id.is_empty() {
+            os.write_string(3, &self.id)?;
+        }
+        if let Some(v) = self.damage {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ItemAddField {
+        ItemAddField::new()
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.slot = 0;
+        self.id.clear();
+        self.damage = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ItemAddField {
+        static instance: ItemAddField = ItemAddField {
+            count: 0,
+            slot: 0,
+            id: ::std::string::String::new(),
+            damage: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ItemAddField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ItemAddField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ItemAddField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ItemAddField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.minecraft_savedata.evolution.ItemRemoveField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ItemRemoveField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemRemoveField.count)
+    pub count: i32,
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemRemoveField.id)
+    pub id: ::std::string::String,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.minecraft_savedata.evolution.ItemRemoveField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ItemRemoveField {
+    fn default() -> &'a ItemRemoveField {
+        <ItemRemoveField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ItemRemoveField {
+    pub fn new() -> ItemRemoveField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &ItemRemoveField| { &m.count },
+            |m: &mut ItemRemoveField| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ItemRemoveField| { &m.id },
+            |m: &mut ItemRemoveField| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ItemRemoveField>(
+            "ItemRemoveField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ItemRemoveField {
+    const NAME: &'static str = "ItemRemoveField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.count = is.read_int32()?;
+                },
+                26 => {
+                    self.id = is.read_string()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.count != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.count);
+        }
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.count != 0 {
+            os.write_int32(1, self.count)?;
+        }
+        if !self.id.is_empty() {
+            os.write_string(3, &self.id)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ItemRemoveField {
+        ItemRemoveField::new()
+    }
+
+    fn clear(&mut self) {
+        self.count = 0;
+        self.id.clear();
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ItemRemoveField {
+        static instance: ItemRemoveField = ItemRemoveField {
+            count: 0,
+            id: ::std::string::String::new(),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ItemRemoveField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ItemRemoveField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ItemRemoveField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ItemRemoveField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.minecraft_savedata.evolution.ItemReorderFields)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ItemReorderFields {
+    // message fields
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemReorderFields.id)
+    pub id: ::std::string::String,
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemReorderFields.count)
+    pub count: i32,
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.ItemReorderFields.slot)
+    pub slot: u32,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.minecraft_savedata.evolution.ItemReorderFields.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ItemReorderFields {
+    fn default() -> &'a ItemReorderFields {
+        <ItemReorderFields as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ItemReorderFields {
+    pub fn new() -> ItemReorderFields {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ItemReorderFields| { &m.id },
+            |m: &mut ItemReorderFields| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "count",
+            |m: &ItemReorderFields| { &m.count },
+            |m: &mut ItemReorderFields| { &mut m.count },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "slot",
+            |m: &ItemReorderFields| { &m.slot },
+            |m: &mut ItemReorderFields| { &mut m.slot },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ItemReorderFields>(
+            "ItemReorderFields",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ItemReorderFields {
+    const NAME: &'static str = "ItemReorderFields";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                26 => {
+                    self.id = is.read_string()?;
+                },
+                8 => {
+                    self.count = is.read_int32()?;
+                },
+                16 => {
+                    self.slot = is.read_uint32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.id);
+        }
+        if self.count != 0 {
+            my_size += ::protobuf::rt::int32_size(1, self.count);
+        }
+        if self.slot != 0 {
+            my_size += ::protobuf::rt::uint32_size(2, self.slot);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if !self.id.is_empty() {
+            os.write_string(3, &self.id)?;
+        }
+        if self.count != 0 {
+            os.write_int32(1, self.count)?;
+        }
+        if self.slot != 0 {
+            os.write_uint32(2, self.slot)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ItemReorderFields {
+        ItemReorderFields::new()
+    }
+
+    fn clear(&mut self) {
+        self.id.clear();
+        self.count = 0;
+        self.slot = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ItemReorderFields {
+        static instance: ItemReorderFields = ItemReorderFields {
+            id: ::std::string::String::new(),
+            count: 0,
+            slot: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ItemReorderFields {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ItemReorderFields").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ItemReorderFields {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ItemReorderFields {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.minecraft_savedata.evolution.GameMode)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GameMode {
+    // message fields
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.GameMode.game_type)
+    pub game_type: ::protobuf::EnumOrUnknown<super::minecraft_savedata::GameType>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.minecraft_savedata.evolution.GameMode.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GameMode {
+    fn default() -> &'a GameMode {
+        <GameMode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameMode {
+    pub fn new() -> GameMode {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "game_type",
+            |m: &GameMode| { &m.game_type },
+            |m: &mut GameMode| { &mut m.game_type },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GameMode>(
+            "GameMode",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GameMode {
+    const NAME: &'static str = "GameMode";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.game_type = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.game_type != ::protobuf::EnumOrUnknown::new(super::minecraft_savedata::GameType::SURVIVAL) {
+            my_size += ::protobuf::rt::int32_size(1, self.game_type.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.game_type != ::protobuf::EnumOrUnknown::new(super::minecraft_savedata::GameType::SURVIVAL) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.game_type))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GameMode {
+        GameMode::new()
+    }
+
+    fn clear(&mut self) {
+        self.game_type = ::protobuf::EnumOrUnknown::new(super::minecraft_savedata::GameType::SURVIVAL);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GameMode {
+        static instance: GameMode = GameMode {
+            game_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GameMode {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GameMode").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GameMode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameMode {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.minecraft_savedata.evolution.GameModeAddVariant)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GameModeAddVariant {
+    // message fields
+    // @@protoc_insertion_point(field:prost.minecraft_savedata.evolution.GameModeAddVariant.game_type)
+    pub game_type: ::protobuf::EnumOrUnknown<GameTypeAddVariant>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.minecraft_savedata.evolution.GameModeAddVariant.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GameModeAddVariant {
+    fn default() -> &'a GameModeAddVariant {
+        <GameModeAddVariant as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameModeAddVariant {
+    pub fn new() -> GameModeAddVariant {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "game_type",
+            |m: &GameModeAddVariant| { &m.game_type },
+            |m: &mut GameModeAddVariant| { &mut m.game_type },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GameModeAddVariant>(
+            "GameModeAddVariant",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GameModeAddVariant {
+    const NAME: &'static str = "GameModeAddVariant";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.game_type = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.game_type != ::protobuf::EnumOrUnknown::new(GameTypeAddVariant::SURVIVAL) {
+            my_size += ::protobuf::rt::int32_size(1, self.game_type.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.game_type != ::protobuf::EnumOrUnknown::new(GameTypeAddVariant::SURVIVAL) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.game_type))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GameModeAddVariant {
+        GameModeAddVariant::new()
+    }
+
+    fn clear(&mut self) {
+        self.game_type = ::protobuf::EnumOrUnknown::new(GameTypeAddVariant::SURVIVAL);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GameModeAddVariant {
+        static instance: GameModeAddVariant = GameModeAddVariant {
+            game_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GameModeAddVariant {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GameModeAddVariant").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GameModeAddVariant {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameModeAddVariant {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:prost.minecraft_savedata.evolution.GameTypeAddVariant)
+pub enum GameTypeAddVariant {
+    // @@protoc_insertion_point(enum_value:prost.minecraft_savedata.evolution.GameTypeAddVariant.SURVIVAL)
+    SURVIVAL = 0,
+    // @@protoc_insertion_point(enum_value:prost.minecraft_savedata.evolution.GameTypeAddVariant.CREATIVE)
+    CREATIVE = 1,
+    // @@protoc_insertion_point(enum_value:prost.minecraft_savedata.evolution.GameTypeAddVariant.ADVENTURE)
+    ADVENTURE = 2,
+    // @@protoc_insertion_point(enum_value:prost.minecraft_savedata.evolution.GameTypeAddVariant.SPECTATOR)
+    SPECTATOR = 3,
+    // @@protoc_insertion_point(enum_value:prost.minecraft_savedata.evolution.GameTypeAddVariant.HARDCORE)
+    HARDCORE = 4,
+}
+
+impl ::protobuf::Enum for GameTypeAddVariant {
+    const NAME: &'static str = "GameTypeAddVariant";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<GameTypeAddVariant> {
+        match value {
+            0 => ::std::option::Option::Some(GameTypeAddVariant::SURVIVAL),
+            1 => ::std::option::Option::Some(GameTypeAddVariant::CREATIVE),
+            2 => ::std::option::Option::Some(GameTypeAddVariant::ADVENTURE),
+            3 => ::std::option::Option::Some(GameTypeAddVariant::SPECTATOR),
+            4 => ::std::option::Option::Some(GameTypeAddVariant::HARDCORE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<GameTypeAddVariant> {
+        match str {
+            "SURVIVAL" => ::std::option::Option::Some(GameTypeAddVariant::SURVIVAL),
+            "CREATIVE" => ::std::option::Option::Some(GameTypeAddVariant::CREATIVE),
+            "ADVENTURE" => ::std::option::Option::Some(GameTypeAddVariant::ADVENTURE),
+            "SPECTATOR" => ::std::option::Option::Some(GameTypeAddVariant::SPECTATOR),
+            "HARDCORE" => ::std::option::Option::Some(GameTypeAddVariant::HARDCORE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [GameTypeAddVariant] = &[
+        GameTypeAddVariant::SURVIVAL,
+        GameTypeAddVariant::CREATIVE,
+        GameTypeAddVariant::ADVENTURE,
+        GameTypeAddVariant::SPECTATOR,
+        GameTypeAddVariant::HARDCORE,
+    ];
+}
+
+impl ::protobuf::EnumFull for GameTypeAddVariant {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("GameTypeAddVariant").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for GameTypeAddVariant {
+    fn default() -> Self {
+        GameTypeAddVariant::SURVIVAL
+    }
+}
+
+impl GameTypeAddVariant {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<GameTypeAddVariant>("GameTypeAddVariant")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\"minecraft_savedata_evolution.proto\x12\"prost.minecraft_savedata.evo\
+    lution\x1a\x18minecraft_savedata.proto\"p\n\x0cItemAddField\x12\x14\n\
+    \x05count\x18\x01\x20\x01(\x05R\x05count\x12\x12\n\x04slot\x18\x02\x20\
+    \x01(\rR\x04slot\x12\x0e\n\x02id\x18\x03\x20\x01(\tR\x02id\x12\x1b\n\x06\
+    damage\x18\x04\x20\x01(\rH\0R\x06damage\x88\x01\x01B\t\n\x07_damage\"C\n\
+    \x0fItemRemoveField\x12\x14\n\x05count\x18\x01\x20\x01(\x05R\x05count\
+    \x12\x0e\n\x02id\x18\x03\x20\x01(\tR\x02idJ\x04\x08\x02\x10\x03R\x04slot\
+    \"M\n\x11ItemReorderFields\x12\x0e\n\x02id\x18\x03\x20\x01(\tR\x02id\x12\
+    \x14\n\x05count\x18\x01\x20\x01(\x05R\x05count\x12\x12\n\x04slot\x18\x02\
+    \x20\x01(\rR\x04slot\"K\n\x08GameMode\x12?\n\tgame_type\x18\x01\x20\x01(\
+    \x0e2\".prost.minecraft_savedata.GameTypeR\x08gameType\"i\n\x12GameModeA\
+    ddVariant\x12S\n\tgame_type\x18\x01\x20\x01(\x0e26.prost.minecraft_saved\
+    ata.evolution.GameTypeAddVariantR\x08gameType*\\\n\x12GameTypeAddVariant\
+    \x12\x0c\n\x08SURVIVAL\x10\0\x12\x0c\n\x08CREATIVE\x10\x01\x12\r\n\tADVE\
+    NTURE\x10\x02\x12\r\n\tSPECTATOR\x10\x03\x12\x0c\n\x08HARDCORE\x10\x04b\
+    \x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::minecraft_savedata::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(ItemAddField::generated_message_descriptor_data());
+            messages.push(ItemRemoveField::generated_message_descriptor_data());
+            messages.push(ItemReorderFields::generated_message_descriptor_data());
+            messages.push(GameMode::generated_message_descriptor_data());
+            messages.push(GameModeAddVariant::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(GameTypeAddVariant::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -1,3 +1,4 @@
 // @generated
 
 pub mod minecraft_savedata;
+pub mod minecraft_savedata_evolution;
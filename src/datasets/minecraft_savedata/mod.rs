@@ -1,14 +1,28 @@
+pub mod evolution;
 #[cfg(any(feature = "capnp", feature = "prost"))]
 pub mod minecraft_savedata_capnp;
+#[cfg(feature = "capnp")]
+pub mod minecraft_savedata_evolution_capnp;
+#[cfg(feature = "flatbuffers")]
+#[path = "minecraft_savedata_evolution_generated.rs"]
+#[allow(unused_imports, clippy::all)]
+pub mod minecraft_savedata_evolution_fb;
 #[cfg(feature = "flatbuffers")]
 #[path = "minecraft_savedata_generated.rs"]
 #[allow(unused_imports, clippy::all)]
 pub mod minecraft_savedata_fb;
 #[cfg(feature = "prost")]
-#[path = "prost.minecraft_savedata.rs"]
-pub mod minecraft_savedata_prost;
+pub mod minecraft_savedata_prost {
+    include!("prost.minecraft_savedata.rs");
+
+    /// Newer versions of some messages, used by the schema evolution checks.
+    pub mod evolution {
+        include!("prost.minecraft_savedata.evolution.rs");
+    }
+}
 #[cfg(feature = "protobuf")]
 pub mod minecraft_savedata_protobuf;
+pub mod partial;
 
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -294,6 +308,17 @@ impl<'a> bench_flatbuffers::Serialize<'a> for Item {
     }
 }
 
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for Item {
+    fn deserialize_fb(item: fb::Item<'a>) -> Self {
+        Item {
+            count: item.count(),
+            slot: item.slot(),
+            id: item.id().to_owned(),
+        }
+    }
+}
+
 #[cfg(feature = "capnp")]
 impl<'a> bench_capnp::Serialize<'a> for Item {
     type Reader = cp::item::Reader<'a>;
@@ -309,6 +334,17 @@ impl<'a> bench_capnp::Serialize<'a> for Item {
     }
 }
 
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Item {
+    fn deserialize_capnp(item: Self::Reader) -> capnp::Result<Self> {
+        Ok(Item {
+            count: item.get_count(),
+            slot: item.get_slot(),
+            id: item.get_id()?.to_string()?,
+        })
+    }
+}
+
 #[cfg(feature = "prost")]
 impl bench_prost::Serialize for Item {
     type Message = pb::Item;
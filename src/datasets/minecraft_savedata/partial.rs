@@ -0,0 +1,141 @@
+//! [`Players`] with only the fields read by the zero-copy `read` benchmarks.
+
+use criterion::{black_box, Criterion};
+
+#[cfg(feature = "prost")]
+use super::pb;
+use super::{GameType, Players};
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+use crate::codec::{for_each_codec, for_each_other_codec, Codec};
+use crate::partial::{self, Partial};
+
+/// [`Player`](super::Player) with only `game_type`.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PlayerRead {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub game_type: GameType,
+}
+
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct PlayersRead {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub players: Vec<PlayerRead>,
+}
+
+impl Partial<Players> for PlayersRead {
+    fn read_full(full: &Players) {
+        for player in full.players.iter() {
+            black_box(&player.game_type);
+        }
+    }
+
+    fn agrees(&self, full: &Players) -> bool {
+        self.players.len() == full.players.len()
+            && self
+                .players
+                .iter()
+                .zip(full.players.iter())
+                .all(|(read, player)| read.game_type == player.game_type)
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    fn read_flexbuffers(root: flexbuffers::Reader<&[u8]>) -> Self {
+        let players = root.as_map().idx("players").as_vector();
+        Self {
+            players: players
+                .iter()
+                .map(|player| PlayerRead {
+                    // Unit variants are written as their names
+                    game_type: match player.as_map().idx("game_type").as_str() {
+                        "Survival" => GameType::Survival,
+                        "Creative" => GameType::Creative,
+                        "Adventure" => GameType::Adventure,
+                        "Spectator" => GameType::Spectator,
+                        other => panic!("unknown game type {other:?}"),
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_read {
+    use super::pb::GameType;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PlayerRead {
+        #[prost(enumeration = "GameType", tag = "1")]
+        pub game_type: i32,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct PlayersRead {
+        #[prost(message, repeated, tag = "1")]
+        pub players: Vec<PlayerRead>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for PlayersRead {
+    type Message = prost_read::PlayersRead;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            players: self
+                .players
+                .iter()
+                .map(|player| prost_read::PlayerRead {
+                    game_type: pb::GameType::from(player.game_type) as i32,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<prost_read::PlayersRead> for PlayersRead {
+    fn from(value: prost_read::PlayersRead) -> Self {
+        PlayersRead {
+            players: value
+                .players
+                .into_iter()
+                .map(|player| PlayerRead {
+                    game_type: pb::GameType::try_from(player.game_type).unwrap().into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn bench(name: &str, c: &mut Criterion, data: &Players) {
+    for_each_codec!(bench_codec, name, c, data);
+    for_each_other_codec!(bench_other_codec, name, c, data);
+}
+
+fn bench_codec<C>(lib: &str, name: &str, c: &mut Criterion, data: &Players)
+where
+    C: Codec<Players> + Codec<PlayersRead>,
+{
+    partial::bench::<C, Players, PlayersRead>(name, lib, c, data);
+}
+
+fn bench_other_codec<C: Codec<Players>>(lib: &str, name: &str, c: &mut Criterion, data: &Players) {
+    partial::bench_full::<C, Players, PlayersRead>(name, lib, c, data);
+}
@@ -0,0 +1,73 @@
+// This file is @generated by prost-build.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ItemAddField {
+    #[prost(int32, tag = "1")]
+    pub count: i32,
+    #[prost(uint32, tag = "2")]
+    pub slot: u32,
+    #[prost(string, tag = "3")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "4")]
+    pub damage: ::core::option::Option<u32>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ItemRemoveField {
+    #[prost(int32, tag = "1")]
+    pub count: i32,
+    #[prost(string, tag = "3")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ItemReorderFields {
+    #[prost(string, tag = "3")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(int32, tag = "1")]
+    pub count: i32,
+    #[prost(uint32, tag = "2")]
+    pub slot: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GameMode {
+    #[prost(enumeration = "super::GameType", tag = "1")]
+    pub game_type: i32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GameModeAddVariant {
+    #[prost(enumeration = "GameTypeAddVariant", tag = "1")]
+    pub game_type: i32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum GameTypeAddVariant {
+    Survival = 0,
+    Creative = 1,
+    Adventure = 2,
+    Spectator = 3,
+    Hardcore = 4,
+}
+impl GameTypeAddVariant {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Survival => "SURVIVAL",
+            Self::Creative => "CREATIVE",
+            Self::Adventure => "ADVENTURE",
+            Self::Spectator => "SPECTATOR",
+            Self::Hardcore => "HARDCORE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SURVIVAL" => Some(Self::Survival),
+            "CREATIVE" => Some(Self::Creative),
+            "ADVENTURE" => Some(Self::Adventure),
+            "SPECTATOR" => Some(Self::Spectator),
+            "HARDCORE" => Some(Self::Hardcore),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,836 @@
+//! Newer versions of [`Guidance`] and [`EntityType`] for the schema evolution checks.
+
+#[cfg(feature = "flatbuffers")]
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+#[cfg(feature = "capnp")]
+use super::cp;
+#[cfg(feature = "capnp")]
+use super::mk48_evolution_capnp as cp_v2;
+#[cfg(feature = "flatbuffers")]
+use super::mk48_evolution_fb::mk_48_evolution as fb_v2;
+#[cfg(feature = "prost")]
+use super::pb::{self, evolution as pb_v2};
+#[cfg(feature = "protobuf")]
+use super::rpb::{self, mk48_evolution as rpb_v2};
+use super::{EntityType, Guidance, Updates};
+#[cfg(feature = "capnp")]
+use crate::bench_capnp;
+#[cfg(feature = "flatbuffers")]
+use crate::bench_flatbuffers;
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+#[cfg(feature = "protobuf")]
+use crate::bench_protobuf;
+use crate::codec::{for_each_codec, Codec};
+use crate::evolution::{self, Change, Evolution};
+
+/// [`Guidance`] with an optional `target_id` field added at the end.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GuidanceAddField {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub angle: u16,
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub submerge: bool,
+    #[cfg_attr(feature = "minicbor", n(2))]
+    pub velocity: i16,
+    #[cfg_attr(feature = "minicbor", n(3))]
+    pub target_id: Option<u32>,
+}
+
+impl Evolution<Guidance> for GuidanceAddField {
+    const CHANGE: Change = Change::AddField;
+
+    fn upgrade(old: &Guidance) -> Self {
+        Self {
+            angle: old.angle,
+            submerge: old.submerge,
+            velocity: old.velocity,
+            target_id: Some(48),
+        }
+    }
+
+    fn agrees(&self, old: &Guidance) -> bool {
+        self.angle == old.angle && self.submerge == old.submerge && self.velocity == old.velocity
+    }
+}
+
+/// [`Guidance`] with the `submerge` field removed.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GuidanceRemoveField {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub angle: u16,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", n(2))]
+    pub velocity: i16,
+}
+
+impl Evolution<Guidance> for GuidanceRemoveField {
+    const CHANGE: Change = Change::RemoveField;
+
+    fn upgrade(old: &Guidance) -> Self {
+        Self {
+            angle: old.angle,
+            velocity: old.velocity,
+        }
+    }
+
+    fn agrees(&self, old: &Guidance) -> bool {
+        self.angle == old.angle && self.velocity == old.velocity
+    }
+}
+
+/// [`Guidance`] with the `velocity` field moved to the front. Tagged formats keep the original
+/// tags.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GuidanceReorderFields {
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", n(2))]
+    pub velocity: i16,
+    #[cfg_attr(feature = "bilrost", bilrost(1))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub angle: u16,
+    #[cfg_attr(feature = "bilrost", bilrost(2))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub submerge: bool,
+}
+
+impl Evolution<Guidance> for GuidanceReorderFields {
+    const CHANGE: Change = Change::ReorderFields;
+
+    fn upgrade(old: &Guidance) -> Self {
+        Self {
+            velocity: old.velocity,
+            angle: old.angle,
+            submerge: old.submerge,
+        }
+    }
+
+    fn agrees(&self, old: &Guidance) -> bool {
+        self.angle == old.angle && self.submerge == old.submerge && self.velocity == old.velocity
+    }
+}
+
+/// Carries an [`EntityType`] so that enums can be sent through the same libraries as structs.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Ship {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub entity_type: EntityType,
+}
+
+/// [`EntityType`] with a `Nimitz` variant added at the end.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bilrost", derive(bilrost::Enumeration))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub enum EntityTypeAddVariant {
+    #[cfg_attr(feature = "bilrost", bilrost(0))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    ArleighBurke,
+    #[cfg_attr(feature = "bilrost", bilrost(1))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    Bismarck,
+    #[cfg_attr(feature = "bilrost", bilrost(2))]
+    #[cfg_attr(feature = "minicbor", n(2))]
+    Clemenceau,
+    #[cfg_attr(feature = "bilrost", bilrost(3))]
+    #[cfg_attr(feature = "minicbor", n(3))]
+    Fletcher,
+    #[cfg_attr(feature = "bilrost", bilrost(4))]
+    #[cfg_attr(feature = "minicbor", n(4))]
+    G5,
+    #[cfg_attr(feature = "bilrost", bilrost(5))]
+    #[cfg_attr(feature = "minicbor", n(5))]
+    Iowa,
+    #[cfg_attr(feature = "bilrost", bilrost(6))]
+    #[cfg_attr(feature = "minicbor", n(6))]
+    Kolkata,
+    #[cfg_attr(feature = "bilrost", bilrost(7))]
+    #[cfg_attr(feature = "minicbor", n(7))]
+    Osa,
+    #[cfg_attr(feature = "bilrost", bilrost(8))]
+    #[cfg_attr(feature = "minicbor", n(8))]
+    Yasen,
+    #[cfg_attr(feature = "bilrost", bilrost(9))]
+    #[cfg_attr(feature = "minicbor", n(9))]
+    Zubr,
+    #[cfg_attr(feature = "bilrost", bilrost(10))]
+    #[cfg_attr(feature = "minicbor", n(10))]
+    Nimitz,
+}
+
+impl From<EntityType> for EntityTypeAddVariant {
+    fn from(value: EntityType) -> Self {
+        match value {
+            EntityType::ArleighBurke => Self::ArleighBurke,
+            EntityType::Bismarck => Self::Bismarck,
+            EntityType::Clemenceau => Self::Clemenceau,
+            EntityType::Fletcher => Self::Fletcher,
+            EntityType::G5 => Self::G5,
+            EntityType::Iowa => Self::Iowa,
+            EntityType::Kolkata => Self::Kolkata,
+            EntityType::Osa => Self::Osa,
+            EntityType::Yasen => Self::Yasen,
+            EntityType::Zubr => Self::Zubr,
+        }
+    }
+}
+
+/// [`Ship`] using [`EntityTypeAddVariant`].
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ShipAddVariant {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub entity_type: EntityTypeAddVariant,
+}
+
+impl Evolution<Ship> for ShipAddVariant {
+    const CHANGE: Change = Change::AddVariant;
+
+    fn upgrade(_: &Ship) -> Self {
+        Self {
+            entity_type: EntityTypeAddVariant::Nimitz,
+        }
+    }
+
+    fn agrees(&self, old: &Ship) -> bool {
+        self.entity_type == EntityTypeAddVariant::from(old.entity_type)
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for GuidanceAddField {
+    type Reader = cp_v2::guidance_add_field::Reader<'a>;
+    type Builder = cp_v2::guidance_add_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        builder.set_angle(self.angle);
+        builder.set_submerge(self.submerge);
+        builder.set_velocity(self.velocity);
+        let mut target_id = builder.reborrow().init_target_id();
+        if let Some(value) = self.target_id {
+            target_id.set_some(value);
+        } else {
+            target_id.set_none(());
+        }
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for GuidanceAddField {
+    fn deserialize_capnp(guidance: Self::Reader) -> capnp::Result<Self> {
+        use cp_v2::guidance_add_field::target_id;
+
+        Ok(GuidanceAddField {
+            angle: guidance.get_angle(),
+            submerge: guidance.get_submerge(),
+            velocity: guidance.get_velocity(),
+            target_id: match guidance.get_target_id().which()? {
+                target_id::None(()) => None,
+                target_id::Some(value) => Some(value),
+            },
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for GuidanceRemoveField {
+    type Reader = cp_v2::guidance_remove_field::Reader<'a>;
+    type Builder = cp_v2::guidance_remove_field::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        builder.set_angle(self.angle);
+        builder.set_velocity(self.velocity);
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for GuidanceRemoveField {
+    fn deserialize_capnp(guidance: Self::Reader) -> capnp::Result<Self> {
+        Ok(GuidanceRemoveField {
+            angle: guidance.get_angle(),
+            velocity: guidance.get_velocity(),
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for GuidanceReorderFields {
+    type Reader = cp_v2::guidance_reorder_fields::Reader<'a>;
+    type Builder = cp_v2::guidance_reorder_fields::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        builder.set_velocity(self.velocity);
+        builder.set_angle(self.angle);
+        builder.set_submerge(self.submerge);
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for GuidanceReorderFields {
+    fn deserialize_capnp(guidance: Self::Reader) -> capnp::Result<Self> {
+        Ok(GuidanceReorderFields {
+            velocity: guidance.get_velocity(),
+            angle: guidance.get_angle(),
+            submerge: guidance.get_submerge(),
+        })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for Ship {
+    type Reader = cp_v2::ship::Reader<'a>;
+    type Builder = cp_v2::ship::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        builder.set_entity_type(self.entity_type.into());
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Ship {
+    fn deserialize_capnp(ship: Self::Reader) -> capnp::Result<Self> {
+        use cp::EntityType as Cp;
+
+        let entity_type = match ship.get_entity_type()? {
+            Cp::ArleighBurke => EntityType::ArleighBurke,
+            Cp::Bismarck => EntityType::Bismarck,
+            Cp::Clemenceau => EntityType::Clemenceau,
+            Cp::Fletcher => EntityType::Fletcher,
+            Cp::G5 => EntityType::G5,
+            Cp::Iowa => EntityType::Iowa,
+            Cp::Kolkata => EntityType::Kolkata,
+            Cp::Osa => EntityType::Osa,
+            Cp::Yasen => EntityType::Yasen,
+            Cp::Zubr => EntityType::Zubr,
+        };
+        Ok(Ship { entity_type })
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Serialize<'a> for ShipAddVariant {
+    type Reader = cp_v2::ship_add_variant::Reader<'a>;
+    type Builder = cp_v2::ship_add_variant::Builder<'a>;
+
+    fn serialize_capnp(&self, builder: &mut Self::Builder) {
+        use cp_v2::EntityTypeAddVariant as Cp;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match self.entity_type {
+            ArleighBurke => Cp::ArleighBurke,
+            Bismarck => Cp::Bismarck,
+            Clemenceau => Cp::Clemenceau,
+            Fletcher => Cp::Fletcher,
+            G5 => Cp::G5,
+            Iowa => Cp::Iowa,
+            Kolkata => Cp::Kolkata,
+            Osa => Cp::Osa,
+            Yasen => Cp::Yasen,
+            Zubr => Cp::Zubr,
+            Nimitz => Cp::Nimitz,
+        };
+        builder.set_entity_type(entity_type);
+    }
+}
+
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for ShipAddVariant {
+    fn deserialize_capnp(ship: Self::Reader) -> capnp::Result<Self> {
+        use cp_v2::EntityTypeAddVariant as Cp;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match ship.get_entity_type()? {
+            Cp::ArleighBurke => ArleighBurke,
+            Cp::Bismarck => Bismarck,
+            Cp::Clemenceau => Clemenceau,
+            Cp::Fletcher => Fletcher,
+            Cp::G5 => G5,
+            Cp::Iowa => Iowa,
+            Cp::Kolkata => Kolkata,
+            Cp::Osa => Osa,
+            Cp::Yasen => Yasen,
+            Cp::Zubr => Zubr,
+            Cp::Nimitz => Nimitz,
+        };
+        Ok(ShipAddVariant { entity_type })
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for Ship {
+    type Target = fb_v2::Ship<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        use fb_v2::EntityType as Fb;
+
+        let entity_type = match self.entity_type {
+            EntityType::ArleighBurke => Fb::ArleighBurke,
+            EntityType::Bismarck => Fb::Bismarck,
+            EntityType::Clemenceau => Fb::Clemenceau,
+            EntityType::Fletcher => Fb::Fletcher,
+            EntityType::G5 => Fb::G5,
+            EntityType::Iowa => Fb::Iowa,
+            EntityType::Kolkata => Fb::Kolkata,
+            EntityType::Osa => Fb::Osa,
+            EntityType::Yasen => Fb::Yasen,
+            EntityType::Zubr => Fb::Zubr,
+        };
+
+        let mut builder = fb_v2::ShipBuilder::new(fbb);
+        builder.add_entity_type(entity_type);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for Ship {
+    fn deserialize_fb(ship: fb_v2::Ship<'a>) -> Self {
+        use fb_v2::EntityType as Fb;
+
+        let entity_type = match ship.entity_type() {
+            Fb::ArleighBurke => EntityType::ArleighBurke,
+            Fb::Bismarck => EntityType::Bismarck,
+            Fb::Clemenceau => EntityType::Clemenceau,
+            Fb::Fletcher => EntityType::Fletcher,
+            Fb::G5 => EntityType::G5,
+            Fb::Iowa => EntityType::Iowa,
+            Fb::Kolkata => EntityType::Kolkata,
+            Fb::Osa => EntityType::Osa,
+            Fb::Yasen => EntityType::Yasen,
+            Fb::Zubr => EntityType::Zubr,
+            entity_type => panic!("invalid entity type {:?}", entity_type),
+        };
+        Ship { entity_type }
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Serialize<'a> for ShipAddVariant {
+    type Target = fb_v2::ShipAddVariant<'a>;
+
+    fn serialize_fb<'b>(&self, fbb: &'b mut FlatBufferBuilder<'a>) -> WIPOffset<Self::Target>
+    where
+        'a: 'b,
+    {
+        use fb_v2::EntityTypeAddVariant as Fb;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match self.entity_type {
+            ArleighBurke => Fb::ArleighBurke,
+            Bismarck => Fb::Bismarck,
+            Clemenceau => Fb::Clemenceau,
+            Fletcher => Fb::Fletcher,
+            G5 => Fb::G5,
+            Iowa => Fb::Iowa,
+            Kolkata => Fb::Kolkata,
+            Osa => Fb::Osa,
+            Yasen => Fb::Yasen,
+            Zubr => Fb::Zubr,
+            Nimitz => Fb::Nimitz,
+        };
+
+        let mut builder = fb_v2::ShipAddVariantBuilder::new(fbb);
+        builder.add_entity_type(entity_type);
+        builder.finish()
+    }
+}
+
+#[cfg(feature = "flatbuffers")]
+impl<'a> bench_flatbuffers::Deserialize<'a> for ShipAddVariant {
+    fn deserialize_fb(ship: fb_v2::ShipAddVariant<'a>) -> Self {
+        use fb_v2::EntityTypeAddVariant as Fb;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match ship.entity_type() {
+            Fb::ArleighBurke => ArleighBurke,
+            Fb::Bismarck => Bismarck,
+            Fb::Clemenceau => Clemenceau,
+            Fb::Fletcher => Fletcher,
+            Fb::G5 => G5,
+            Fb::Iowa => Iowa,
+            Fb::Kolkata => Kolkata,
+            Fb::Osa => Osa,
+            Fb::Yasen => Yasen,
+            Fb::Zubr => Zubr,
+            Fb::Nimitz => Nimitz,
+            entity_type => panic!("invalid entity type {:?}", entity_type),
+        };
+        ShipAddVariant { entity_type }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for GuidanceAddField {
+    type Message = pb_v2::GuidanceAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            angle: self.angle as u32,
+            submerge: self.submerge,
+            velocity: self.velocity as i32,
+            target_id: self.target_id,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::GuidanceAddField> for GuidanceAddField {
+    fn from(value: pb_v2::GuidanceAddField) -> Self {
+        GuidanceAddField {
+            angle: value.angle.try_into().unwrap(),
+            submerge: value.submerge,
+            velocity: value.velocity.try_into().unwrap(),
+            target_id: value.target_id,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for GuidanceRemoveField {
+    type Message = pb_v2::GuidanceRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            angle: self.angle as u32,
+            velocity: self.velocity as i32,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::GuidanceRemoveField> for GuidanceRemoveField {
+    fn from(value: pb_v2::GuidanceRemoveField) -> Self {
+        GuidanceRemoveField {
+            angle: value.angle.try_into().unwrap(),
+            velocity: value.velocity.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for GuidanceReorderFields {
+    type Message = pb_v2::GuidanceReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            velocity: self.velocity as i32,
+            angle: self.angle as u32,
+            submerge: self.submerge,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::GuidanceReorderFields> for GuidanceReorderFields {
+    fn from(value: pb_v2::GuidanceReorderFields) -> Self {
+        GuidanceReorderFields {
+            velocity: value.velocity.try_into().unwrap(),
+            angle: value.angle.try_into().unwrap(),
+            submerge: value.submerge,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for Ship {
+    type Message = pb_v2::Ship;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            entity_type: pb::EntityType::from(self.entity_type) as i32,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::Ship> for Ship {
+    fn from(value: pb_v2::Ship) -> Self {
+        Ship {
+            entity_type: pb::EntityType::try_from(value.entity_type).unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for ShipAddVariant {
+    type Message = pb_v2::ShipAddVariant;
+
+    fn serialize_pb(&self) -> Self::Message {
+        use pb_v2::EntityTypeAddVariant as Pb;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match self.entity_type {
+            ArleighBurke => Pb::ArleighBurke,
+            Bismarck => Pb::Bismarck,
+            Clemenceau => Pb::Clemenceau,
+            Fletcher => Pb::Fletcher,
+            G5 => Pb::G5,
+            Iowa => Pb::Iowa,
+            Kolkata => Pb::Kolkata,
+            Osa => Pb::Osa,
+            Yasen => Pb::Yasen,
+            Zubr => Pb::Zubr,
+            Nimitz => Pb::Nimitz,
+        };
+        Self::Message {
+            entity_type: entity_type as i32,
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<pb_v2::ShipAddVariant> for ShipAddVariant {
+    fn from(value: pb_v2::ShipAddVariant) -> Self {
+        use pb_v2::EntityTypeAddVariant as Pb;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match Pb::try_from(value.entity_type).unwrap() {
+            Pb::ArleighBurke => ArleighBurke,
+            Pb::Bismarck => Bismarck,
+            Pb::Clemenceau => Clemenceau,
+            Pb::Fletcher => Fletcher,
+            Pb::G5 => G5,
+            Pb::Iowa => Iowa,
+            Pb::Kolkata => Kolkata,
+            Pb::Osa => Osa,
+            Pb::Yasen => Yasen,
+            Pb::Zubr => Zubr,
+            Pb::Nimitz => Nimitz,
+        };
+        ShipAddVariant { entity_type }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for GuidanceAddField {
+    type Message = rpb_v2::GuidanceAddField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            angle: self.angle as u32,
+            submerge: self.submerge,
+            velocity: self.velocity as i32,
+            target_id: self.target_id,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::GuidanceAddField> for GuidanceAddField {
+    fn from(value: rpb_v2::GuidanceAddField) -> Self {
+        GuidanceAddField {
+            angle: value.angle.try_into().unwrap(),
+            submerge: value.submerge,
+            velocity: value.velocity.try_into().unwrap(),
+            target_id: value.target_id,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for GuidanceRemoveField {
+    type Message = rpb_v2::GuidanceRemoveField;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            angle: self.angle as u32,
+            velocity: self.velocity as i32,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::GuidanceRemoveField> for GuidanceRemoveField {
+    fn from(value: rpb_v2::GuidanceRemoveField) -> Self {
+        GuidanceRemoveField {
+            angle: value.angle.try_into().unwrap(),
+            velocity: value.velocity.try_into().unwrap(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for GuidanceReorderFields {
+    type Message = rpb_v2::GuidanceReorderFields;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            velocity: self.velocity as i32,
+            angle: self.angle as u32,
+            submerge: self.submerge,
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::GuidanceReorderFields> for GuidanceReorderFields {
+    fn from(value: rpb_v2::GuidanceReorderFields) -> Self {
+        GuidanceReorderFields {
+            velocity: value.velocity.try_into().unwrap(),
+            angle: value.angle.try_into().unwrap(),
+            submerge: value.submerge,
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for Ship {
+    type Message = rpb_v2::Ship;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            entity_type: protobuf::EnumOrUnknown::new(rpb::mk48::EntityType::from(
+                self.entity_type,
+            )),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::Ship> for Ship {
+    fn from(value: rpb_v2::Ship) -> Self {
+        Ship {
+            entity_type: value.entity_type.unwrap().into(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl bench_protobuf::Serialize for ShipAddVariant {
+    type Message = rpb_v2::ShipAddVariant;
+
+    fn serialize_pb(&self) -> Self::Message {
+        use rpb_v2::EntityTypeAddVariant as Pb;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match self.entity_type {
+            ArleighBurke => Pb::ARLEIGH_BURKE,
+            Bismarck => Pb::BISMARCK,
+            Clemenceau => Pb::CLEMENCEAU,
+            Fletcher => Pb::FLETCHER,
+            G5 => Pb::G5,
+            Iowa => Pb::IOWA,
+            Kolkata => Pb::KOLKATA,
+            Osa => Pb::OSA,
+            Yasen => Pb::YASEN,
+            Zubr => Pb::ZUBR,
+            Nimitz => Pb::NIMITZ,
+        };
+        Self::Message {
+            entity_type: protobuf::EnumOrUnknown::new(entity_type),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<rpb_v2::ShipAddVariant> for ShipAddVariant {
+    fn from(value: rpb_v2::ShipAddVariant) -> Self {
+        use rpb_v2::EntityTypeAddVariant as Pb;
+        use EntityTypeAddVariant::*;
+
+        let entity_type = match value.entity_type.unwrap() {
+            Pb::ARLEIGH_BURKE => ArleighBurke,
+            Pb::BISMARCK => Bismarck,
+            Pb::CLEMENCEAU => Clemenceau,
+            Pb::FLETCHER => Fletcher,
+            Pb::G5 => G5,
+            Pb::IOWA => Iowa,
+            Pb::KOLKATA => Kolkata,
+            Pb::OSA => Osa,
+            Pb::YASEN => Yasen,
+            Pb::ZUBR => Zubr,
+            Pb::NIMITZ => Nimitz,
+        };
+        ShipAddVariant { entity_type }
+    }
+}
+
+pub fn check(name: &str, data: &Updates) {
+    let contacts = || {
+        data.updates
+            .iter()
+            .flat_map(|update| update.contacts.iter())
+    };
+    let guidances = contacts()
+        .map(|contact| contact.guidance)
+        .collect::<Vec<_>>();
+    let ships = contacts()
+        .filter_map(|contact| contact.entity_type)
+        .map(|entity_type| Ship { entity_type })
+        .collect::<Vec<_>>();
+    for_each_codec!(check_codec, name, &guidances, &ships);
+    #[cfg(feature = "capnp")]
+    check_codec::<crate::codec::Capnp>("capnp", name, &guidances, &ships);
+    // Guidance is a struct, which FlatBuffers does not allow to change
+    #[cfg(feature = "flatbuffers")]
+    evolution::check::<crate::codec::Flatbuffers, Ship, ShipAddVariant>(
+        name,
+        "flatbuffers",
+        &ships,
+    );
+    #[cfg(feature = "protobuf")]
+    check_codec::<crate::codec::Protobuf>("protobuf", name, &guidances, &ships);
+}
+
+fn check_codec<C>(lib: &str, name: &str, guidances: &[Guidance], ships: &[Ship])
+where
+    C: Codec<Guidance>
+        + Codec<GuidanceAddField>
+        + Codec<GuidanceRemoveField>
+        + Codec<GuidanceReorderFields>
+        + Codec<Ship>
+        + Codec<ShipAddVariant>,
+{
+    evolution::check::<C, Guidance, GuidanceAddField>(name, lib, guidances);
+    evolution::check::<C, Guidance, GuidanceRemoveField>(name, lib, guidances);
+    evolution::check::<C, Guidance, GuidanceReorderFields>(name, lib, guidances);
+    evolution::check::<C, Ship, ShipAddVariant>(name, lib, ships);
+}
@@ -0,0 +1,454 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod mk_48_evolution {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ENTITY_TYPE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ENTITY_TYPE: i8 = 9;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ENTITY_TYPE: [EntityType; 10] = [
+  EntityType::ArleighBurke,
+  EntityType::Bismarck,
+  EntityType::Clemenceau,
+  EntityType::Fletcher,
+  EntityType::G5,
+  EntityType::Iowa,
+  EntityType::Kolkata,
+  EntityType::Osa,
+  EntityType::Yasen,
+  EntityType::Zubr,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct EntityType(pub i8);
+#[allow(non_upper_case_globals)]
+impl EntityType {
+  pub const ArleighBurke: Self = Self(0);
+  pub const Bismarck: Self = Self(1);
+  pub const Clemenceau: Self = Self(2);
+  pub const Fletcher: Self = Self(3);
+  pub const G5: Self = Self(4);
+  pub const Iowa: Self = Self(5);
+  pub const Kolkata: Self = Self(6);
+  pub const Osa: Self = Self(7);
+  pub const Yasen: Self = Self(8);
+  pub const Zubr: Self = Self(9);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 9;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::ArleighBurke,
+    Self::Bismarck,
+    Self::Clemenceau,
+    Self::Fletcher,
+    Self::G5,
+    Self::Iowa,
+    Self::Kolkata,
+    Self::Osa,
+    Self::Yasen,
+    Self::Zubr,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::ArleighBurke => Some("ArleighBurke"),
+      Self::Bismarck => Some("Bismarck"),
+      Self::Clemenceau => Some("Clemenceau"),
+      Self::Fletcher => Some("Fletcher"),
+      Self::G5 => Some("G5"),
+      Self::Iowa => Some("Iowa"),
+      Self::Kolkata => Some("Kolkata"),
+      Self::Osa => Some("Osa"),
+      Self::Yasen => Some("Yasen"),
+      Self::Zubr => Some("Zubr"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for EntityType {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for EntityType {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for EntityType {
+    type Output = EntityType;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for EntityType {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for EntityType {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for EntityType {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_ENTITY_TYPE_ADD_VARIANT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_ENTITY_TYPE_ADD_VARIANT: i8 = 10;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ENTITY_TYPE_ADD_VARIANT: [EntityTypeAddVariant; 11] = [
+  EntityTypeAddVariant::ArleighBurke,
+  EntityTypeAddVariant::Bismarck,
+  EntityTypeAddVariant::Clemenceau,
+  EntityTypeAddVariant::Fletcher,
+  EntityTypeAddVariant::G5,
+  EntityTypeAddVariant::Iowa,
+  EntityTypeAddVariant::Kolkata,
+  EntityTypeAddVariant::Osa,
+  EntityTypeAddVariant::Yasen,
+  EntityTypeAddVariant::Zubr,
+  EntityTypeAddVariant::Nimitz,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct EntityTypeAddVariant(pub i8);
+#[allow(non_upper_case_globals)]
+impl EntityTypeAddVariant {
+  pub const ArleighBurke: Self = Self(0);
+  pub const Bismarck: Self = Self(1);
+  pub const Clemenceau: Self = Self(2);
+  pub const Fletcher: Self = Self(3);
+  pub const G5: Self = Self(4);
+  pub const Iowa: Self = Self(5);
+  pub const Kolkata: Self = Self(6);
+  pub const Osa: Self = Self(7);
+  pub const Yasen: Self = Self(8);
+  pub const Zubr: Self = Self(9);
+  pub const Nimitz: Self = Self(10);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 10;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::ArleighBurke,
+    Self::Bismarck,
+    Self::Clemenceau,
+    Self::Fletcher,
+    Self::G5,
+    Self::Iowa,
+    Self::Kolkata,
+    Self::Osa,
+    Self::Yasen,
+    Self::Zubr,
+    Self::Nimitz,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::ArleighBurke => Some("ArleighBurke"),
+      Self::Bismarck => Some("Bismarck"),
+      Self::Clemenceau => Some("Clemenceau"),
+      Self::Fletcher => Some("Fletcher"),
+      Self::G5 => Some("G5"),
+      Self::Iowa => Some("Iowa"),
+      Self::Kolkata => Some("Kolkata"),
+      Self::Osa => Some("Osa"),
+      Self::Yasen => Some("Yasen"),
+      Self::Zubr => Some("Zubr"),
+      Self::Nimitz => Some("Nimitz"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for EntityTypeAddVariant {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for EntityTypeAddVariant {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for EntityTypeAddVariant {
+    type Output = EntityTypeAddVariant;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for EntityTypeAddVariant {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for EntityTypeAddVariant {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for EntityTypeAddVariant {}
+pub enum ShipOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Ship<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Ship<'a> {
+  type Inner = Ship<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> Ship<'a> {
+  pub const VT_ENTITY_TYPE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Ship { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ShipArgs
+  ) -> flatbuffers::WIPOffset<Ship<'bldr>> {
+    let mut builder = ShipBuilder::new(_fbb);
+    builder.add_entity_type(args.entity_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity_type(&self) -> EntityType {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<EntityType>(Ship::VT_ENTITY_TYPE, Some(EntityType::ArleighBurke)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Ship<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<EntityType>("entity_type", Self::VT_ENTITY_TYPE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ShipArgs {
+    pub entity_type: EntityType,
+}
+impl<'a> Default for ShipArgs {
+  #[inline]
+  fn default() -> Self {
+    ShipArgs {
+      entity_type: EntityType::ArleighBurke,
+    }
+  }
+}
+
+pub struct ShipBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShipBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity_type(&mut self, entity_type: EntityType) {
+    self.fbb_.push_slot::<EntityType>(Ship::VT_ENTITY_TYPE, entity_type, EntityType::ArleighBurke);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ShipBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ShipBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Ship<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Ship<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Ship");
+      ds.field("entity_type", &self.entity_type());
+      ds.finish()
+  }
+}
+pub enum ShipAddVariantOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ShipAddVariant<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ShipAddVariant<'a> {
+  type Inner = ShipAddVariant<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ShipAddVariant<'a> {
+  pub const VT_ENTITY_TYPE: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ShipAddVariant { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ShipAddVariantArgs
+  ) -> flatbuffers::WIPOffset<ShipAddVariant<'bldr>> {
+    let mut builder = ShipAddVariantBuilder::new(_fbb);
+    builder.add_entity_type(args.entity_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity_type(&self) -> EntityTypeAddVariant {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<EntityTypeAddVariant>(ShipAddVariant::VT_ENTITY_TYPE, Some(EntityTypeAddVariant::ArleighBurke)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ShipAddVariant<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<EntityTypeAddVariant>("entity_type", Self::VT_ENTITY_TYPE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ShipAddVariantArgs {
+    pub entity_type: EntityTypeAddVariant,
+}
+impl<'a> Default for ShipAddVariantArgs {
+  #[inline]
+  fn default() -> Self {
+    ShipAddVariantArgs {
+      entity_type: EntityTypeAddVariant::ArleighBurke,
+    }
+  }
+}
+
+pub struct ShipAddVariantBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShipAddVariantBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity_type(&mut self, entity_type: EntityTypeAddVariant) {
+    self.fbb_.push_slot::<EntityTypeAddVariant>(ShipAddVariant::VT_ENTITY_TYPE, entity_type, EntityTypeAddVariant::ArleighBurke);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ShipAddVariantBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ShipAddVariantBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ShipAddVariant<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ShipAddVariant<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ShipAddVariant");
+      ds.field("entity_type", &self.entity_type());
+      ds.finish()
+  }
+}
+}  // pub mod mk48_evolution
+
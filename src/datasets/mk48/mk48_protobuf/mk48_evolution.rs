@@ -0,0 +1,906 @@
+// This file is generated by rust-protobuf 3.7.2. Do not edit
+// .proto file is parsed by pure
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_results)]
+#![allow(unused_mut)]
+
+//! Generated file from `mk48_evolution.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_3_7_2;
+
+// @@protoc_insertion_point(message:prost.mk48.evolution.GuidanceAddField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GuidanceAddField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceAddField.angle)
+    pub angle: u32,
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceAddField.submerge)
+    pub submerge: bool,
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceAddField.velocity)
+    pub velocity: i32,
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceAddField.target_id)
+    pub target_id: ::std::option::Option<u32>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mk48.evolution.GuidanceAddField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GuidanceAddField {
+    fn default() -> &'a GuidanceAddField {
+        <GuidanceAddField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GuidanceAddField {
+    pub fn new() -> GuidanceAddField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(4);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "angle",
+            |m: &GuidanceAddField| { &m.angle },
+            |m: &mut GuidanceAddField| { &mut m.angle },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "submerge",
+            |m: &GuidanceAddField| { &m.submerge },
+            |m: &mut GuidanceAddField| { &mut m.submerge },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "velocity",
+            |m: &GuidanceAddField| { &m.velocity },
+            |m: &mut GuidanceAddField| { &mut m.velocity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_option_accessor::<_, _>(
+            "target_id",
+            |m: &GuidanceAddField| { &m.target_id },
+            |m: &mut GuidanceAddField| { &mut m.target_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GuidanceAddField>(
+            "GuidanceAddField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GuidanceAddField {
+    const NAME: &'static str = "GuidanceAddField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.angle = is.read_uint32()?;
+                },
+                16 => {
+                    self.submerge = is.read_bool()?;
+                },
+                24 => {
+                    self.velocity = is.read_int32()?;
+                },
+                32 => {
+                    self.target_id = ::std::option::Option::Some(is.read_uint32()?);
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.angle != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.angle);
+        }
+        if self.submerge != false {
+            my_size += 1 + 1;
+        }
+        if self.velocity != 0 {
+            my_size += ::protobuf::rt::int32_size(3, self.velocity);
+        }
+        if let Some(v) = self.target_id {
+            my_size += ::protobuf::rt::uint32_size(4, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.angle != 0 {
+            os.write_uint32(1, self.angle)?;
+        }
+        if self.submerge != false {
+            os.write_bool(2, self.submerge)?;
+        }
+        if self.velocity != 0 {
+            os.write_int32(3, self.velocity)?;
+        }
+        if let Some(v) = self.target_id {
+            os.write_uint32(4, v)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GuidanceAddField {
+        GuidanceAddField::new()
+    }
+
+    fn clear(&mut self) {
+        self.angle = 0;
+        self.submerge = false;
+        self.velocity = 0;
+        self.target_id = ::std::option::Option::None;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GuidanceAddField {
+        static instance: GuidanceAddField = GuidanceAddField {
+            angle: 0,
+            submerge: false,
+            velocity: 0,
+            target_id: ::std::option::Option::None,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GuidanceAddField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GuidanceAddField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GuidanceAddField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GuidanceAddField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mk48.evolution.GuidanceRemoveField)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GuidanceRemoveField {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceRemoveField.angle)
+    pub angle: u32,
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceRemoveField.velocity)
+    pub velocity: i32,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mk48.evolution.GuidanceRemoveField.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GuidanceRemoveField {
+    fn default() -> &'a GuidanceRemoveField {
+        <GuidanceRemoveField as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GuidanceRemoveField {
+    pub fn new() -> GuidanceRemoveField {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(2);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "angle",
+            |m: &GuidanceRemoveField| { &m.angle },
+            |m: &mut GuidanceRemoveField| { &mut m.angle },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "velocity",
+            |m: &GuidanceRemoveField| { &m.velocity },
+            |m: &mut GuidanceRemoveField| { &mut m.velocity },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GuidanceRemoveField>(
+            "GuidanceRemoveField",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GuidanceRemoveField {
+    const NAME: &'static str = "GuidanceRemoveField";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.angle = is.read_uint32()?;
+                },
+                24 => {
+                    self.velocity = is.read_int32()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.angle != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.angle);
+        }
+        if self.velocity != 0 {
+            my_size += ::protobuf::rt::int32_size(3, self.velocity);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.angle != 0 {
+            os.write_uint32(1, self.angle)?;
+        }
+        if self.velocity != 0 {
+            os.write_int32(3, self.velocity)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GuidanceRemoveField {
+        GuidanceRemoveField::new()
+    }
+
+    fn clear(&mut self) {
+        self.angle = 0;
+        self.velocity = 0;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GuidanceRemoveField {
+        static instance: GuidanceRemoveField = GuidanceRemoveField {
+            angle: 0,
+            velocity: 0,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GuidanceRemoveField {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GuidanceRemoveField").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GuidanceRemoveField {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GuidanceRemoveField {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mk48.evolution.GuidanceReorderFields)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct GuidanceReorderFields {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceReorderFields.velocity)
+    pub velocity: i32,
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceReorderFields.angle)
+    pub angle: u32,
+    // @@protoc_insertion_point(field:prost.mk48.evolution.GuidanceReorderFields.submerge)
+    pub submerge: bool,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mk48.evolution.GuidanceReorderFields.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a GuidanceReorderFields {
+    fn default() -> &'a GuidanceReorderFields {
+        <GuidanceReorderFields as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GuidanceReorderFields {
+    pub fn new() -> GuidanceReorderFields {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(3);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "velocity",
+            |m: &GuidanceReorderFields| { &m.velocity },
+            |m: &mut GuidanceReorderFields| { &mut m.velocity },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "angle",
+            |m: &GuidanceReorderFields| { &m.angle },
+            |m: &mut GuidanceReorderFields| { &mut m.angle },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "submerge",
+            |m: &GuidanceReorderFields| { &m.submerge },
+            |m: &mut GuidanceReorderFields| { &mut m.submerge },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<GuidanceReorderFields>(
+            "GuidanceReorderFields",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for GuidanceReorderFields {
+    const NAME: &'static str = "GuidanceReorderFields";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                24 => {
+                    self.velocity = is.read_int32()?;
+                },
+                8 => {
+                    self.angle = is.read_uint32()?;
+                },
+                16 => {
+                    self.submerge = is.read_bool()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.velocity != 0 {
+            my_size += ::protobuf::rt::int32_size(3, self.velocity);
+        }
+        if self.angle != 0 {
+            my_size += ::protobuf::rt::uint32_size(1, self.angle);
+        }
+        if self.submerge != false {
+            my_size += 1 + 1;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.velocity != 0 {
+            os.write_int32(3, self.velocity)?;
+        }
+        if self.angle != 0 {
+            os.write_uint32(1, self.angle)?;
+        }
+        if self.submerge != false {
+            os.write_bool(2, self.submerge)?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> GuidanceReorderFields {
+        GuidanceReorderFields::new()
+    }
+
+    fn clear(&mut self) {
+        self.velocity = 0;
+        self.angle = 0;
+        self.submerge = false;
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static GuidanceReorderFields {
+        static instance: GuidanceReorderFields = GuidanceReorderFields {
+            velocity: 0,
+            angle: 0,
+            submerge: false,
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for GuidanceReorderFields {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("GuidanceReorderFields").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for GuidanceReorderFields {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GuidanceReorderFields {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mk48.evolution.Ship)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct Ship {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mk48.evolution.Ship.entity_type)
+    pub entity_type: ::protobuf::EnumOrUnknown<super::mk48::EntityType>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mk48.evolution.Ship.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a Ship {
+    fn default() -> &'a Ship {
+        <Ship as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "entity_type",
+            |m: &Ship| { &m.entity_type },
+            |m: &mut Ship| { &mut m.entity_type },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<Ship>(
+            "Ship",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for Ship {
+    const NAME: &'static str = "Ship";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.entity_type = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.entity_type != ::protobuf::EnumOrUnknown::new(super::mk48::EntityType::ARLEIGH_BURKE) {
+            my_size += ::protobuf::rt::int32_size(1, self.entity_type.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.entity_type != ::protobuf::EnumOrUnknown::new(super::mk48::EntityType::ARLEIGH_BURKE) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.entity_type))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> Ship {
+        Ship::new()
+    }
+
+    fn clear(&mut self) {
+        self.entity_type = ::protobuf::EnumOrUnknown::new(super::mk48::EntityType::ARLEIGH_BURKE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static Ship {
+        static instance: Ship = Ship {
+            entity_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for Ship {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("Ship").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for Ship {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ship {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+// @@protoc_insertion_point(message:prost.mk48.evolution.ShipAddVariant)
+#[derive(PartialEq,Clone,Default,Debug)]
+pub struct ShipAddVariant {
+    // message fields
+    // @@protoc_insertion_point(field:prost.mk48.evolution.ShipAddVariant.entity_type)
+    pub entity_type: ::protobuf::EnumOrUnknown<EntityTypeAddVariant>,
+    // special fields
+    // @@protoc_insertion_point(special_field:prost.mk48.evolution.ShipAddVariant.special_fields)
+    pub special_fields: ::protobuf::SpecialFields,
+}
+
+impl<'a> ::std::default::Default for &'a ShipAddVariant {
+    fn default() -> &'a ShipAddVariant {
+        <ShipAddVariant as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShipAddVariant {
+    pub fn new() -> ShipAddVariant {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::with_capacity(1);
+        let mut oneofs = ::std::vec::Vec::with_capacity(0);
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "entity_type",
+            |m: &ShipAddVariant| { &m.entity_type },
+            |m: &mut ShipAddVariant| { &mut m.entity_type },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ShipAddVariant>(
+            "ShipAddVariant",
+            fields,
+            oneofs,
+        )
+    }
+}
+
+impl ::protobuf::Message for ShipAddVariant {
+    const NAME: &'static str = "ShipAddVariant";
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::Result<()> {
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            match tag {
+                8 => {
+                    self.entity_type = is.read_enum_or_unknown()?;
+                },
+                tag => {
+                    ::protobuf::rt::read_unknown_or_skip_group(tag, is, self.special_fields.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u64 {
+        let mut my_size = 0;
+        if self.entity_type != ::protobuf::EnumOrUnknown::new(EntityTypeAddVariant::ARLEIGH_BURKE) {
+            my_size += ::protobuf::rt::int32_size(1, self.entity_type.value());
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.special_fields.unknown_fields());
+        self.special_fields.cached_size().set(my_size as u32);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::Result<()> {
+        if self.entity_type != ::protobuf::EnumOrUnknown::new(EntityTypeAddVariant::ARLEIGH_BURKE) {
+            os.write_enum(1, ::protobuf::EnumOrUnknown::value(&self.entity_type))?;
+        }
+        os.write_unknown_fields(self.special_fields.unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn special_fields(&self) -> &::protobuf::SpecialFields {
+        &self.special_fields
+    }
+
+    fn mut_special_fields(&mut self) -> &mut ::protobuf::SpecialFields {
+        &mut self.special_fields
+    }
+
+    fn new() -> ShipAddVariant {
+        ShipAddVariant::new()
+    }
+
+    fn clear(&mut self) {
+        self.entity_type = ::protobuf::EnumOrUnknown::new(EntityTypeAddVariant::ARLEIGH_BURKE);
+        self.special_fields.clear();
+    }
+
+    fn default_instance() -> &'static ShipAddVariant {
+        static instance: ShipAddVariant = ShipAddVariant {
+            entity_type: ::protobuf::EnumOrUnknown::from_i32(0),
+            special_fields: ::protobuf::SpecialFields::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::MessageFull for ShipAddVariant {
+    fn descriptor() -> ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().message_by_package_relative_name("ShipAddVariant").unwrap()).clone()
+    }
+}
+
+impl ::std::fmt::Display for ShipAddVariant {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShipAddVariant {
+    type RuntimeType = ::protobuf::reflect::rt::RuntimeTypeMessage<Self>;
+}
+
+#[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+// @@protoc_insertion_point(enum:prost.mk48.evolution.EntityTypeAddVariant)
+pub enum EntityTypeAddVariant {
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.ARLEIGH_BURKE)
+    ARLEIGH_BURKE = 0,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.BISMARCK)
+    BISMARCK = 1,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.CLEMENCEAU)
+    CLEMENCEAU = 2,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.FLETCHER)
+    FLETCHER = 3,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.G5)
+    G5 = 4,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.IOWA)
+    IOWA = 5,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.KOLKATA)
+    KOLKATA = 6,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.OSA)
+    OSA = 7,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.YASEN)
+    YASEN = 8,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.ZUBR)
+    ZUBR = 9,
+    // @@protoc_insertion_point(enum_value:prost.mk48.evolution.EntityTypeAddVariant.NIMITZ)
+    NIMITZ = 10,
+}
+
+impl ::protobuf::Enum for EntityTypeAddVariant {
+    const NAME: &'static str = "EntityTypeAddVariant";
+
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<EntityTypeAddVariant> {
+        match value {
+            0 => ::std::option::Option::Some(EntityTypeAddVariant::ARLEIGH_BURKE),
+            1 => ::std::option::Option::Some(EntityTypeAddVariant::BISMARCK),
+            2 => ::std::option::Option::Some(EntityTypeAddVariant::CLEMENCEAU),
+            3 => ::std::option::Option::Some(EntityTypeAddVariant::FLETCHER),
+            4 => ::std::option::Option::Some(EntityTypeAddVariant::G5),
+            5 => ::std::option::Option::Some(EntityTypeAddVariant::IOWA),
+            6 => ::std::option::Option::Some(EntityTypeAddVariant::KOLKATA),
+            7 => ::std::option::Option::Some(EntityTypeAddVariant::OSA),
+            8 => ::std::option::Option::Some(EntityTypeAddVariant::YASEN),
+            9 => ::std::option::Option::Some(EntityTypeAddVariant::ZUBR),
+            10 => ::std::option::Option::Some(EntityTypeAddVariant::NIMITZ),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn from_str(str: &str) -> ::std::option::Option<EntityTypeAddVariant> {
+        match str {
+            "ARLEIGH_BURKE" => ::std::option::Option::Some(EntityTypeAddVariant::ARLEIGH_BURKE),
+            "BISMARCK" => ::std::option::Option::Some(EntityTypeAddVariant::BISMARCK),
+            "CLEMENCEAU" => ::std::option::Option::Some(EntityTypeAddVariant::CLEMENCEAU),
+            "FLETCHER" => ::std::option::Option::Some(EntityTypeAddVariant::FLETCHER),
+            "G5" => ::std::option::Option::Some(EntityTypeAddVariant::G5),
+            "IOWA" => ::std::option::Option::Some(EntityTypeAddVariant::IOWA),
+            "KOLKATA" => ::std::option::Option::Some(EntityTypeAddVariant::KOLKATA),
+            "OSA" => ::std::option::Option::Some(EntityTypeAddVariant::OSA),
+            "YASEN" => ::std::option::Option::Some(EntityTypeAddVariant::YASEN),
+            "ZUBR" => ::std::option::Option::Some(EntityTypeAddVariant::ZUBR),
+            "NIMITZ" => ::std::option::Option::Some(EntityTypeAddVariant::NIMITZ),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    const VALUES: &'static [EntityTypeAddVariant] = &[
+        EntityTypeAddVariant::ARLEIGH_BURKE,
+        EntityTypeAddVariant::BISMARCK,
+        EntityTypeAddVariant::CLEMENCEAU,
+        EntityTypeAddVariant::FLETCHER,
+        EntityTypeAddVariant::G5,
+        EntityTypeAddVariant::IOWA,
+        EntityTypeAddVariant::KOLKATA,
+        EntityTypeAddVariant::OSA,
+        EntityTypeAddVariant::YASEN,
+        EntityTypeAddVariant::ZUBR,
+        EntityTypeAddVariant::NIMITZ,
+    ];
+}
+
+impl ::protobuf::EnumFull for EntityTypeAddVariant {
+    fn enum_descriptor() -> ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::Lazy::new();
+        descriptor.get(|| file_descriptor().enum_by_package_relative_name("EntityTypeAddVariant").unwrap()).clone()
+    }
+
+    fn descriptor(&self) -> ::protobuf::reflect::EnumValueDescriptor {
+        let index = *self as usize;
+        Self::enum_descriptor().value_by_index(index)
+    }
+}
+
+impl ::std::default::Default for EntityTypeAddVariant {
+    fn default() -> Self {
+        EntityTypeAddVariant::ARLEIGH_BURKE
+    }
+}
+
+impl EntityTypeAddVariant {
+    fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+        ::protobuf::reflect::GeneratedEnumDescriptorData::new::<EntityTypeAddVariant>("EntityTypeAddVariant")
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x14mk48_evolution.proto\x12\x14prost.mk48.evolution\x1a\nmk48.proto\"\
+    \x90\x01\n\x10GuidanceAddField\x12\x14\n\x05angle\x18\x01\x20\x01(\rR\
+    \x05angle\x12\x1a\n\x08submerge\x18\x02\x20\x01(\x08R\x08submerge\x12\
+    \x1a\n\x08velocity\x18\x03\x20\x01(\x05R\x08velocity\x12\x20\n\ttarget_i\
+    d\x18\x04\x20\x01(\rH\0R\x08targetId\x88\x01\x01B\x0c\n\n_target_id\"W\n\
+    \x13GuidanceRemoveField\x12\x14\n\x05angle\x18\x01\x20\x01(\rR\x05angle\
+    \x12\x1a\n\x08velocity\x18\x03\x20\x01(\x05R\x08velocityJ\x04\x08\x02\
+    \x10\x03R\x08submerge\"e\n\x15GuidanceReorderFields\x12\x1a\n\x08velocit\
+    y\x18\x03\x20\x01(\x05R\x08velocity\x12\x14\n\x05angle\x18\x01\x20\x01(\
+    \rR\x05angle\x12\x1a\n\x08submerge\x18\x02\x20\x01(\x08R\x08submerge\"?\
+    \n\x04Ship\x127\n\x0bentity_type\x18\x01\x20\x01(\x0e2\x16.prost.mk48.En\
+    tityTypeR\nentityType\"]\n\x0eShipAddVariant\x12K\n\x0bentity_type\x18\
+    \x01\x20\x01(\x0e2*.prost.mk48.evolution.EntityTypeAddVariantR\nentityTy\
+    pe*\x9e\x01\n\x14EntityTypeAddVariant\x12\x11\n\rARLEIGH_BURKE\x10\0\x12\
+    \x0c\n\x08BISMARCK\x10\x01\x12\x0e\n\nCLEMENCEAU\x10\x02\x12\x0c\n\x08FL\
+    ETCHER\x10\x03\x12\x06\n\x02G5\x10\x04\x12\x08\n\x04IOWA\x10\x05\x12\x0b\
+    \n\x07KOLKATA\x10\x06\x12\x07\n\x03OSA\x10\x07\x12\t\n\x05YASEN\x10\x08\
+    \x12\x08\n\x04ZUBR\x10\t\x12\n\n\x06NIMITZ\x10\nb\x06proto3\
+";
+
+/// `FileDescriptorProto` object which was a source for this generated file
+fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    static file_descriptor_proto_lazy: ::protobuf::rt::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::Lazy::new();
+    file_descriptor_proto_lazy.get(|| {
+        ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+    })
+}
+
+/// `FileDescriptor` object which allows dynamic access to files
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    static generated_file_descriptor_lazy: ::protobuf::rt::Lazy<::protobuf::reflect::GeneratedFileDescriptor> = ::protobuf::rt::Lazy::new();
+    static file_descriptor: ::protobuf::rt::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::rt::Lazy::new();
+    file_descriptor.get(|| {
+        let generated_file_descriptor = generated_file_descriptor_lazy.get(|| {
+            let mut deps = ::std::vec::Vec::with_capacity(1);
+            deps.push(super::mk48::file_descriptor().clone());
+            let mut messages = ::std::vec::Vec::with_capacity(5);
+            messages.push(GuidanceAddField::generated_message_descriptor_data());
+            messages.push(GuidanceRemoveField::generated_message_descriptor_data());
+            messages.push(GuidanceReorderFields::generated_message_descriptor_data());
+            messages.push(Ship::generated_message_descriptor_data());
+            messages.push(ShipAddVariant::generated_message_descriptor_data());
+            let mut enums = ::std::vec::Vec::with_capacity(1);
+            enums.push(EntityTypeAddVariant::generated_enum_descriptor_data());
+            ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
+                file_descriptor_proto(),
+                deps,
+                messages,
+                enums,
+            )
+        });
+        ::protobuf::reflect::FileDescriptor::new_generated_2(generated_file_descriptor)
+    })
+}
@@ -1,3 +1,4 @@
 // @generated
 
 pub mod mk48;
+pub mod mk48_evolution;
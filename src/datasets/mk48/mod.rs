@@ -1,14 +1,28 @@
+pub mod evolution;
 #[cfg(any(feature = "capnp", feature = "prost"))]
 pub mod mk48_capnp;
+#[cfg(feature = "capnp")]
+pub mod mk48_evolution_capnp;
+#[cfg(feature = "flatbuffers")]
+#[path = "mk48_evolution_generated.rs"]
+#[allow(unused_imports, clippy::all)]
+pub mod mk48_evolution_fb;
 #[cfg(feature = "flatbuffers")]
 #[path = "mk48_generated.rs"]
 #[allow(unused_imports, clippy::all)]
 pub mod mk48_fb;
 #[cfg(feature = "prost")]
-#[path = "prost.mk48.rs"]
-pub mod mk48_prost;
+pub mod mk48_prost {
+    include!("prost.mk48.rs");
+
+    /// Newer versions of some messages, used by the schema evolution checks.
+    pub mod evolution {
+        include!("prost.mk48.evolution.rs");
+    }
+}
 #[cfg(feature = "protobuf")]
 pub mod mk48_protobuf;
+pub mod partial;
 
 #[cfg(feature = "flatbuffers")]
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -545,6 +559,17 @@ impl<'a> bench_capnp::Serialize<'a> for Guidance {
     }
 }
 
+#[cfg(feature = "capnp")]
+impl<'a> bench_capnp::Deserialize<'a> for Guidance {
+    fn deserialize_capnp(guidance: Self::Reader) -> capnp::Result<Self> {
+        Ok(Guidance {
+            angle: guidance.get_angle(),
+            submerge: guidance.get_submerge(),
+            velocity: guidance.get_velocity(),
+        })
+    }
+}
+
 #[cfg(feature = "prost")]
 impl bench_prost::Serialize for Guidance {
     type Message = pb::Guidance;
@@ -0,0 +1,131 @@
+//! [`Updates`] with only the fields read by the zero-copy `read` benchmarks.
+
+use criterion::{black_box, Criterion};
+
+use super::Updates;
+#[cfg(feature = "prost")]
+use crate::bench_prost;
+use crate::codec::{for_each_codec, for_each_other_codec, Codec};
+use crate::partial::{self, Partial};
+
+/// [`Update`](super::Update) with only `score`.
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateRead {
+    #[cfg_attr(feature = "bilrost", bilrost(2))]
+    #[cfg_attr(feature = "minicbor", n(1))]
+    pub score: u32,
+}
+
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdatesRead {
+    #[cfg_attr(feature = "bilrost", bilrost(encoding(packed)))]
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub updates: Vec<UpdateRead>,
+}
+
+impl Partial<Updates> for UpdatesRead {
+    fn read_full(full: &Updates) {
+        for update in full.updates.iter() {
+            black_box(update.score);
+        }
+    }
+
+    fn agrees(&self, full: &Updates) -> bool {
+        self.updates.len() == full.updates.len()
+            && self
+                .updates
+                .iter()
+                .zip(full.updates.iter())
+                .all(|(read, update)| read.score == update.score)
+    }
+
+    #[cfg(feature = "flexbuffers")]
+    fn read_flexbuffers(root: flexbuffers::Reader<&[u8]>) -> Self {
+        let updates = root.as_map().idx("updates").as_vector();
+        Self {
+            updates: updates
+                .iter()
+                .map(|update| UpdateRead {
+                    score: update.as_map().idx("score").as_u32(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_read {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UpdateRead {
+        #[prost(uint32, tag = "2")]
+        pub score: u32,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct UpdatesRead {
+        #[prost(message, repeated, tag = "1")]
+        pub updates: Vec<UpdateRead>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl bench_prost::Serialize for UpdatesRead {
+    type Message = prost_read::UpdatesRead;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            updates: self
+                .updates
+                .iter()
+                .map(|update| prost_read::UpdateRead {
+                    score: update.score,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<prost_read::UpdatesRead> for UpdatesRead {
+    fn from(value: prost_read::UpdatesRead) -> Self {
+        UpdatesRead {
+            updates: value
+                .updates
+                .into_iter()
+                .map(|update| UpdateRead {
+                    score: update.score,
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn bench(name: &str, c: &mut Criterion, data: &Updates) {
+    for_each_codec!(bench_codec, name, c, data);
+    for_each_other_codec!(bench_other_codec, name, c, data);
+}
+
+fn bench_codec<C>(lib: &str, name: &str, c: &mut Criterion, data: &Updates)
+where
+    C: Codec<Updates> + Codec<UpdatesRead>,
+{
+    partial::bench::<C, Updates, UpdatesRead>(name, lib, c, data);
+}
+
+fn bench_other_codec<C: Codec<Updates>>(lib: &str, name: &str, c: &mut Criterion, data: &Updates) {
+    partial::bench_full::<C, Updates, UpdatesRead>(name, lib, c, data);
+}
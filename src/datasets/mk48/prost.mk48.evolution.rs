@@ -0,0 +1,91 @@
+// This file is @generated by prost-build.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GuidanceAddField {
+    #[prost(uint32, tag = "1")]
+    pub angle: u32,
+    #[prost(bool, tag = "2")]
+    pub submerge: bool,
+    #[prost(int32, tag = "3")]
+    pub velocity: i32,
+    #[prost(uint32, optional, tag = "4")]
+    pub target_id: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GuidanceRemoveField {
+    #[prost(uint32, tag = "1")]
+    pub angle: u32,
+    #[prost(int32, tag = "3")]
+    pub velocity: i32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GuidanceReorderFields {
+    #[prost(int32, tag = "3")]
+    pub velocity: i32,
+    #[prost(uint32, tag = "1")]
+    pub angle: u32,
+    #[prost(bool, tag = "2")]
+    pub submerge: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Ship {
+    #[prost(enumeration = "super::EntityType", tag = "1")]
+    pub entity_type: i32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ShipAddVariant {
+    #[prost(enumeration = "EntityTypeAddVariant", tag = "1")]
+    pub entity_type: i32,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EntityTypeAddVariant {
+    ArleighBurke = 0,
+    Bismarck = 1,
+    Clemenceau = 2,
+    Fletcher = 3,
+    G5 = 4,
+    Iowa = 5,
+    Kolkata = 6,
+    Osa = 7,
+    Yasen = 8,
+    Zubr = 9,
+    Nimitz = 10,
+}
+impl EntityTypeAddVariant {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::ArleighBurke => "ARLEIGH_BURKE",
+            Self::Bismarck => "BISMARCK",
+            Self::Clemenceau => "CLEMENCEAU",
+            Self::Fletcher => "FLETCHER",
+            Self::G5 => "G5",
+            Self::Iowa => "IOWA",
+            Self::Kolkata => "KOLKATA",
+            Self::Osa => "OSA",
+            Self::Yasen => "YASEN",
+            Self::Zubr => "ZUBR",
+            Self::Nimitz => "NIMITZ",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ARLEIGH_BURKE" => Some(Self::ArleighBurke),
+            "BISMARCK" => Some(Self::Bismarck),
+            "CLEMENCEAU" => Some(Self::Clemenceau),
+            "FLETCHER" => Some(Self::Fletcher),
+            "G5" => Some(Self::G5),
+            "IOWA" => Some(Self::Iowa),
+            "KOLKATA" => Some(Self::Kolkata),
+            "OSA" => Some(Self::Osa),
+            "YASEN" => Some(Self::Yasen),
+            "ZUBR" => Some(Self::Zubr),
+            "NIMITZ" => Some(Self::Nimitz),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Deterministic encoding checks.
+//!
+//! Content addressing and signatures need the same value to always encode to the same bytes. Each
+//! library encodes equal values several times, built in different ways, and the outputs are
+//! compared byte for byte.
+
+use core::fmt;
+use std::collections::HashMap;
+
+#[cfg(feature = "protobuf")]
+mod determinism_protobuf;
+
+use crate::codec::{self, for_each_codec, try_encode, CanonicalMode, Encode};
+use crate::datasets::{
+    log::Logs,
+    mesh::{Mesh, Triangle, Vector3},
+    minecraft_savedata::Players,
+    mk48::Updates,
+};
+
+/// The number of times the dataset is encoded when checking repeated encodings.
+const REPEATS: usize = 3;
+
+/// The number of entries in the map built for the map order check.
+const TAGS: u32 = 16;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Every encoding was identical.
+    Yes,
+    /// Some encodings differed.
+    No,
+    /// The library could not encode the value.
+    Error,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Yes => "yes",
+            Self::No => "no",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// A map whose iteration order depends on how it was built.
+#[derive(PartialEq)]
+#[cfg_attr(feature = "bilrost", derive(bilrost::Message))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "databuf", derive(databuf::Encode, databuf::Decode))]
+#[cfg_attr(feature = "minicbor", derive(minicbor::Encode, minicbor::Decode))]
+#[cfg_attr(feature = "msgpacker", derive(msgpacker::MsgPacker))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[cfg_attr(
+    feature = "simd-json",
+    derive(simd_json_derive::Serialize, simd_json_derive::Deserialize)
+)]
+#[cfg_attr(feature = "speedy", derive(speedy::Readable, speedy::Writable))]
+#[cfg_attr(feature = "savefile", derive(savefile_derive::Savefile))]
+#[cfg_attr(feature = "nanoserde", derive(nanoserde::SerBin, nanoserde::DeBin))]
+pub struct Tags {
+    #[cfg_attr(feature = "minicbor", n(0))]
+    pub tags: HashMap<String, u32>,
+}
+
+impl Tags {
+    fn build(order: impl Iterator<Item = u32>) -> Self {
+        Self {
+            tags: order.map(|i| (format!("tag{}", i), i)).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_messages {
+    use std::collections::HashMap;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Tags {
+        #[prost(map = "string, uint32", tag = "1")]
+        pub tags: HashMap<String, u32>,
+    }
+}
+
+#[cfg(feature = "prost")]
+impl crate::bench_prost::Serialize for Tags {
+    type Message = prost_messages::Tags;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            tags: self.tags.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl From<prost_messages::Tags> for Tags {
+    fn from(value: prost_messages::Tags) -> Self {
+        Tags { tags: value.tags }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl crate::bench_protobuf::Serialize for Tags {
+    type Message = determinism_protobuf::determinism::Tags;
+
+    fn serialize_pb(&self) -> Self::Message {
+        Self::Message {
+            tags: self.tags.clone(),
+            special_fields: protobuf::SpecialFields::new(),
+        }
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<determinism_protobuf::determinism::Tags> for Tags {
+    fn from(value: determinism_protobuf::determinism::Tags) -> Self {
+        Tags { tags: value.tags }
+    }
+}
+
+/// A mesh with a single triangle whose coordinates are all `0.0` or all `-0.0`, which compare
+/// equal.
+fn zeros(zero: f32) -> Mesh {
+    let vector = Vector3 {
+        x: zero,
+        y: zero,
+        z: zero,
+    };
+    Mesh {
+        triangles: vec![Triangle {
+            v0: vector,
+            v1: vector,
+            v2: vector,
+            normal: vector,
+        }],
+    }
+}
+
+/// Whether every value encodes to the same bytes.
+fn compare<C: Encode<T>, T>(values: &[&T]) -> Outcome {
+    let mut encoded = Vec::with_capacity(values.len());
+    for value in values {
+        match try_encode::<C, T>(value) {
+            Ok(bytes) => encoded.push(bytes),
+            Err(_) => return Outcome::Error,
+        }
+    }
+
+    if encoded.windows(2).all(|pair| pair[0] == pair[1]) {
+        Outcome::Yes
+    } else {
+        Outcome::No
+    }
+}
+
+/// Checks one library and prints the outcomes.
+pub fn check<C, T>(name: &str, lib: &str, data: &T)
+where
+    C: Encode<T> + Encode<Tags> + Encode<Mesh>,
+{
+    // Each map gets its own random hasher, so even the same insertion order is likely to iterate
+    // differently
+    let map_order = compare::<C, Tags>(&[
+        &Tags::build(0..TAGS),
+        &Tags::build((0..TAGS).rev()),
+        &Tags::build((0..TAGS).step_by(2).chain((1..TAGS).step_by(2))),
+    ]);
+    report::<C, T>(name, lib, data, Some(map_order));
+}
+
+/// Checks a library whose data model has no maps, or which can't encode a `HashMap`. Users of
+/// these libraries can't put a map with an unstable order in their data, so the map order check
+/// doesn't apply.
+pub fn check_without_maps<C, T>(name: &str, lib: &str, data: &T)
+where
+    C: Encode<T> + Encode<Mesh>,
+{
+    report::<C, T>(name, lib, data, None);
+}
+
+fn report<C, T>(name: &str, lib: &str, data: &T, map_order: Option<Outcome>)
+where
+    C: Encode<T> + Encode<Mesh>,
+{
+    let repeat = compare::<C, T>(&[data; REPEATS]);
+    // `-0.0` and `0.0` are different values with different bits, but they compare equal, so data
+    // that is deduplicated or signed by value can hold either one
+    let negative_zero = compare::<C, Mesh>(&[&zeros(0.0), &zeros(-0.0)]);
+
+    let outcomes = [Some(repeat), map_order, Some(negative_zero)];
+    let outcomes = outcomes.iter().flatten();
+    let deterministic = if outcomes.clone().any(|&outcome| outcome == Outcome::Error) {
+        Outcome::Error
+    } else if outcomes.clone().all(|&outcome| outcome == Outcome::Yes) {
+        Outcome::Yes
+    } else {
+        Outcome::No
+    };
+
+    // A library that always encodes canonically has to pass the map order check in its default
+    // configuration, otherwise its documented mode isn't borne out
+    let canonical_mode = match <C as Encode<T>>::CANONICAL_MODE {
+        CanonicalMode::Always if map_order != Some(Outcome::Yes) => "unverified".to_owned(),
+        mode => mode.to_string(),
+    };
+
+    crate::bench_check(name, lib, "determinism/deterministic", deterministic);
+    crate::bench_check(name, lib, "determinism/canonical_mode", canonical_mode);
+    crate::bench_check(name, lib, "determinism/repeat", repeat);
+    if let Some(map_order) = map_order {
+        crate::bench_check(name, lib, "determinism/map_order", map_order);
+    }
+    crate::bench_check(name, lib, "determinism/negative_zero", negative_zero);
+}
+
+macro_rules! check_dataset {
+    ($fn:ident, $ty:ty) => {
+        pub fn $fn(name: &str, data: &$ty) {
+            fn check_codec<C>(lib: &str, name: &str, data: &$ty)
+            where
+                C: Encode<$ty> + Encode<Tags> + Encode<Mesh>,
+            {
+                check::<C, $ty>(name, lib, data);
+            }
+
+            for_each_codec!(check_codec, name, data);
+            #[cfg(feature = "bitcode")]
+            check_codec::<codec::Bitcode>("bitcode", name, data);
+            #[cfg(feature = "databuf")]
+            check_codec::<codec::Databuf>("databuf", name, data);
+            #[cfg(feature = "msgpacker")]
+            check_codec::<codec::Msgpacker>("msgpacker", name, data);
+            #[cfg(feature = "nanoserde")]
+            check_codec::<codec::Nanoserde>("nanoserde", name, data);
+            #[cfg(feature = "protobuf")]
+            check_codec::<codec::Protobuf>("protobuf", name, data);
+            #[cfg(feature = "rkyv")]
+            check_codec::<codec::Rkyv>("rkyv", name, data);
+            #[cfg(feature = "savefile")]
+            check_codec::<codec::Savefile>("savefile", name, data);
+            #[cfg(feature = "simd-json")]
+            check_codec::<codec::SimdJson>("simd-json", name, data);
+            #[cfg(feature = "speedy")]
+            check_codec::<codec::Speedy>("speedy", name, data);
+
+            // Cap'n Proto and FlatBuffers have no map type, `scale` only encodes `BTreeMap` and
+            // `wiring` has no map support
+            #[cfg(feature = "capnp")]
+            check_without_maps::<codec::Capnp, $ty>(name, "capnp", data);
+            #[cfg(feature = "flatbuffers")]
+            check_without_maps::<codec::Flatbuffers, $ty>(name, "flatbuffers", data);
+            #[cfg(feature = "scale")]
+            check_without_maps::<codec::Scale, $ty>(name, "scale", data);
+            #[cfg(feature = "wiring")]
+            check_without_maps::<codec::Wiring, $ty>(name, "wiring", data);
+        }
+    };
+}
+
+check_dataset!(check_log, Logs);
+check_dataset!(check_mesh, Mesh);
+check_dataset!(check_minecraft_savedata, Players);
+check_dataset!(check_mk48, Updates);
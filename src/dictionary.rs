@@ -0,0 +1,111 @@
+//! Zstd compression of individual messages with a trained dictionary.
+//!
+//! RPC-sized messages are too small for a compressor to find much repetition in. A dictionary
+//! trained on earlier messages gives it that context up front. Each library's encodings of the
+//! first half of a dataset's messages train a dictionary, and messages from the second half are
+//! compressed one at a time with and without it.
+
+use criterion::{black_box, Criterion};
+
+use crate::codec::{for_each_codec, Codec};
+use crate::datasets::{log::Logs, mesh::Mesh, minecraft_savedata::Players, mk48::Updates};
+
+/// The most messages used to train the dictionary and the most messages compressed with it.
+const MESSAGES: usize = 1000;
+
+/// The maximum size of a trained dictionary, which is typical for small messages.
+const DICTIONARY_SIZE: usize = 16 * 1024;
+
+/// zstd's default level, which the whole-buffer `zstd` results use too.
+const LEVEL: i32 = 3;
+
+/// Trains a dictionary for one library and benchmarks compressing messages with it.
+pub fn bench<C: Codec<T>, T>(name: &str, lib: &str, c: &mut Criterion, messages: &[T]) {
+    let (training, messages) = messages.split_at(messages.len() / 2);
+    let training = training
+        .iter()
+        .take(MESSAGES)
+        .map(C::encode)
+        .collect::<Vec<_>>();
+    let messages = messages
+        .iter()
+        .take(MESSAGES)
+        .map(C::encode)
+        .collect::<Vec<_>>();
+
+    // Training fails when there isn't enough data to learn from, which is a result in itself
+    let Ok(dictionary) = zstd::dict::from_samples(&training, DICTIONARY_SIZE) else {
+        return;
+    };
+    let mut compressor = zstd::bulk::Compressor::with_dictionary(LEVEL, &dictionary).unwrap();
+    let mut decompressor = zstd::bulk::Decompressor::with_dictionary(&dictionary).unwrap();
+
+    let compressed = messages
+        .iter()
+        .map(|message| compressor.compress(message).unwrap())
+        .collect::<Vec<_>>();
+    for (message, compressed) in messages.iter().zip(compressed.iter()) {
+        assert!(decompressor.decompress(compressed, message.len()).unwrap() == *message);
+    }
+
+    let size = messages.iter().map(Vec::len).sum::<usize>();
+    let zstd_size = messages
+        .iter()
+        .map(|message| zstd::bulk::compress(message, LEVEL).unwrap().len())
+        .sum::<usize>();
+    let zstd_dict_size = compressed.iter().map(Vec::len).sum::<usize>();
+    println!("{}/{}/message_size {}", name, lib, size);
+    println!("{}/{}/message_zstd {}", name, lib, zstd_size);
+    println!("{}/{}/message_zstd_dict {}", name, lib, zstd_dict_size);
+
+    let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+
+    group.bench_function("message_zstd_dict_time", |b| {
+        b.iter(|| {
+            for message in messages.iter() {
+                black_box(compressor.compress(black_box(message)).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("message_zstd_dict_decompress_time", |b| {
+        b.iter(|| {
+            for (message, compressed) in messages.iter().zip(compressed.iter()) {
+                black_box(
+                    decompressor
+                        .decompress(black_box(compressed), message.len())
+                        .unwrap(),
+                );
+            }
+        })
+    });
+
+    group.finish();
+}
+
+macro_rules! bench_dataset {
+    ($fn:ident, $ty:ty, $messages:ident, $message:ty) => {
+        pub fn $fn(name: &str, c: &mut Criterion, data: &$ty) {
+            fn bench_codec<C: Codec<$message>>(
+                lib: &str,
+                name: &str,
+                c: &mut Criterion,
+                messages: &[$message],
+            ) {
+                bench::<C, $message>(name, lib, c, messages);
+            }
+
+            for_each_codec!(bench_codec, name, c, &data.$messages);
+        }
+    };
+}
+
+bench_dataset!(bench_log, Logs, logs, crate::datasets::log::Log);
+bench_dataset!(bench_mesh, Mesh, triangles, crate::datasets::mesh::Triangle);
+bench_dataset!(
+    bench_minecraft_savedata,
+    Players,
+    players,
+    crate::datasets::minecraft_savedata::Player
+);
+bench_dataset!(bench_mk48, Updates, updates, crate::datasets::mk48::Update);
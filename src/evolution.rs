@@ -0,0 +1,117 @@
+//! Schema evolution checks.
+//!
+//! Each dataset defines "v2" versions of some of its types, each with a single kind of [`Change`]
+//! applied. Values are written with one version of the type and read back with the other to find
+//! out whether a library tolerates the change in each direction.
+
+use core::fmt;
+
+use crate::codec::{try_decode, Codec, Encode};
+
+/// The number of values from each dataset that are sent through each change.
+const SAMPLES: usize = 100;
+
+#[derive(Clone, Copy)]
+pub enum Change {
+    /// An optional field was added to the end of a struct.
+    AddField,
+    /// A field was removed from a struct.
+    RemoveField,
+    /// The fields of a struct were declared in a different order.
+    ReorderFields,
+    /// A variant was added to the end of an enum.
+    AddVariant,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::AddField => "add_field",
+            Self::RemoveField => "remove_field",
+            Self::ReorderFields => "reorder_fields",
+            Self::AddVariant => "add_variant",
+        })
+    }
+}
+
+/// The result of reading a value written with the other version of a type. Ordered from best to
+/// worst so that the outcome over many values is the maximum.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Outcome {
+    /// Every value decoded and agreed with the original on all shared fields.
+    Ok,
+    /// Decoding failed with an error (or a panic).
+    Error,
+    /// Decoding succeeded but produced a value that disagrees with the original.
+    Corrupt,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ok => "ok",
+            Self::Error => "error",
+            Self::Corrupt => "corrupt",
+        })
+    }
+}
+
+/// A newer version of `Old`.
+pub trait Evolution<Old>: Sized {
+    const CHANGE: Change;
+
+    /// The value that a writer using the new version would produce for `old`. This should use
+    /// whatever the change introduced (e.g. set the added field or pick the added variant).
+    fn upgrade(old: &Old) -> Self;
+
+    /// Whether this value agrees with `old` on everything the two versions have in common.
+    fn agrees(&self, old: &Old) -> bool;
+}
+
+/// Checks one change against one library in both directions and prints the outcomes.
+pub fn check<C, Old, New>(name: &str, lib: &str, values: &[Old])
+where
+    C: Codec<Old> + Codec<New>,
+    New: Evolution<Old>,
+{
+    let old_to_new = values
+        .iter()
+        .take(SAMPLES)
+        .map(|old| {
+            let bytes = <C as Encode<Old>>::encode(old);
+            classify(try_decode::<C, New>(&bytes), |new| new.agrees(old))
+        })
+        .max()
+        .unwrap_or(Outcome::Ok);
+    crate::bench_check(
+        name,
+        lib,
+        &format!("evolution/{}/old_to_new", New::CHANGE),
+        old_to_new,
+    );
+
+    let new_to_old = values
+        .iter()
+        .take(SAMPLES)
+        .map(|old| {
+            let new = New::upgrade(old);
+            let bytes = <C as Encode<New>>::encode(&new);
+            classify(try_decode::<C, Old>(&bytes), |old| new.agrees(old))
+        })
+        .max()
+        .unwrap_or(Outcome::Ok);
+    crate::bench_check(
+        name,
+        lib,
+        &format!("evolution/{}/new_to_old", New::CHANGE),
+        new_to_old,
+    );
+}
+
+fn classify<T>(decoded: Result<T, String>, agrees: impl FnOnce(&T) -> bool) -> Outcome {
+    match decoded {
+        Ok(value) if agrees(&value) => Outcome::Ok,
+        Ok(_) => Outcome::Corrupt,
+        Err(_) => Outcome::Error,
+    }
+}
@@ -0,0 +1,122 @@
+//! Interoperability checks between libraries that implement the same encoding.
+//!
+//! Every library in an encoding group writes the whole dataset and every library in the group
+//! (including the writer) reads it back. The groups mirror `common_encodings` in
+//! `tools/config.json`.
+
+use core::fmt;
+
+#[allow(unused_imports)]
+use crate::codec::{self, try_decode, Codec};
+use crate::datasets::{log::Logs, mesh::Mesh, minecraft_savedata::Players, mk48::Updates};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The reader decoded the data and would have written exactly the same bytes.
+    Identical,
+    /// The reader decoded the data, but would have written different bytes.
+    Ok,
+    /// The reader rejected the data.
+    Error,
+    /// The reader decoded the data into a different value.
+    Corrupt,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Identical => "identical",
+            Self::Ok => "ok",
+            Self::Error => "error",
+            Self::Corrupt => "corrupt",
+        })
+    }
+}
+
+pub struct Implementation<T> {
+    lib: &'static str,
+    encode: fn(&T) -> Vec<u8>,
+    decode: fn(&[u8]) -> Result<T, String>,
+}
+
+impl<T> Implementation<T> {
+    pub fn new<C: Codec<T>>(lib: &'static str) -> Self {
+        Self {
+            lib,
+            encode: C::encode,
+            decode: try_decode::<C, T>,
+        }
+    }
+}
+
+/// Checks every pair of implementations of one encoding and prints the outcomes.
+pub fn check<T: PartialEq>(name: &str, value: &T, implementations: &[Implementation<T>]) {
+    // A lone implementation has nothing to interoperate with
+    if implementations.len() < 2 {
+        return;
+    }
+
+    let encoded = implementations
+        .iter()
+        .map(|implementation| (implementation.encode)(value))
+        .collect::<Vec<_>>();
+
+    for (writer, bytes) in implementations.iter().zip(encoded.iter()) {
+        for (reader, own_bytes) in implementations.iter().zip(encoded.iter()) {
+            let outcome = match (reader.decode)(bytes) {
+                Ok(decoded) if decoded != *value => Outcome::Corrupt,
+                Ok(_) if bytes == own_bytes => Outcome::Identical,
+                Ok(_) => Outcome::Ok,
+                Err(_) => Outcome::Error,
+            };
+            crate::bench_check(
+                name,
+                writer.lib,
+                &format!("interop/{}", reader.lib),
+                outcome,
+            );
+        }
+    }
+}
+
+macro_rules! check_dataset {
+    ($fn:ident, $ty:ty) => {
+        pub fn $fn(name: &str, data: &$ty) {
+            let cbor: &[Implementation<$ty>] = &[
+                #[cfg(feature = "cbor4ii")]
+                Implementation::new::<codec::Cbor4ii>("cbor4ii"),
+                #[cfg(feature = "ciborium")]
+                Implementation::new::<codec::Ciborium>("ciborium"),
+                #[cfg(feature = "serde_cbor")]
+                Implementation::new::<codec::SerdeCbor>("serde_cbor"),
+            ];
+            let json: &[Implementation<$ty>] = &[
+                #[cfg(feature = "serde_json")]
+                Implementation::new::<codec::SerdeJson>("serde_json"),
+                #[cfg(feature = "simd-json")]
+                Implementation::new::<codec::SimdJson>("simd-json"),
+            ];
+            let messagepack: &[Implementation<$ty>] = &[
+                #[cfg(feature = "msgpacker")]
+                Implementation::new::<codec::Msgpacker>("msgpacker"),
+                #[cfg(feature = "rmp-serde")]
+                Implementation::new::<codec::RmpSerde>("rmp-serde"),
+            ];
+            let protobuf: &[Implementation<$ty>] = &[
+                #[cfg(feature = "prost")]
+                Implementation::new::<codec::Prost>("prost"),
+                #[cfg(feature = "protobuf")]
+                Implementation::new::<codec::Protobuf>("protobuf"),
+            ];
+
+            for implementations in [cbor, json, messagepack, protobuf] {
+                check(name, data, implementations);
+            }
+        }
+    };
+}
+
+check_dataset!(check_log, Logs);
+check_dataset!(check_mesh, Mesh);
+check_dataset!(check_minecraft_savedata, Players);
+check_dataset!(check_mk48, Updates);
@@ -2,6 +2,7 @@
 #![cfg_attr(feature = "wiring", allow(clippy::manual_async_fn))]
 #![cfg_attr(feature = "nibblecode", feature(ptr_alignment_type))]
 
+pub mod alignment;
 #[cfg(feature = "bilrost")]
 pub mod bench_bilrost;
 #[cfg(feature = "bincode")]
@@ -68,9 +69,28 @@ pub mod bench_simd_json;
 pub mod bench_speedy;
 #[cfg(feature = "wiring")]
 pub mod bench_wiring;
+pub mod codec;
 pub mod datasets;
+pub mod determinism;
+#[cfg(feature = "measure-compression")]
+pub mod dictionary;
+pub mod evolution;
+pub mod interop;
+#[cfg(feature = "async")]
+pub mod loopback;
+pub mod mmap;
+pub mod partial;
+pub mod random_access;
+pub mod stream;
+
+use core::{fmt::Display, mem, ops};
+use std::{
+    fs,
+    io::{self, BufReader, BufWriter, Seek, SeekFrom, Write},
+    path::PathBuf,
+};
 
-use core::{mem, ops};
+use criterion::{black_box, measurement::WallTime, BenchmarkGroup, Criterion};
 
 use rand::Rng;
 
@@ -160,43 +180,353 @@ pub fn generate_vec<R: Rng, T: Generate>(rng: &mut R, range: ops::Range<usize>)
     result
 }
 
-pub fn bench_size(name: &str, lib: &str, bytes: &[u8]) {
+/// Prints the size of a serialized buffer and, with `measure-compression`, its compressed sizes.
+/// Compression and decompression are benchmarked in a group named after the library's so they get
+/// the same warm-up and sampling as the serialize and deserialize benchmarks. The library's group
+/// has to be finished first.
+#[cfg_attr(not(feature = "measure-compression"), allow(unused_variables))]
+pub fn bench_size(c: &mut Criterion, name: &str, lib: &str, bytes: &[u8]) {
     println!("{}/{}/size {}", name, lib, bytes.len());
     #[cfg(feature = "measure-compression")]
     {
-        println!("{}/{}/zlib {}", name, lib, zlib_size(bytes));
-        println!("{}/{}/zstd {}", name, lib, zstd_size(bytes));
-        println!(
-            "{}/{}/zstd_time {}",
-            name,
-            lib,
-            bench_compression(|| zstd_size(bytes))
-        );
+        let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+        for compressor in COMPRESSORS {
+            let compressed = (compressor.compress)(bytes);
+            assert!((compressor.decompress)(&compressed) == bytes);
+            println!("{}/{}/{} {}", name, lib, compressor.name, compressed.len());
+
+            if !compressor.slow {
+                compressor.bench_compress(&mut group, bytes);
+            }
+            compressor.bench_decompress(&mut group, &compressed);
+        }
+        group.finish();
+
+        // Slow compressors take fewer samples in a group of their own, so the setting doesn't
+        // carry over to any other benchmark
+        #[cfg(feature = "measure-compression-extra")]
+        {
+            use criterion::SamplingMode;
+
+            let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+            group.sample_size(10).sampling_mode(SamplingMode::Flat);
+            for compressor in COMPRESSORS.iter().filter(|compressor| compressor.slow) {
+                compressor.bench_compress(&mut group, bytes);
+            }
+            group.finish();
+        }
+    }
+}
+
+/// Benchmarks serializing to an [`io::Write`] and deserializing from an [`io::Read`], both directly
+/// and through [`BufWriter`] and [`BufReader`]. These go through a temporary file so that every
+/// write and read the library makes is a system call, like it would be with a socket.
+///
+/// These are separate benchmarks from `serialize` and `deserialize` with the buffered runs as
+/// their variants, so they don't stand in for the in-memory times.
+pub fn bench_io<T: PartialEq>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    data: &T,
+    serialize: impl FnMut(&mut dyn io::Write, &T),
+    deserialize: impl FnMut(&mut dyn io::Read) -> T,
+) {
+    bench_io_with_check(group, data, serialize, deserialize, |decoded| {
+        decoded == *data
+    });
+}
+
+/// Like [`bench_io`] for libraries that read back something other than the value they wrote, such
+/// as a message that is accessed in place. `check` is given what was read back from the file.
+pub fn bench_io_with_check<T, D>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    data: &T,
+    mut serialize: impl FnMut(&mut dyn io::Write, &T),
+    mut deserialize: impl FnMut(&mut dyn io::Read) -> D,
+    check: impl FnOnce(D) -> bool,
+) {
+    let mut temp = TempFile::new();
+    let file = &mut temp.file;
+
+    bench_serialize_io(group, file, data, &mut serialize);
+    write_file(file, data, &mut serialize);
+
+    group.bench_function("deserialize_reader", |b| {
+        b.iter(|| {
+            file.seek(SeekFrom::Start(0)).unwrap();
+            black_box(deserialize(black_box(&mut *file)));
+        })
+    });
+
+    bench_deserialize_buffered(group, file, |reader| deserialize(reader));
+
+    file.seek(SeekFrom::Start(0)).unwrap();
+    assert!(check(deserialize(file)));
+}
+
+/// Like [`bench_io`] for libraries that can only deserialize from an [`io::BufRead`], so they only
+/// have the buffered reader variant.
+pub fn bench_buf_io<T: PartialEq>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    data: &T,
+    mut serialize: impl FnMut(&mut dyn io::Write, &T),
+    mut deserialize: impl FnMut(&mut dyn io::BufRead) -> T,
+) {
+    let mut temp = TempFile::new();
+    let file = &mut temp.file;
+
+    bench_serialize_io(group, file, data, &mut serialize);
+    write_file(file, data, &mut serialize);
+
+    bench_deserialize_buffered(group, file, &mut deserialize);
+
+    file.seek(SeekFrom::Start(0)).unwrap();
+    assert!(deserialize(&mut BufReader::new(file)) == *data);
+}
+
+/// Benchmarks serializing to an [`io::Write`] like [`bench_io`], for libraries that can only
+/// deserialize from in-memory buffers.
+pub fn bench_writer<T>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    data: &T,
+    mut serialize: impl FnMut(&mut dyn io::Write, &T),
+) {
+    let mut temp = TempFile::new();
+    bench_serialize_io(group, &mut temp.file, data, &mut serialize);
+}
+
+fn bench_serialize_io<T>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    file: &mut fs::File,
+    data: &T,
+    serialize: &mut impl FnMut(&mut dyn io::Write, &T),
+) {
+    group.bench_function("serialize_writer", |b| {
+        b.iter(|| {
+            file.seek(SeekFrom::Start(0)).unwrap();
+            serialize(black_box(&mut *file), black_box(data));
+        })
+    });
+
+    group.bench_function("serialize_writer (buffered)", |b| {
+        b.iter(|| {
+            file.seek(SeekFrom::Start(0)).unwrap();
+            let mut writer = BufWriter::new(&mut *file);
+            serialize(black_box(&mut writer), black_box(data));
+            writer.flush().unwrap();
+        })
+    });
+}
+
+fn bench_deserialize_buffered<D>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    file: &mut fs::File,
+    mut deserialize: impl FnMut(&mut dyn io::BufRead) -> D,
+) {
+    group.bench_function("deserialize_reader (buffered)", |b| {
+        b.iter(|| {
+            file.seek(SeekFrom::Start(0)).unwrap();
+            black_box(deserialize(black_box(&mut BufReader::new(&mut *file))));
+        })
+    });
+}
+
+/// Replaces the contents of the file with the serialized data, to be read back.
+fn write_file<T>(
+    file: &mut fs::File,
+    data: &T,
+    serialize: &mut impl FnMut(&mut dyn io::Write, &T),
+) {
+    file.seek(SeekFrom::Start(0)).unwrap();
+    serialize(file, data);
+    let len = file.stream_position().unwrap();
+    file.set_len(len).unwrap();
+}
+
+/// The file that [`bench_io`] goes through. It's removed when dropped, so it doesn't outlive a
+/// benchmark that panics.
+struct TempFile {
+    file: fs::File,
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn new() -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "rust_serialization_benchmark_{}",
+            std::process::id()
+        ));
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .unwrap();
+        Self { file, path }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        // This may run while unwinding, where a second panic would abort
+        let _ = fs::remove_file(&self.path);
     }
 }
 
+/// Marks a library as only able to serialize to and deserialize from in-memory buffers, which
+/// [`bench_io`] would have to go through.
+pub fn bench_slice_only(name: &str, lib: &str) {
+    bench_check(name, lib, "io", "slice-only");
+}
+
+pub fn bench_check(name: &str, lib: &str, check: &str, outcome: impl Display) {
+    println!("{}/{}/check/{} {}", name, lib, check, outcome);
+}
+
+/// A compression algorithm measured alongside every library's serialized size. The name prefixes
+/// its results (`<name>`, `<name>_time` and `<name>_decompress_time`) and must match a
+/// `Compression` variant in `pages`.
 #[cfg(feature = "measure-compression")]
-fn bench_compression(compress: impl Fn() -> usize) -> String {
-    let start = std::time::Instant::now();
-    let size = compress();
-    let elapsed = start.elapsed();
+struct Compressor {
+    name: &'static str,
+    compress: fn(&[u8]) -> Vec<u8>,
+    decompress: fn(&[u8]) -> Vec<u8>,
+    /// Whether compression takes long enough that it needs fewer samples to finish in reasonable
+    /// time.
+    slow: bool,
+}
+
+#[cfg(feature = "measure-compression")]
+const COMPRESSORS: &[Compressor] = &[
+    Compressor {
+        name: "zlib",
+        compress: zlib_compress,
+        decompress: zlib_decompress,
+        slow: false,
+    },
+    // zstd's default level, which earlier results were measured with
+    Compressor {
+        name: "zstd",
+        compress: |bytes| zstd_compress(bytes, 3),
+        decompress: zstd_decompress,
+        slow: false,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "zstd1",
+        compress: |bytes| zstd_compress(bytes, 1),
+        decompress: zstd_decompress,
+        slow: false,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "zstd9",
+        compress: |bytes| zstd_compress(bytes, 9),
+        decompress: zstd_decompress,
+        slow: false,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "zstd19",
+        compress: |bytes| zstd_compress(bytes, 19),
+        decompress: zstd_decompress,
+        slow: true,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "lz4",
+        compress: lz4_flex::compress_prepend_size,
+        decompress: |bytes| lz4_flex::decompress_size_prepended(bytes).unwrap(),
+        slow: false,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "snappy",
+        compress: |bytes| snap::raw::Encoder::new().compress_vec(bytes).unwrap(),
+        decompress: |bytes| snap::raw::Decoder::new().decompress_vec(bytes).unwrap(),
+        slow: false,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "brotli",
+        compress: brotli_compress,
+        decompress: brotli_decompress,
+        slow: true,
+    },
+    #[cfg(feature = "measure-compression-extra")]
+    Compressor {
+        name: "xz",
+        compress: xz_compress,
+        decompress: xz_decompress,
+        slow: true,
+    },
+];
 
-    let s = format!("{elapsed:.4?}");
-    let (number, unit): (String, String) = s.chars().partition(|c| c.is_numeric() || *c == '.');
-    let s = format!("{number} {unit}");
+#[cfg(feature = "measure-compression")]
+impl Compressor {
+    fn bench_compress(&self, group: &mut BenchmarkGroup<'_, WallTime>, bytes: &[u8]) {
+        group.bench_function(format!("{}_time", self.name), |b| {
+            b.iter(|| black_box((self.compress)(black_box(bytes))))
+        });
+    }
 
-    let mb_per_second = ((size as f64 / 1_000_000.0) / elapsed.as_secs_f64()) as u64;
-    format!("time:   [{s} {s} {s}] {mb_per_second} MB/s")
+    fn bench_decompress(&self, group: &mut BenchmarkGroup<'_, WallTime>, compressed: &[u8]) {
+        group.bench_function(format!("{}_decompress_time", self.name), |b| {
+            b.iter(|| black_box((self.decompress)(black_box(compressed))))
+        });
+    }
 }
 
 #[cfg(feature = "measure-compression")]
-fn zlib_size(mut bytes: &[u8]) -> usize {
+fn zlib_compress(mut bytes: &[u8]) -> Vec<u8> {
     let mut encoder = libflate::zlib::Encoder::new(Vec::new()).unwrap();
     std::io::copy(&mut bytes, &mut encoder).unwrap();
-    encoder.finish().into_result().unwrap().len()
+    encoder.finish().into_result().unwrap()
+}
+
+#[cfg(feature = "measure-compression")]
+fn zlib_decompress(bytes: &[u8]) -> Vec<u8> {
+    let mut decoder = libflate::zlib::Decoder::new(bytes).unwrap();
+    let mut result = Vec::new();
+    std::io::copy(&mut decoder, &mut result).unwrap();
+    result
+}
+
+#[cfg(feature = "measure-compression")]
+fn zstd_compress(bytes: &[u8], level: i32) -> Vec<u8> {
+    zstd::stream::encode_all(bytes, level).unwrap()
 }
 
 #[cfg(feature = "measure-compression")]
-fn zstd_size(bytes: &[u8]) -> usize {
-    zstd::stream::encode_all(bytes, 0).unwrap().len()
+fn zstd_decompress(bytes: &[u8]) -> Vec<u8> {
+    zstd::stream::decode_all(bytes).unwrap()
+}
+
+#[cfg(feature = "measure-compression-extra")]
+fn brotli_compress(mut bytes: &[u8]) -> Vec<u8> {
+    let mut result = Vec::new();
+    let params = brotli::enc::BrotliEncoderParams::default();
+    brotli::BrotliCompress(&mut bytes, &mut result, &params).unwrap();
+    result
+}
+
+#[cfg(feature = "measure-compression-extra")]
+fn brotli_decompress(mut bytes: &[u8]) -> Vec<u8> {
+    let mut result = Vec::new();
+    brotli::BrotliDecompress(&mut bytes, &mut result).unwrap();
+    result
+}
+
+#[cfg(feature = "measure-compression-extra")]
+fn xz_compress(bytes: &[u8]) -> Vec<u8> {
+    let mut result = Vec::new();
+    std::io::Read::read_to_end(&mut xz2::read::XzEncoder::new(bytes, 6), &mut result).unwrap();
+    result
+}
+
+#[cfg(feature = "measure-compression-extra")]
+fn xz_decompress(bytes: &[u8]) -> Vec<u8> {
+    let mut result = Vec::new();
+    std::io::Read::read_to_end(&mut xz2::read::XzDecoder::new(bytes), &mut result).unwrap();
+    result
 }
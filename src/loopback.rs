@@ -0,0 +1,120 @@
+//! Framed codec benchmarks over an in-process async transport.
+//!
+//! Services usually send messages one at a time with a length prefix. Each library's messages are
+//! written through a [`LengthDelimitedCodec`] into one end of an in-memory duplex stream, and read
+//! and decoded from the other end at the same time, so the time per message includes framing and
+//! async IO. The messages are the same ones that `pages` counts.
+
+use std::time::{Duration, Instant};
+
+use criterion::{black_box, Criterion};
+use futures_util::{future, SinkExt, StreamExt};
+use tokio_util::bytes::Bytes;
+use tokio_util::codec::{FramedRead, FramedWrite, LengthDelimitedCodec};
+
+use crate::codec::{for_each_codec, Codec};
+use crate::datasets::{log::Logs, mesh::Mesh, minecraft_savedata::Players, mk48::Updates};
+
+/// The capacity of the duplex stream, which is about a socket buffer's worth.
+const CAPACITY: usize = 64 * 1024;
+
+fn codec() -> LengthDelimitedCodec {
+    // A whole mesh is one message
+    LengthDelimitedCodec::builder()
+        .max_frame_length(usize::MAX)
+        .new_codec()
+}
+
+/// Benchmarks sending one library's messages over the loopback transport. Each criterion iteration
+/// is one message, cycling through `messages`.
+pub fn bench<C: Codec<T>, T: PartialEq>(name: &str, lib: &str, c: &mut Criterion, messages: &[T]) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    let received = runtime.block_on(send::<C, T, _>(messages, messages.len() as u64, |bytes| {
+        C::decode(bytes).unwrap()
+    }));
+    assert!(received.0 == messages);
+
+    let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+
+    group.bench_function("loopback", |b| {
+        b.iter_custom(|iters| {
+            runtime
+                .block_on(send::<C, T, _>(messages, iters, |bytes| {
+                    black_box(C::decode(black_box(bytes)).unwrap());
+                }))
+                .1
+        })
+    });
+
+    group.finish();
+}
+
+/// Sends `count` messages and receives them with `receive`, returning what it returned for each
+/// message and how long it took.
+async fn send<C: Codec<T>, T, R>(
+    messages: &[T],
+    count: u64,
+    receive: impl Fn(&[u8]) -> R,
+) -> (Vec<R>, Duration) {
+    let (client, server) = tokio::io::duplex(CAPACITY);
+    let mut writer = FramedWrite::new(client, codec());
+    let mut reader = FramedRead::new(server, codec());
+
+    let start = Instant::now();
+    let write = async {
+        for message in messages.iter().cycle().take(count as usize) {
+            writer.send(Bytes::from(C::encode(message))).await.unwrap();
+        }
+    };
+    let read = async {
+        let mut received = Vec::new();
+        for _ in 0..count {
+            let frame = reader.next().await.unwrap().unwrap();
+            received.push(receive(&frame));
+        }
+        received
+    };
+    let ((), received) = future::join(write, read).await;
+
+    (received, start.elapsed())
+}
+
+macro_rules! bench_dataset {
+    ($fn:ident, $ty:ty, |$data:ident| $messages:expr, $message:ty) => {
+        pub fn $fn(name: &str, c: &mut Criterion, $data: &$ty) {
+            fn bench_codec<C: Codec<$message>>(
+                lib: &str,
+                name: &str,
+                c: &mut Criterion,
+                messages: &[$message],
+            ) {
+                bench::<C, $message>(name, lib, c, messages);
+            }
+
+            for_each_codec!(bench_codec, name, c, $messages);
+        }
+    };
+}
+
+bench_dataset!(
+    bench_log,
+    Logs,
+    |data| &data.logs,
+    crate::datasets::log::Log
+);
+bench_dataset!(bench_mesh, Mesh, |data| core::slice::from_ref(data), Mesh);
+bench_dataset!(
+    bench_minecraft_savedata,
+    Players,
+    |data| &data.players,
+    crate::datasets::minecraft_savedata::Player
+);
+bench_dataset!(
+    bench_mk48,
+    Updates,
+    |data| &data.updates,
+    crate::datasets::mk48::Update
+);
@@ -0,0 +1,67 @@
+//! Zero-copy access through memory-mapped files.
+//!
+//! Zero-copy libraries are usually pointed at a memory-mapped file instead of a buffer that was
+//! just written to the heap. These benchmarks write the encoded buffer to a temporary file and map
+//! it again for every iteration, so the results include mapping the file and faulting in the pages
+//! that are touched.
+
+use std::{fs, path::PathBuf};
+
+use criterion::{black_box, measurement::WallTime, BenchmarkGroup};
+use memmap2::Mmap;
+
+/// A temporary file holding an encoded buffer, removed when dropped.
+pub struct MappedFile {
+    path: PathBuf,
+    file: fs::File,
+    align: usize,
+}
+
+impl MappedFile {
+    /// Writes `bytes` to a temporary file. Every mapping of it is checked to be aligned to `align`,
+    /// which libraries that access their buffers in place rely on.
+    pub fn new(bytes: &[u8], align: usize) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "rust_serialization_benchmark_{}.mmap",
+            std::process::id()
+        ));
+        fs::write(&path, bytes).unwrap();
+        let file = fs::File::open(&path).unwrap();
+
+        Self { path, file, align }
+    }
+
+    /// Maps the whole file into memory.
+    pub fn map(&self) -> Mmap {
+        // Safety: the file is private to this process and is not modified while it is mapped
+        let map = unsafe { Mmap::map(&self.file) }.unwrap();
+        assert!(
+            (map.as_ptr() as usize).is_multiple_of(self.align),
+            "mapping is not aligned to {} bytes",
+            self.align
+        );
+        map
+    }
+}
+
+impl Drop for MappedFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Benchmarks running `f` on a fresh mapping of `file`. Each iteration maps the file, runs `f` on
+/// it and unmaps it.
+pub fn bench(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    id: &str,
+    file: &MappedFile,
+    f: impl Fn(&[u8]),
+) {
+    group.bench_function(id, |b| {
+        b.iter(|| {
+            let map = file.map();
+            f(black_box(&map));
+        })
+    });
+}
@@ -0,0 +1,88 @@
+//! Partial reads for libraries that don't have zero-copy access.
+//!
+//! The zero-copy `read` benchmarks pull a few fields out of every message. Other libraries have to
+//! decode something to do the same, and the cheapest thing they can decode is a type that only
+//! declares those fields. Self-describing and tagged formats skip the rest of each message; formats
+//! that can't skip fields decode the whole dataset and read the fields out of it instead, as do the
+//! libraries whose traits the partial types don't derive. `flexbuffers` reads the fields lazily
+//! with its own reader.
+
+use criterion::{black_box, measurement::WallTime, BenchmarkGroup, Criterion};
+
+use crate::codec::{try_decode, Codec, Encode};
+
+/// A version of `Full` that only has the fields read by the zero-copy `read` benchmarks.
+pub trait Partial<Full>: Sized {
+    /// Reads the fields out of a fully decoded value.
+    fn read_full(full: &Full);
+
+    /// Whether this value has the same fields as `full`.
+    fn agrees(&self, full: &Full) -> bool;
+
+    /// Reads the fields lazily from the root of a flexbuffer.
+    #[cfg(feature = "flexbuffers")]
+    fn read_flexbuffers(root: flexbuffers::Reader<&[u8]>) -> Self;
+}
+
+/// Benchmarks reading the fields with the cheapest way that works for the library.
+pub fn bench<C, Full, P>(name: &str, lib: &str, c: &mut Criterion, data: &Full)
+where
+    C: Codec<Full> + Codec<P>,
+    P: Partial<Full>,
+{
+    let bytes = <C as Encode<Full>>::encode(data);
+
+    let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+
+    #[cfg(feature = "flexbuffers")]
+    if lib == "flexbuffers" {
+        let read =
+            |bytes: &[u8]| P::read_flexbuffers(flexbuffers::Reader::get_root(bytes).unwrap());
+        assert!(read(bytes.as_slice()).agrees(data));
+
+        group.bench_function("read (lazy reader)", |b| {
+            b.iter(|| black_box(read(black_box(bytes.as_slice()))))
+        });
+
+        group.finish();
+        return;
+    }
+
+    // Libraries that can't skip fields either fail here or read the wrong values
+    let skips = try_decode::<C, P>(&bytes).is_ok_and(|partial| partial.agrees(data));
+    if skips {
+        group.bench_function("read (skipping fields)", |b| {
+            b.iter(|| black_box(<C as Codec<P>>::decode(black_box(&bytes)).unwrap()))
+        });
+    } else {
+        bench_full_decode::<C, Full, P>(&mut group, &bytes);
+    }
+
+    group.finish();
+}
+
+/// Benchmarks reading the fields out of a full decode, for libraries that can't decode `P`.
+pub fn bench_full<C, Full, P>(name: &str, lib: &str, c: &mut Criterion, data: &Full)
+where
+    C: Codec<Full>,
+    P: Partial<Full>,
+{
+    let bytes = <C as Encode<Full>>::encode(data);
+
+    let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+    bench_full_decode::<C, Full, P>(&mut group, &bytes);
+    group.finish();
+}
+
+fn bench_full_decode<C, Full, P>(group: &mut BenchmarkGroup<'_, WallTime>, bytes: &[u8])
+where
+    C: Codec<Full>,
+    P: Partial<Full>,
+{
+    group.bench_function("read (full decode)", |b| {
+        b.iter(|| {
+            let full = <C as Codec<Full>>::decode(black_box(bytes)).unwrap();
+            P::read_full(&full);
+        })
+    });
+}
@@ -0,0 +1,67 @@
+//! Random access into the elements of a large container.
+//!
+//! On-disk indexes look up single elements by position instead of walking the whole container. Each
+//! lookup reads one element at a random index. Zero-copy libraries index into the buffer directly,
+//! and every other library has to decode the whole container to get at one element.
+
+use std::time::Instant;
+
+use criterion::{black_box, measurement::WallTime, BenchmarkGroup, Criterion};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::codec::{for_each_codec, Codec};
+use crate::datasets::{log::Logs, mesh::Mesh};
+
+/// The number of random indices that lookups cycle through.
+const LOOKUPS: usize = 1000;
+
+/// Benchmarks looking up random elements of a container with `len` elements. Each criterion
+/// iteration is one lookup, so the results are latency per lookup.
+pub fn bench(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    id: &str,
+    len: usize,
+    lookup: impl Fn(usize),
+) {
+    let mut rng = StdRng::seed_from_u64(len as u64);
+    let indices = (0..LOOKUPS)
+        .map(|_| rng.gen_range(0..len))
+        .collect::<Vec<_>>();
+
+    group.bench_function(id, |b| {
+        b.iter_custom(|iters| {
+            let start = Instant::now();
+            for &index in indices.iter().cycle().take(iters as usize) {
+                lookup(black_box(index));
+            }
+            start.elapsed()
+        })
+    });
+}
+
+macro_rules! bench_dataset {
+    ($fn:ident, $ty:ty, $elements:ident) => {
+        pub fn $fn(name: &str, c: &mut Criterion, data: &$ty) {
+            fn bench_codec<C: Codec<$ty>>(lib: &str, name: &str, c: &mut Criterion, data: &$ty) {
+                let bytes = C::encode(data);
+
+                let mut group = c.benchmark_group(format!("{}/{}", name, lib));
+                bench(
+                    &mut group,
+                    "random_access (full decode)",
+                    data.$elements.len(),
+                    |index| {
+                        let data = C::decode(black_box(&bytes)).unwrap();
+                        black_box(&data.$elements[index]);
+                    },
+                );
+                group.finish();
+            }
+
+            for_each_codec!(bench_codec, name, c, data);
+        }
+    };
+}
+
+bench_dataset!(bench_log, Logs, logs);
+bench_dataset!(bench_mesh, Mesh, triangles);
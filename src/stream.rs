@@ -0,0 +1,318 @@
+//! Multi-message streams of length-framed records.
+//!
+//! Logs and event feeds are usually written as a stream of records rather than one container that
+//! holds all of them. Each dataset's messages are encoded into one stream and decoded from it one
+//! record at a time. Libraries with a native way to delimit records use it: varint length prefixes
+//! for protobuf, COBS for postcard, CBOR sequences and newline-delimited JSON. Every other library
+//! gets a varint length prefix in front of each message.
+//!
+//! The overhead is the number of bytes the stream spends on framing beyond the messages themselves.
+
+use criterion::{black_box, Criterion};
+#[allow(unused_imports)]
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::codec::{for_each_codec, Codec};
+use crate::datasets::{log::Logs, mesh::Mesh, minecraft_savedata::Players, mk48::Updates};
+
+/// Libraries from [`for_each_codec`] that have a native framing below instead of a length prefix.
+const NATIVE: &[&str] = &["ciborium", "postcard", "prost", "serde_cbor", "serde_json"];
+
+/// One library's way of writing and reading a stream of messages.
+pub struct Framing<T> {
+    pub lib: &'static str,
+    pub encode: fn(&[T]) -> Vec<u8>,
+    pub decode: fn(&[u8]) -> Vec<T>,
+}
+
+impl<T> Framing<T> {
+    /// Frames each message with a varint length prefix.
+    pub fn length_prefixed<C: Codec<T>>(lib: &'static str) -> Self {
+        Self {
+            lib,
+            encode: |messages| {
+                let mut buffer = Vec::new();
+                for message in messages {
+                    let bytes = C::encode(message);
+                    write_varint(&mut buffer, bytes.len());
+                    buffer.extend_from_slice(&bytes);
+                }
+                buffer
+            },
+            decode: |mut bytes| {
+                let mut messages = Vec::new();
+                while !bytes.is_empty() {
+                    let len = read_varint(&mut bytes);
+                    let (message, rest) = bytes.split_at(len);
+                    messages.push(C::decode(message).unwrap());
+                    bytes = rest;
+                }
+                messages
+            },
+        }
+    }
+}
+
+fn write_varint(buffer: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> usize {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = bytes.split_first().unwrap();
+        *bytes = rest;
+        value |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(feature = "ciborium")]
+fn ciborium_framing<T: Serialize + DeserializeOwned>() -> Framing<T> {
+    Framing {
+        lib: "ciborium",
+        encode: |messages| {
+            let mut buffer = Vec::new();
+            for message in messages {
+                ciborium::ser::into_writer(message, &mut buffer).unwrap();
+            }
+            buffer
+        },
+        decode: |mut bytes| {
+            let mut messages = Vec::new();
+            while !bytes.is_empty() {
+                messages.push(ciborium::de::from_reader(&mut bytes).unwrap());
+            }
+            messages
+        },
+    }
+}
+
+#[cfg(feature = "postcard")]
+fn postcard_framing<T: Serialize + DeserializeOwned>() -> Framing<T> {
+    Framing {
+        lib: "postcard",
+        encode: |messages| {
+            let mut buffer = Vec::new();
+            for message in messages {
+                buffer.extend_from_slice(&postcard::to_allocvec_cobs(message).unwrap());
+            }
+            buffer
+        },
+        decode: |bytes| {
+            // COBS decodes in place, so each read starts from a fresh copy of the stream
+            let mut buffer = bytes.to_vec();
+            let mut bytes = buffer.as_mut_slice();
+            let mut messages = Vec::new();
+            while !bytes.is_empty() {
+                let (message, rest) = postcard::take_from_bytes_cobs(bytes).unwrap();
+                messages.push(message);
+                bytes = rest;
+            }
+            messages
+        },
+    }
+}
+
+#[cfg(feature = "prost")]
+fn prost_framing<T: crate::bench_prost::Serialize>() -> Framing<T> {
+    use prost::Message;
+
+    Framing {
+        lib: "prost",
+        encode: |messages| {
+            let mut buffer = Vec::new();
+            for message in messages {
+                message
+                    .serialize_pb()
+                    .encode_length_delimited(&mut buffer)
+                    .unwrap();
+            }
+            buffer
+        },
+        decode: |mut bytes| {
+            let mut messages = Vec::new();
+            while !bytes.is_empty() {
+                messages.push(
+                    T::Message::decode_length_delimited(&mut bytes)
+                        .unwrap()
+                        .into(),
+                );
+            }
+            messages
+        },
+    }
+}
+
+#[cfg(feature = "protobuf")]
+fn protobuf_framing<T: crate::bench_protobuf::Serialize>() -> Framing<T> {
+    use protobuf::Message;
+
+    Framing {
+        lib: "protobuf",
+        encode: |messages| {
+            let mut buffer = Vec::new();
+            for message in messages {
+                message
+                    .serialize_pb()
+                    .write_length_delimited_to_vec(&mut buffer)
+                    .unwrap();
+            }
+            buffer
+        },
+        decode: |bytes| {
+            let mut input = protobuf::CodedInputStream::from_bytes(bytes);
+            let mut messages = Vec::new();
+            while !input.eof().unwrap() {
+                messages.push(input.read_message::<T::Message>().unwrap().into());
+            }
+            messages
+        },
+    }
+}
+
+#[cfg(feature = "serde_cbor")]
+fn serde_cbor_framing<T: Serialize + DeserializeOwned>() -> Framing<T> {
+    Framing {
+        lib: "serde_cbor",
+        encode: |messages| {
+            let mut buffer = Vec::new();
+            for message in messages {
+                serde_cbor::to_writer(&mut buffer, message).unwrap();
+            }
+            buffer
+        },
+        decode: |bytes| {
+            serde_cbor::Deserializer::from_slice(bytes)
+                .into_iter()
+                .map(Result::unwrap)
+                .collect()
+        },
+    }
+}
+
+#[cfg(feature = "serde_json")]
+fn serde_json_framing<T: Serialize + DeserializeOwned>() -> Framing<T> {
+    Framing {
+        lib: "serde_json",
+        encode: |messages| {
+            let mut buffer = Vec::new();
+            for message in messages {
+                serde_json::to_writer(&mut buffer, message).unwrap();
+                buffer.push(b'\n');
+            }
+            buffer
+        },
+        decode: |bytes| {
+            serde_json::Deserializer::from_slice(bytes)
+                .into_iter()
+                .map(Result::unwrap)
+                .collect()
+        },
+    }
+}
+
+/// A framing and the size of a message without it.
+pub type Measured<T> = (Framing<T>, fn(&T) -> usize);
+
+/// Benchmarks writing and reading a stream with each framing.
+pub fn bench<T: PartialEq>(
+    name: &str,
+    c: &mut Criterion,
+    messages: &[T],
+    framings: &[Measured<T>],
+) {
+    for (framing, plain) in framings {
+        let encoded = (framing.encode)(messages);
+        assert!((framing.decode)(&encoded) == messages);
+
+        let plain_total = messages.iter().map(plain).sum::<usize>();
+        assert!(
+            encoded.len() >= plain_total,
+            "{} stream of {} bytes is smaller than its messages ({} bytes)",
+            framing.lib,
+            encoded.len(),
+            plain_total
+        );
+        let overhead = encoded.len() - plain_total;
+        println!("{}/{}/stream_size {}", name, framing.lib, encoded.len());
+        println!("{}/{}/stream_overhead {}", name, framing.lib, overhead);
+
+        let mut group = c.benchmark_group(format!("{}/{}", name, framing.lib));
+
+        group.bench_function("stream_serialize", |b| {
+            b.iter(|| black_box((framing.encode)(black_box(messages))))
+        });
+
+        group.bench_function("stream_deserialize", |b| {
+            b.iter(|| black_box((framing.decode)(black_box(&encoded))))
+        });
+
+        group.finish();
+    }
+}
+
+fn plain_size<C: Codec<T>, T>(message: &T) -> usize {
+    C::encode(message).len()
+}
+
+macro_rules! bench_dataset {
+    ($fn:ident, $ty:ty, $messages:ident, $message:ty) => {
+        pub fn $fn(name: &str, c: &mut Criterion, data: &$ty) {
+            #[allow(unused_mut)]
+            let mut framings = Vec::<Measured<$message>>::new();
+
+            fn push_codec<C: Codec<$message>>(
+                lib: &'static str,
+                framings: &mut Vec<Measured<$message>>,
+            ) {
+                let framing = if !NATIVE.contains(&lib) {
+                    Framing::length_prefixed::<C>(lib)
+                } else {
+                    match lib {
+                        #[cfg(feature = "ciborium")]
+                        "ciborium" => ciborium_framing(),
+                        #[cfg(feature = "postcard")]
+                        "postcard" => postcard_framing(),
+                        #[cfg(feature = "prost")]
+                        "prost" => prost_framing(),
+                        #[cfg(feature = "serde_cbor")]
+                        "serde_cbor" => serde_cbor_framing(),
+                        #[cfg(feature = "serde_json")]
+                        "serde_json" => serde_json_framing(),
+                        _ => unreachable!(),
+                    }
+                };
+                framings.push((framing, plain_size::<C, $message>));
+            }
+
+            for_each_codec!(push_codec, &mut framings);
+            #[cfg(feature = "protobuf")]
+            framings.push((protobuf_framing(), |message| {
+                use protobuf::Message;
+
+                crate::bench_protobuf::Serialize::serialize_pb(message).compute_size() as usize
+            }));
+
+            bench(name, c, &data.$messages, &framings);
+        }
+    };
+}
+
+bench_dataset!(bench_log, Logs, logs, crate::datasets::log::Log);
+bench_dataset!(bench_mesh, Mesh, triangles, crate::datasets::mesh::Triangle);
+bench_dataset!(
+    bench_minecraft_savedata,
+    Players,
+    players,
+    crate::datasets::minecraft_savedata::Player
+);
+bench_dataset!(bench_mk48, Updates, updates, crate::datasets::mk48::Update);
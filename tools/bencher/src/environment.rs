@@ -0,0 +1,291 @@
+//! Records the environment that the benchmarks are run in, so that runs can be checked for
+//! comparability.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::Path,
+    process::Command,
+};
+
+use schema::{CpuFrequency, Environment, Git};
+use toml_edit::{DocumentMut, Item};
+
+use crate::Error;
+
+/// Profile settings that affect performance, and their defaults for the `bench` profile.
+const PROFILE_SETTINGS: [(&str, &str); 5] = [
+    ("opt-level", "3"),
+    ("lto", "false"),
+    ("codegen-units", "16"),
+    ("debug", "false"),
+    ("panic", "unwind"),
+];
+
+/// Collects the environment that `bench_command` will be run in.
+pub fn collect(bench_command: &[String]) -> Result<Environment, Error> {
+    let manifest = parse_toml(
+        Path::new("Cargo.toml"),
+        &fs::read_to_string("Cargo.toml").map_err(|source| Error::Read {
+            path: "Cargo.toml".into(),
+            source,
+        })?,
+    )?;
+    let rustflags = rustflags()?;
+
+    Ok(Environment {
+        git: git(),
+        features: features(bench_command, &manifest),
+        target_cpu: target_cpu(&rustflags),
+        rustflags,
+        profile: profile(&manifest),
+        kernel: output("uname", &["-sr"]),
+        cpu_frequency: cpu_frequency(),
+        total_memory: total_memory(),
+        command_line: bench_command.to_vec(),
+    })
+}
+
+fn parse_toml(path: &Path, contents: &str) -> Result<DocumentMut, Error> {
+    contents.parse().map_err(|source| Error::Toml {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Runs a command and returns its trimmed output, or `None` if it couldn't be run or failed.
+fn output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn read(path: impl AsRef<Path>) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|contents| contents.trim().to_string())
+}
+
+fn git() -> Option<Git> {
+    Some(Git {
+        commit: output("git", &["rev-parse", "HEAD"])?,
+        dirty: !output("git", &["status", "--porcelain"])?.is_empty(),
+    })
+}
+
+/// Resolves the features enabled by the cargo feature flags in `args`, including the features that
+/// they enable in turn.
+fn features(args: &[String], manifest: &DocumentMut) -> Vec<String> {
+    let mut declared = manifest
+        .get("features")
+        .and_then(Item::as_table)
+        .map(|features| {
+            features
+                .iter()
+                .map(|(name, enables)| {
+                    let enables = enables
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|feature| feature.as_str())
+                        .collect::<Vec<_>>();
+                    (name, enables)
+                })
+                .collect::<BTreeMap<_, _>>()
+        })
+        .unwrap_or_default();
+    // Optional dependencies are features too, unless they're only enabled with `dep:`
+    let dep_enabled = declared
+        .values()
+        .flatten()
+        .filter_map(|feature| feature.strip_prefix("dep:"))
+        .collect::<BTreeSet<_>>();
+    if let Some(dependencies) = manifest.get("dependencies").and_then(Item::as_table_like) {
+        for (name, dependency) in dependencies.iter() {
+            let optional = dependency
+                .get("optional")
+                .and_then(Item::as_bool)
+                .unwrap_or(false);
+            if optional && !dep_enabled.contains(name) {
+                declared.entry(name).or_default();
+            }
+        }
+    }
+
+    let mut requested = Vec::new();
+    let mut default = true;
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        let list = match arg {
+            // Arguments after `--` are passed on to the benchmarks
+            "--" => break,
+            "--all-features" => {
+                requested.extend(declared.keys().copied());
+                continue;
+            }
+            "--no-default-features" => {
+                default = false;
+                continue;
+            }
+            "--features" | "-F" => args.next(),
+            arg => arg
+                .strip_prefix("--features=")
+                .or_else(|| arg.strip_prefix("-F")),
+        };
+        requested.extend(
+            list.into_iter()
+                .flat_map(|list| list.split([',', ' ']))
+                .filter(|feature| !feature.is_empty()),
+        );
+    }
+    if default {
+        requested.push("default");
+    }
+
+    let mut enabled = BTreeSet::new();
+    while let Some(feature) = requested.pop() {
+        // Dependency features like `dep:foo` and `foo/bar` aren't features of this crate
+        if declared.contains_key(feature) && enabled.insert(feature.to_string()) {
+            requested.extend(declared[feature].iter().copied());
+        }
+    }
+    enabled.into_iter().collect()
+}
+
+/// Finds the flags that cargo passes to rustc, in the same order of precedence as cargo.
+fn rustflags() -> Result<Vec<String>, Error> {
+    if let Ok(flags) = env::var("CARGO_ENCODED_RUSTFLAGS") {
+        return Ok(flags
+            .split('\x1f')
+            .filter(|flag| !flag.is_empty())
+            .map(String::from)
+            .collect());
+    }
+    if let Ok(flags) = env::var("RUSTFLAGS") {
+        return Ok(flags.split_whitespace().map(String::from).collect());
+    }
+
+    let path = Path::new(".cargo/config.toml");
+    let Some(config) = read(path) else {
+        return Ok(Vec::new());
+    };
+    let config = parse_toml(path, &config)?;
+    Ok(
+        match config.get("build").and_then(|build| build.get("rustflags")) {
+            Some(Item::Value(toml_edit::Value::Array(flags))) => flags
+                .iter()
+                .filter_map(|flag| flag.as_str())
+                .map(String::from)
+                .collect(),
+            Some(flags) => flags
+                .as_str()
+                .map(|flags| flags.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
+            None => Vec::new(),
+        },
+    )
+}
+
+/// Finds the `target-cpu` in `rustflags`, and which CPU it is if it's `native`.
+fn target_cpu(rustflags: &[String]) -> Option<String> {
+    let cpu = rustflags
+        .iter()
+        .find_map(|flag| flag.split_once("target-cpu=").map(|(_, cpu)| cpu))?;
+    if cpu != "native" {
+        return Some(cpu.to_string());
+    }
+
+    // rustc describes `native` as "Select the CPU of the current host (currently znver3)."
+    let host = output("rustc", &["--print", "target-cpus"]).and_then(|cpus| {
+        cpus.lines()
+            .find(|line| line.trim_start().starts_with("native"))
+            .and_then(|line| line.split_once("(currently "))
+            .and_then(|(_, host)| host.split_once(')'))
+            .map(|(host, _)| host.to_string())
+    });
+    Some(match host {
+        Some(host) => format!("native ({host})"),
+        None => cpu.to_string(),
+    })
+}
+
+fn setting_value(item: &Item) -> Option<String> {
+    let value = item.as_value()?;
+    value
+        .as_str()
+        .map(String::from)
+        .or_else(|| value.as_bool().map(|value| value.to_string()))
+        .or_else(|| value.as_integer().map(|value| value.to_string()))
+}
+
+/// Resolves the settings of the `bench` profile, which inherits from the `release` profile. Each
+/// profile's settings can be overridden with `CARGO_PROFILE_<name>_<setting>` variables.
+fn profile(manifest: &DocumentMut) -> BTreeMap<String, String> {
+    PROFILE_SETTINGS
+        .iter()
+        .map(|(setting, default)| {
+            let value = ["bench", "release"]
+                .iter()
+                .find_map(|profile| {
+                    let var = format!("CARGO_PROFILE_{profile}_{setting}")
+                        .replace('-', "_")
+                        .to_uppercase();
+                    env::var(var).ok().or_else(|| {
+                        manifest
+                            .get("profile")?
+                            .get(profile)?
+                            .get(setting)
+                            .and_then(setting_value)
+                    })
+                })
+                .unwrap_or_else(|| default.to_string());
+            (setting.to_string(), value)
+        })
+        .collect()
+}
+
+/// Reads how Linux's cpufreq scales the CPUs' clock speeds.
+fn cpu_frequency() -> Option<CpuFrequency> {
+    let mut governors = BTreeSet::new();
+    for entry in fs::read_dir("/sys/devices/system/cpu").ok()?.flatten() {
+        let is_cpu = entry.file_name().to_str().is_some_and(|name| {
+            name.strip_prefix("cpu")
+                .is_some_and(|index| index.parse::<u32>().is_ok())
+        });
+        if is_cpu {
+            governors.extend(read(entry.path().join("cpufreq/scaling_governor")));
+        }
+    }
+    let driver = read("/sys/devices/system/cpu/cpu0/cpufreq/scaling_driver");
+    // intel_pstate reports whether turbo is disabled, and other drivers whether boost is enabled
+    let boost = read("/sys/devices/system/cpu/intel_pstate/no_turbo")
+        .map(|no_turbo| no_turbo == "0")
+        .or_else(|| read("/sys/devices/system/cpu/cpufreq/boost").map(|boost| boost == "1"));
+
+    if driver.is_none() && governors.is_empty() && boost.is_none() {
+        return None;
+    }
+    Some(CpuFrequency {
+        driver,
+        governors: governors.into_iter().collect(),
+        boost,
+    })
+}
+
+fn total_memory() -> Option<u64> {
+    if let Some(meminfo) = read("/proc/meminfo") {
+        let kilobytes = meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix("MemTotal:"))?
+            .trim()
+            .strip_suffix("kB")?
+            .trim()
+            .parse::<u64>()
+            .ok()?;
+        Some(kilobytes * 1024)
+    } else {
+        output("sysctl", &["-n", "hw.memsize"])?.parse().ok()
+    }
+}
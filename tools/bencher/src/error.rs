@@ -0,0 +1,20 @@
+use std::{io, path::PathBuf, process::ExitStatus};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("failed to run `{command}`")]
+    Spawn { command: String, source: io::Error },
+    #[error("`{command}` failed with {status}")]
+    Failed { command: String, status: ExitStatus },
+    #[error("failed to read {}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("failed to parse {}", path.display())]
+    Toml {
+        path: PathBuf,
+        source: toml_edit::TomlError,
+    },
+    #[error("failed to write {}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error("failed to write a temporary file")]
+    TempFile(#[source] io::Error),
+}
@@ -1,34 +1,98 @@
-use std::{fs, path::PathBuf, process::Command};
+mod environment;
+mod error;
 
-use tempfile::NamedTempFile;
+use std::{
+    env, fs,
+    io::Write,
+    iter,
+    path::PathBuf,
+    process::{Command, ExitCode, ExitStatus, Stdio},
+};
+
+use error::Error;
+use schema::error::report;
+use tempfile::{NamedTempFile, TempPath};
 use time::OffsetDateTime;
 
-fn main() {
+/// Runs the benchmarks and formats their results. Arguments are passed on to `cargo bench`, e.g.
+/// `cargo run -p bencher -- --features rkyv`.
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn command_line(command: &Command) -> String {
+    iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|arg| arg.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn check(command: &Command, status: ExitStatus) -> Result<(), Error> {
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::Failed {
+            command: command_line(command),
+            status,
+        })
+    }
+}
+
+fn spawn_error(command: &Command) -> impl FnOnce(std::io::Error) -> Error + '_ {
+    |source| Error::Spawn {
+        command: command_line(command),
+        source,
+    }
+}
+
+/// Runs `command` to completion and returns what it printed to stdout.
+fn output(command: &mut Command) -> Result<Vec<u8>, Error> {
+    let output = command.output().map_err(spawn_error(command))?;
+    check(command, output.status)?;
+    Ok(output.stdout)
+}
+
+fn status(command: &mut Command) -> Result<(), Error> {
+    let status = command.status().map_err(spawn_error(command))?;
+    check(command, status)
+}
+
+fn temp_file(contents: &[u8]) -> Result<TempPath, Error> {
+    let mut file = NamedTempFile::new().map_err(Error::TempFile)?;
+    file.write_all(contents).map_err(Error::TempFile)?;
+    Ok(file.into_temp_path())
+}
+
+fn run() -> Result<(), Error> {
     let now = OffsetDateTime::now_utc();
 
-    let metadata_path = NamedTempFile::new().unwrap().into_temp_path();
-    let metadata = Command::new("cargo")
-        .args(["metadata", "--format-version", "1"])
-        .output()
-        .unwrap()
-        .stdout;
-    fs::write(&metadata_path, metadata).unwrap();
-
-    let rustc_info_path = NamedTempFile::new().unwrap().into_temp_path();
-    let rustc_version = Command::new("rustc")
-        .args(["--version", "--verbose"])
-        .output()
-        .unwrap()
-        .stdout;
-    fs::write(&rustc_info_path, rustc_version).unwrap();
+    let bench_command = ["cargo".to_string(), "bench".to_string()]
+        .into_iter()
+        .chain(env::args().skip(1))
+        .collect::<Vec<_>>();
+
+    let metadata_path = temp_file(&output(Command::new("cargo").args([
+        "metadata",
+        "--format-version",
+        "1",
+    ]))?)?;
+
+    let rustc_info_path = temp_file(&output(
+        Command::new("rustc").args(["--version", "--verbose"]),
+    )?)?;
 
     #[cfg(target_os = "linux")]
-    let cpu_info_path = {
-        let cpu_info_path = NamedTempFile::new().unwrap().into_temp_path();
-        let lscpu = Command::new("lscpu").output().unwrap().stdout;
-        fs::write(&cpu_info_path, lscpu).unwrap();
-        cpu_info_path
-    };
+    let cpu_info_path = temp_file(&output(&mut Command::new("lscpu"))?)?;
+
+    let environment = environment::collect(&bench_command)?;
+    let environment_path = temp_file(serde_json::to_string(&environment).unwrap().as_bytes())?;
 
     let mut bench_path = PathBuf::from("benchmark_results");
     bench_path.push(format!(
@@ -43,12 +107,16 @@ fn main() {
 
     let mut log_path = bench_path.clone();
     log_path.set_extension("log");
-    let log = Command::new("cargo")
-        .args(["bench"])
-        .output()
-        .unwrap()
-        .stdout;
-    fs::write(&log_path, log).unwrap();
+    let mut bench = Command::new(&bench_command[0]);
+    // Build errors and criterion's progress are printed to stderr, so they're shown as they happen
+    bench.args(&bench_command[1..]).stderr(Stdio::inherit());
+    let log = bench.output().map_err(spawn_error(&bench))?;
+    // The log is kept even if the benchmarks failed, since it shows how far they got
+    fs::write(&log_path, log.stdout).map_err(|source| Error::Write {
+        path: log_path.clone(),
+        source,
+    })?;
+    check(&bench, log.status)?;
 
     let mut config_path = PathBuf::from("tools");
     config_path.push("config.json");
@@ -65,38 +133,40 @@ fn main() {
         .arg(&metadata_path)
         .arg("--rustc-info")
         .arg(&rustc_info_path)
+        .arg("--environment")
+        .arg(&environment_path)
+        .arg("--criterion")
+        .arg("target/criterion")
         .arg("--output")
         .arg(&json_path);
     #[cfg(target_os = "linux")]
     parser.arg("--cpu-info").arg(&cpu_info_path);
-    parser.status().unwrap();
+    status(&mut parser)?;
 
     let mut template_path = PathBuf::from("tools");
     template_path.push("README.md.template");
 
-    Command::new("cargo")
-        .args(["run", "-p", "formatter", "--"])
-        .arg(&json_path)
-        .arg("--config")
-        .arg(&config_path)
-        .arg("--template")
-        .arg(&template_path)
-        .args([
-            "--date",
-            &format!(
-                "{yr}-{mon:02}-{day:02} {hr:02}:{min:02}:{sec:02}",
-                yr = now.year(),
-                mon = now.month() as usize,
-                day = now.day(),
-                hr = now.hour(),
-                min = now.minute(),
-                sec = now.second(),
-            ),
-            "--output",
-            "README.md",
-        ])
-        .status()
-        .unwrap();
-
-    metadata_path.close().unwrap();
+    status(
+        Command::new("cargo")
+            .args(["run", "-p", "formatter", "--"])
+            .arg(&json_path)
+            .arg("--config")
+            .arg(&config_path)
+            .arg("--template")
+            .arg(&template_path)
+            .args([
+                "--date",
+                &format!(
+                    "{yr}-{mon:02}-{day:02} {hr:02}:{min:02}:{sec:02}",
+                    yr = now.year(),
+                    mon = now.month() as usize,
+                    day = now.day(),
+                    hr = now.hour(),
+                    min = now.minute(),
+                    sec = now.second(),
+                ),
+                "--output",
+                "README.md",
+            ]),
+    )
 }
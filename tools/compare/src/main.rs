@@ -0,0 +1,554 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use clap::Parser;
+
+use schema::{error::report, value_name, Bench, Results, Stats};
+
+#[derive(Parser, Debug)]
+#[command(name = "compare")]
+#[command(about = "Compares the results of two benchmarking runs and reports regressions")]
+#[command(
+    after_help = "Exits with status 1 if the results can't be read and 2 if anything regressed."
+)]
+struct Args {
+    /// The results to compare against
+    baseline: PathBuf,
+    /// The results to compare
+    candidate: PathBuf,
+    /// The largest change, in percent, that isn't reported as a regression or improvement
+    #[arg(short, long, default_value_t = 5.0)]
+    threshold: f64,
+    /// List every benchmark instead of only the ones that regressed or improved
+    #[arg(short, long)]
+    all: bool,
+    /// Print Markdown suitable for a PR comment instead of plain text
+    #[arg(short, long)]
+    markdown: bool,
+}
+
+/// The exit status when anything regressed, distinct from [`ExitCode::FAILURE`] for results that
+/// can't be read so that scripts can tell a regression from a broken run.
+const REGRESSED: u8 = 2;
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let (baseline, candidate) = match (
+        Results::read(&args.baseline),
+        Results::read(&args.candidate),
+    ) {
+        (Ok(baseline), Ok(candidate)) => (baseline, candidate),
+        (Err(error), _) | (_, Err(error)) => {
+            report(&error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = Report::new(&baseline, &candidate, args.threshold);
+
+    let mut output = String::new();
+    if args.markdown {
+        report.write_markdown(&mut output, args.all).unwrap();
+    } else {
+        report.write_text(&mut output, args.all).unwrap();
+    }
+    print!("{output}");
+
+    if report.count(Verdict::Regressed) > 0 {
+        ExitCode::from(REGRESSED)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// A single value from a run, with the bounds of its confidence interval if it has one.
+#[derive(Clone, Copy)]
+struct Measurement {
+    value: f64,
+    interval: Option<(f64, f64)>,
+    unit: Unit,
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Nanos,
+    Bytes,
+}
+
+impl Measurement {
+    fn nanos(value: f64, stats: Option<&Stats>) -> Self {
+        Self {
+            value,
+            interval: stats.map(|stats| (stats.lower, stats.upper)),
+            unit: Unit::Nanos,
+        }
+    }
+
+    /// Sizes are exact, so any difference between them is significant.
+    fn bytes(value: u64) -> Self {
+        Self {
+            value: value as f64,
+            interval: Some((value as f64, value as f64)),
+            unit: Unit::Bytes,
+        }
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Unit::Nanos => {
+                let (value, suffix) = if self.value > 1_000_000.0 {
+                    (self.value / 1_000_000.0, "ms")
+                } else if self.value > 1_000.0 {
+                    (self.value / 1_000.0, "µs")
+                } else {
+                    (self.value, "ns")
+                };
+                let precision = 4usize.saturating_sub(value.log10().floor() as usize);
+                write!(f, "{value:.*} {suffix}", precision)
+            }
+            Unit::Bytes => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// Collects every value in `results`, keyed by their benchmark IDs in the same form criterion
+/// prints them, e.g. `log/rkyv/read (unvalidated)`.
+fn measurements(results: &Results) -> BTreeMap<String, Measurement> {
+    let mut measurements = BTreeMap::new();
+    for (dataset_name, dataset) in results.datasets.iter() {
+        for (feature_name, feature) in dataset.features.iter() {
+            for (bench_name, bench) in feature.benches.iter() {
+                let id = |variant| {
+                    format!(
+                        "{dataset_name}/{feature_name}/{}",
+                        value_name(bench_name, variant)
+                    )
+                };
+                match bench {
+                    Bench::Nanos(values) => {
+                        for (variant, value, stats) in values.iter_named() {
+                            measurements.insert(id(variant), Measurement::nanos(*value, stats));
+                        }
+                    }
+                    Bench::Bytes(values) => {
+                        for (variant, value, _) in values.iter_named() {
+                            measurements.insert(id(variant), Measurement::bytes(*value));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    measurements
+}
+
+/// Whether a change is larger than the noise in its measurements.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Significance {
+    /// The confidence intervals don't overlap
+    Significant,
+    /// The confidence intervals overlap
+    Noise,
+    /// At least one of the measurements has no confidence interval
+    Unknown,
+}
+
+impl Significance {
+    fn new(baseline: &Measurement, candidate: &Measurement) -> Self {
+        match (baseline.interval, candidate.interval) {
+            (Some((baseline_lower, baseline_upper)), Some((candidate_lower, candidate_upper))) => {
+                if candidate_lower > baseline_upper || candidate_upper < baseline_lower {
+                    Significance::Significant
+                } else {
+                    Significance::Noise
+                }
+            }
+            _ => Significance::Unknown,
+        }
+    }
+}
+
+impl Display for Significance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Significance::Significant => write!(f, "yes"),
+            Significance::Noise => write!(f, "no"),
+            Significance::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+/// How a benchmark changed between runs. Lower is better for both times and sizes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Verdict {
+    Regressed,
+    Improved,
+    Unchanged,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Regressed => write!(f, "regressed"),
+            Verdict::Improved => write!(f, "improved"),
+            Verdict::Unchanged => write!(f, "unchanged"),
+        }
+    }
+}
+
+struct Row {
+    id: String,
+    baseline: Measurement,
+    candidate: Measurement,
+    /// The change from the baseline to the candidate, in percent
+    change: f64,
+    significance: Significance,
+    verdict: Verdict,
+}
+
+struct Report {
+    threshold: f64,
+    /// Rows ordered by verdict, then by benchmark ID
+    rows: Vec<Row>,
+    only_in_baseline: usize,
+    only_in_candidate: usize,
+    /// Crate versions and environment settings that differ between runs, with their baseline and
+    /// candidate values
+    changes: Vec<(String, String, String)>,
+    /// The first lines of both runs' rustc info, if they differ
+    rustc_change: Option<(String, String)>,
+}
+
+impl Report {
+    fn new(baseline: &Results, candidate: &Results, threshold: f64) -> Self {
+        let baseline_measurements = measurements(baseline);
+        let candidate_measurements = measurements(candidate);
+
+        let mut rows = Vec::new();
+        for (id, old) in baseline_measurements.iter() {
+            let Some(new) = candidate_measurements.get(id) else {
+                continue;
+            };
+            let change = (new.value - old.value) / old.value * 100.0;
+            let significance = Significance::new(old, new);
+            // Changes without confidence intervals can't be told apart from noise, so they're
+            // judged by the threshold alone
+            let verdict = if significance == Significance::Noise || change.abs() <= threshold {
+                Verdict::Unchanged
+            } else if change > 0.0 {
+                Verdict::Regressed
+            } else {
+                Verdict::Improved
+            };
+            rows.push(Row {
+                id: id.clone(),
+                baseline: *old,
+                candidate: *new,
+                change,
+                significance,
+                verdict,
+            });
+        }
+        rows.sort_by(|a, b| (a.verdict, &a.id).cmp(&(b.verdict, &b.id)));
+
+        let mut changes = baseline
+            .features
+            .iter()
+            .filter_map(|(feature, old)| {
+                let new = candidate.features.get(feature)?;
+                (old.crate_name != new.crate_name || old.version != new.version).then(|| {
+                    (
+                        feature.clone(),
+                        format!("{} {}", old.crate_name, old.version),
+                        format!("{} {}", new.crate_name, new.version),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        if let (Some(old), Some(new)) = (&baseline.environment, &candidate.environment) {
+            changes.extend(old.differences(new));
+        }
+
+        let first_line = |info: &str| info.lines().next().unwrap_or_default().to_string();
+        let rustc_change = (baseline.rustc_info != candidate.rustc_info).then(|| {
+            (
+                first_line(&baseline.rustc_info),
+                first_line(&candidate.rustc_info),
+            )
+        });
+
+        Self {
+            threshold,
+            only_in_baseline: baseline_measurements
+                .keys()
+                .filter(|id| !candidate_measurements.contains_key(*id))
+                .count(),
+            only_in_candidate: candidate_measurements
+                .keys()
+                .filter(|id| !baseline_measurements.contains_key(*id))
+                .count(),
+            rows,
+            changes,
+            rustc_change,
+        }
+    }
+
+    fn count(&self, verdict: Verdict) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| row.verdict == verdict)
+            .count()
+    }
+
+    fn listed(&self, all: bool) -> impl Iterator<Item = &Row> {
+        self.rows
+            .iter()
+            .filter(move |row| all || row.verdict != Verdict::Unchanged)
+    }
+
+    fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} regressed, {} improved and {} unchanged of {} benchmarks compared with a threshold of {}%",
+            self.count(Verdict::Regressed),
+            self.count(Verdict::Improved),
+            self.count(Verdict::Unchanged),
+            self.rows.len(),
+            self.threshold,
+        );
+        if self.only_in_baseline > 0 || self.only_in_candidate > 0 {
+            write!(
+                summary,
+                " ({} only in the baseline, {} only in the candidate)",
+                self.only_in_baseline, self.only_in_candidate
+            )
+            .unwrap();
+        }
+        summary
+    }
+
+    fn write_text(&self, output: &mut String, all: bool) -> fmt::Result {
+        writeln!(output, "{}", self.summary())?;
+        if let Some((old, new)) = &self.rustc_change {
+            writeln!(output, "rustc: {old} -> {new}")?;
+        }
+        for (name, old, new) in self.changes.iter() {
+            writeln!(output, "{name}: {old} -> {new}")?;
+        }
+
+        let header = [
+            "benchmark",
+            "baseline",
+            "candidate",
+            "change",
+            "significant",
+            "verdict",
+        ]
+        .map(String::from);
+        let rows = self
+            .listed(all)
+            .map(|row| {
+                [
+                    row.id.clone(),
+                    row.baseline.to_string(),
+                    row.candidate.to_string(),
+                    format!("{:+.2}%", row.change),
+                    row.significance.to_string(),
+                    row.verdict.to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut widths = [0; 6];
+        for row in rows.iter().chain([&header]) {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        writeln!(output)?;
+        for row in [&header].into_iter().chain(rows.iter()) {
+            let (id, cells) = row.split_first().unwrap();
+            write!(output, "{id:<0$}", widths[0])?;
+            for (cell, width) in cells.iter().zip(widths[1..].iter()) {
+                write!(output, "  {cell:>width$}")?;
+            }
+            writeln!(output)?;
+        }
+        Ok(())
+    }
+
+    fn write_markdown(&self, output: &mut String, all: bool) -> fmt::Result {
+        writeln!(output, "### Benchmark comparison")?;
+        writeln!(output)?;
+        writeln!(output, "{}", self.summary())?;
+        if self.rustc_change.is_some() || !self.changes.is_empty() {
+            writeln!(output)?;
+        }
+        if let Some((old, new)) = &self.rustc_change {
+            writeln!(output, "- rustc: `{old}` → `{new}`")?;
+        }
+        for (name, old, new) in self.changes.iter() {
+            writeln!(output, "- {name}: `{old}` → `{new}`")?;
+        }
+
+        let mut rows = self.listed(all).peekable();
+        if rows.peek().is_none() {
+            return Ok(());
+        }
+        writeln!(output)?;
+        writeln!(
+            output,
+            "| Benchmark | Baseline | Candidate | Change | Significant |"
+        )?;
+        writeln!(output, "|---|--:|--:|--:|:-:|")?;
+        for row in rows {
+            let change = match row.verdict {
+                Verdict::Regressed => format!("**{:+.2}%** 🔴", row.change),
+                Verdict::Improved => format!("**{:+.2}%** 🟢", row.change),
+                Verdict::Unchanged => format!("{:+.2}%", row.change),
+            };
+            writeln!(
+                output,
+                "| `{}` | {} | {} | {change} | {} |",
+                row.id, row.baseline, row.candidate, row.significance
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// A time with a confidence interval of ±1%.
+    fn time(value: f64) -> Value {
+        json!({
+            "Nanos": {
+                "primary": value,
+                "variants": {},
+                "primary_stats": { "lower": value * 0.99, "upper": value * 1.01 },
+            }
+        })
+    }
+
+    fn run(benches: Value) -> Results {
+        let json = json!({
+            "schema_version": schema::SCHEMA_VERSION,
+            "cpu_info": null,
+            "rustc_info": "rustc 1.80.0",
+            "datasets": { "log": { "features": { "bincode": { "benches": benches } } } },
+            "features": { "bincode": { "crate_name": "bincode", "version": "2.0.1" } },
+        });
+        Results::from_json(&json.to_string()).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn verdict(report: &Report, bench: &str) -> Verdict {
+        let id = format!("log/bincode/{bench}");
+        report.rows.iter().find(|row| row.id == id).unwrap().verdict
+    }
+
+    fn nanos(value: f64, interval: Option<(f64, f64)>) -> Measurement {
+        Measurement {
+            value,
+            interval,
+            unit: Unit::Nanos,
+        }
+    }
+
+    #[test]
+    fn significance_from_overlapping_intervals() {
+        let baseline = nanos(100.0, Some((95.0, 105.0)));
+        let overlapping = nanos(108.0, Some((104.0, 112.0)));
+        let disjoint = nanos(110.0, Some((106.0, 114.0)));
+        let unknown = nanos(110.0, None);
+        assert!(Significance::new(&baseline, &overlapping) == Significance::Noise);
+        assert!(Significance::new(&baseline, &disjoint) == Significance::Significant);
+        assert!(Significance::new(&baseline, &unknown) == Significance::Unknown);
+    }
+
+    #[test]
+    fn verdicts() {
+        let baseline = run(json!({
+            "regressed": time(100.0),
+            "improved": time(100.0),
+            "within_threshold": time(100.0),
+            "within_noise": {
+                "Nanos": {
+                    "primary": 100.0,
+                    "variants": {},
+                    "primary_stats": { "lower": 80.0, "upper": 120.0 },
+                }
+            },
+            "without_stats": { "Nanos": { "primary": 100.0, "variants": {} } },
+            "size": { "Bytes": { "primary": 100, "variants": {} } },
+            "removed": time(100.0),
+        }));
+        let candidate = run(json!({
+            "regressed": time(120.0),
+            "improved": time(80.0),
+            "within_threshold": time(104.0),
+            "within_noise": {
+                "Nanos": {
+                    "primary": 110.0,
+                    "variants": {},
+                    "primary_stats": { "lower": 90.0, "upper": 130.0 },
+                }
+            },
+            "without_stats": { "Nanos": { "primary": 120.0, "variants": {} } },
+            "size": { "Bytes": { "primary": 110, "variants": {} } },
+            "added": time(100.0),
+        }));
+        let report = Report::new(&baseline, &candidate, 5.0);
+
+        assert!(verdict(&report, "regressed") == Verdict::Regressed);
+        assert!(verdict(&report, "improved") == Verdict::Improved);
+        assert!(verdict(&report, "within_threshold") == Verdict::Unchanged);
+        assert!(verdict(&report, "within_noise") == Verdict::Unchanged);
+        // Without a confidence interval only the threshold applies
+        assert!(verdict(&report, "without_stats") == Verdict::Regressed);
+        assert!(verdict(&report, "size") == Verdict::Regressed);
+
+        assert_eq!(report.count(Verdict::Regressed), 3);
+        assert_eq!(report.only_in_baseline, 1);
+        assert_eq!(report.only_in_candidate, 1);
+        assert!(report
+            .rows
+            .windows(2)
+            .all(|rows| rows[0].verdict <= rows[1].verdict));
+    }
+
+    #[test]
+    fn reports_crate_version_changes() {
+        let baseline = run(json!({ "serialize": time(100.0) }));
+        let mut candidate = run(json!({ "serialize": time(100.0) }));
+        candidate.features.get_mut("bincode").unwrap().version = "2.0.2".to_string();
+        let report = Report::new(&baseline, &candidate, 5.0);
+        assert_eq!(
+            report.changes,
+            [(
+                "bincode".to_string(),
+                "bincode 2.0.1".to_string(),
+                "bincode 2.0.2".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn formats_times() {
+        assert_eq!(nanos(12.5, None).to_string(), "12.500 ns");
+        assert_eq!(nanos(1_234_567.0, None).to_string(), "1.2346 ms");
+        // More digits before the point than the precision allows
+        assert_eq!(nanos(123_456_000_000.0, None).to_string(), "123456 ms");
+    }
+}
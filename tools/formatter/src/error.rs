@@ -0,0 +1,19 @@
+use std::{fmt, io, path::PathBuf};
+
+use schema::error::ReadError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Read(#[from] ReadError),
+    #[error("failed to read the template {}", path.display())]
+    Template { path: PathBuf, source: io::Error },
+    #[error("feature `{feature}` has results but no crate")]
+    MissingCrate { feature: String },
+    #[error("dataset `{dataset}` is missing from the config's suites")]
+    MissingSuite { dataset: String },
+    #[error("failed to format the tables")]
+    Format(#[from] fmt::Error),
+    #[error("failed to write {}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
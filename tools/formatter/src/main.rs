@@ -1,14 +1,20 @@
+mod error;
+
 use std::{
     borrow::Cow,
     collections::BTreeSet,
     fmt::{self, Display, Write},
     fs,
     path::PathBuf,
+    process::ExitCode,
 };
 
 use clap::Parser;
 
-use schema::{Bench, Config, Dataset, FeatureName, Features, Results, Values};
+use error::Error;
+use schema::{
+    error::report, Bench, Config, Dataset, FeatureName, Features, PackageId, Results, Stats, Values,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "formatter")]
@@ -25,20 +31,36 @@ struct Args {
     output: PathBuf,
 }
 
-fn main() {
-    let args = Args::parse();
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let config = Config::read(&args.config)?;
 
-    let config = Config::read(&args.config);
+    let results = Results::read(&args.input)?;
+    let template = fs::read_to_string(&args.template).map_err(|source| Error::Template {
+        path: args.template.clone(),
+        source,
+    })?;
 
-    let results =
-        serde_json::from_str::<Results>(&fs::read_to_string(args.input).unwrap()).unwrap();
-    let template = fs::read_to_string(args.template).unwrap();
+    let readme = format(&results, &config, &template, &args.date)?;
+    fs::write(&args.output, readme).map_err(|source| Error::Write {
+        path: args.output.clone(),
+        source,
+    })
+}
 
-    fs::write(
-        args.output,
-        format(&results, &config, &template, &args.date).unwrap(),
-    )
-    .unwrap();
+fn find_crate<'a>(features: &'a Features, feature: &str) -> Result<&'a PackageId, Error> {
+    features.get(feature).ok_or_else(|| Error::MissingCrate {
+        feature: feature.to_string(),
+    })
 }
 
 struct Tables {
@@ -58,7 +80,8 @@ impl Display for Nanos {
         } else {
             (self.0, "ns")
         };
-        write!(f, "{value:.*} {suffix}", 4 - value.log10().floor() as usize)
+        let precision = 4usize.saturating_sub(value.log10().floor() as usize);
+        write!(f, "{value:.*} {suffix}", precision)
     }
 }
 
@@ -78,20 +101,50 @@ impl Display for Percent {
     }
 }
 
+/// The best value in a column, and the upper bound of its confidence interval if it has one.
+#[derive(Clone, Copy, Default)]
+struct Best {
+    value: f64,
+    upper: Option<f64>,
+}
+
+impl Best {
+    /// Returns whether `value` is worse than the best value by less than the noise, which is when
+    /// their confidence intervals overlap.
+    fn within_noise(&self, value: f64, stats: Option<&Stats>) -> bool {
+        match (self.upper, stats) {
+            (Some(upper), Some(stats)) => value > self.value && stats.lower <= upper,
+            _ => false,
+        }
+    }
+}
+
+/// A value relative to the best one in its column, marked if the difference is within the noise.
+struct Relative(Percent, bool);
+
+impl Display for Relative {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.1 {
+            write!(f, "≈")?;
+        }
+        write!(f, "{}", self.0)
+    }
+}
+
 fn format_values<T: Copy, U: Display>(
     values: &Values<T>,
     output: &mut String,
-    display: impl Fn(T) -> U,
+    display: impl Fn(T, Option<&Stats>) -> U,
 ) -> fmt::Result {
     if let Some(value) = values.primary {
-        write!(output, " {}", display(value))?;
+        write!(output, " {}", display(value, values.primary_stats.as_ref()))?;
     }
 
     for (name, value) in values.variants.iter() {
         write!(
             output,
             " <span title=\"{name}\">*{val}\\**</span>",
-            val = display(*value)
+            val = display(*value, values.variant_stats.get(name))
         )?;
     }
     write!(output, " |")?;
@@ -102,25 +155,27 @@ fn write_crate_row(
     output: &mut String,
     feature: FeatureName<'_>,
     features: &Features,
-) -> fmt::Result {
-    let package_id = features.get(feature.name).unwrap();
+    marker: &str,
+) -> Result<(), Error> {
+    let package_id = find_crate(features, feature.name)?;
     if let Some(encoding) = feature.common_encoding {
         write!(
             output,
-            "| {encoding}:<br> [{pkg} {version}][{feature}] |",
+            "| {encoding}:<br> [{pkg} {version}][{feature}]{marker} |",
             pkg = package_id.crate_name,
             version = package_id.version,
             feature = feature.name,
-        )
+        )?;
     } else {
         write!(
             output,
-            "| [{pkg} {version}][{feature}] |",
+            "| [{pkg} {version}][{feature}]{marker} |",
             pkg = package_id.crate_name,
             version = package_id.version,
             feature = feature.name,
-        )
+        )?;
     }
+    Ok(())
 }
 
 pub fn capitalize(s: &str) -> String {
@@ -141,8 +196,9 @@ fn build_tables(
     dataset: &Dataset,
     config: &Config,
     columns: &[&str],
+    required: Option<&str>,
     placeholder: &str,
-) -> Result<Tables, fmt::Error> {
+) -> Result<Tables, Error> {
     let mut header = "| Crate |".to_string();
     for column in columns {
         write!(&mut header, " {} |", capitalize(column))?;
@@ -155,7 +211,7 @@ fn build_tables(
     let mut data = String::new();
     let mut comparison = String::new();
 
-    let mins = columns
+    let bests = columns
         .iter()
         .cloned()
         .map(|col| {
@@ -163,30 +219,52 @@ fn build_tables(
                 .features
                 .values()
                 .filter_map(|feature| feature.benches.get(col))
-                .map(|bench| match bench {
-                    Bench::Nanos(values) => values.iter().cloned().reduce(f64::min).unwrap(),
-                    Bench::Bytes(values) => values.iter().cloned().min().unwrap() as f64,
+                .flat_map(|bench| match bench {
+                    Bench::Nanos(values) => values
+                        .iter_with_stats()
+                        .map(|(&value, stats)| Best {
+                            value,
+                            upper: stats.map(|stats| stats.upper),
+                        })
+                        .collect::<Vec<_>>(),
+                    Bench::Bytes(values) => values
+                        .iter()
+                        .map(|&value| Best {
+                            value: value as f64,
+                            upper: None,
+                        })
+                        .collect(),
                 })
-                .reduce(f64::min)
+                .reduce(|a, b| if b.value < a.value { b } else { a })
                 .unwrap_or_default()
         })
         .collect::<Vec<_>>();
 
     for (feature, crate_) in dataset.grouped_features(config) {
-        if columns.iter().any(|&c| crate_.benches.contains_key(c)) {
-            write_crate_row(&mut data, feature, features)?;
-            write_crate_row(&mut comparison, feature, features)?;
+        if columns.iter().any(|&c| crate_.benches.contains_key(c))
+            && required.into_iter().all(|c| crate_.benches.contains_key(c))
+        {
+            let marker = match crate_.checks.get("io").map(String::as_str) {
+                Some("slice-only") => " ¶",
+                _ => "",
+            };
+            write_crate_row(&mut data, feature, features, marker)?;
+            write_crate_row(&mut comparison, feature, features, marker)?;
 
-            for (&column, &min) in columns.iter().zip(mins.iter()) {
+            for (&column, &best) in columns.iter().zip(bests.iter()) {
                 if let Some(bench) = crate_.benches.get(column) {
                     match bench {
                         Bench::Nanos(values) => {
-                            format_values(values, &mut data, Nanos)?;
-                            format_values(values, &mut comparison, |x| Percent(min, x))?;
+                            format_values(values, &mut data, |x, _| Nanos(x))?;
+                            format_values(values, &mut comparison, |x, stats| {
+                                Relative(Percent(best.value, x), best.within_noise(x, stats))
+                            })?;
                         }
                         Bench::Bytes(values) => {
-                            format_values(values, &mut data, Bytes)?;
-                            format_values(values, &mut comparison, |x| Percent(min, x as f64))?;
+                            format_values(values, &mut data, |x, _| Bytes(x))?;
+                            format_values(values, &mut comparison, |x, _| {
+                                Percent(best.value, x as f64)
+                            })?;
                         }
                     }
                 } else {
@@ -194,8 +272,8 @@ fn build_tables(
                     write!(&mut comparison, " {placeholder} |")?;
                 }
             }
-            write!(&mut data, "\n")?;
-            write!(&mut comparison, "\n")?;
+            writeln!(&mut data)?;
+            writeln!(&mut comparison)?;
         }
     }
 
@@ -206,12 +284,89 @@ fn build_tables(
     })
 }
 
-fn format(
-    results: &Results,
+fn evolution_header(check: &str) -> String {
+    check
+        .split('/')
+        .skip(1)
+        .map(|part| capitalize(&part.replace("_to_", "_→_")))
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+fn interop_header(check: &str) -> String {
+    format!("`{}`", check.trim_start_matches("interop/"))
+}
+
+fn determinism_header(check: &str) -> String {
+    capitalize(check.trim_start_matches("determinism/"))
+}
+
+fn alignment_header(check: &str) -> String {
+    capitalize(check.trim_start_matches("alignment/"))
+}
+
+fn check_outcome(outcome: &str) -> &str {
+    match outcome {
+        "identical" => "≡",
+        "ok" | "yes" => "✓",
+        "no" => "✗",
+        "corrupt" => "**corrupt**",
+        outcome => outcome,
+    }
+}
+
+/// Builds a table of check outcomes, leaving out columns that no feature reported. Returns an
+/// empty string if none of the columns were reported.
+fn build_check_table(
+    features: &Features,
+    dataset: &Dataset,
     config: &Config,
-    template: &str,
-    date: &str,
-) -> Result<String, fmt::Error> {
+    columns: &[&str],
+    header: impl Fn(&str) -> String,
+    placeholder: &str,
+) -> Result<String, Error> {
+    let columns = columns
+        .iter()
+        .cloned()
+        .filter(|&col| {
+            dataset
+                .features
+                .values()
+                .any(|feature| feature.checks.contains_key(col))
+        })
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return Ok(String::new());
+    }
+
+    let mut table = "| Crate |".to_string();
+    for column in columns.iter() {
+        write!(&mut table, " {} |", header(column))?;
+    }
+    write!(&mut table, "\n|---|")?;
+    for _ in columns.iter() {
+        write!(&mut table, ":-:|")?;
+    }
+    writeln!(&mut table)?;
+
+    for (feature, crate_) in dataset.grouped_features(config) {
+        if columns.iter().any(|&c| crate_.checks.contains_key(c)) {
+            write_crate_row(&mut table, feature, features, "")?;
+            for &column in columns.iter() {
+                if let Some(outcome) = crate_.checks.get(column) {
+                    write!(&mut table, " {} |", check_outcome(outcome))?;
+                } else {
+                    write!(&mut table, " {placeholder} |")?;
+                }
+            }
+            writeln!(&mut table)?;
+        }
+    }
+
+    Ok(table)
+}
+
+fn format(results: &Results, config: &Config, template: &str, date: &str) -> Result<String, Error> {
     const ALL_SER_DE_COLS: &[&str] = &[
         "serialize",
         "deserialize",
@@ -219,9 +374,63 @@ fn format(
         "size",
         "zlib",
         "zstd",
+        "zlib_time",
         "zstd_time",
+        "zlib_decompress_time",
+        "zstd_decompress_time",
+    ];
+    const ZCD_COLS: &[&str] = &[
+        "access",
+        "read",
+        "update",
+        "random_access",
+        "mmap_access",
+        "mmap_read",
+    ];
+    const STREAM_COLS: &[&str] = &[
+        "size",
+        "stream_size",
+        "stream_overhead",
+        "serialize",
+        "stream_serialize",
+        "deserialize",
+        "stream_deserialize",
+    ];
+    const BUILDER_COLS: &[&str] = &["update_builder"];
+    const IO_COLS: &[&str] = &["serialize_writer", "deserialize_reader"];
+    const LOOPBACK_COLS: &[&str] = &["loopback"];
+    const DICTIONARY_COLS: &[&str] = &[
+        "message_size",
+        "message_zstd",
+        "message_zstd_dict",
+        "message_zstd_dict_time",
+        "message_zstd_dict_decompress_time",
     ];
-    const ZCD_COLS: &[&str] = &["access", "read", "update"];
+    const EVOLUTION_COLS: &[&str] = &[
+        "evolution/add_field/old_to_new",
+        "evolution/add_field/new_to_old",
+        "evolution/remove_field/old_to_new",
+        "evolution/remove_field/new_to_old",
+        "evolution/reorder_fields/old_to_new",
+        "evolution/reorder_fields/new_to_old",
+        "evolution/add_variant/old_to_new",
+        "evolution/add_variant/new_to_old",
+    ];
+    const DETERMINISM_COLS: &[&str] = &[
+        "determinism/deterministic",
+        "determinism/canonical_mode",
+        "determinism/repeat",
+        "determinism/map_order",
+        "determinism/negative_zero",
+    ];
+
+    const ALIGNMENT_COLS: &[&str] = &[
+        "alignment/offset_1",
+        "alignment/offset_2",
+        "alignment/offset_4",
+        "alignment/offset_8",
+    ];
+    const REALIGN_COLS: &[&str] = &["realign"];
 
     let mut runtime_info = format!(
         "\
@@ -246,20 +455,99 @@ fn format(
         )?;
     }
 
+    let encodings = config
+        .common_encodings
+        .values()
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>();
+
     let mut tables = String::new();
 
     for (dataset_name, dataset) in results.datasets.iter() {
-        let mut ser_de_cols = Cow::from(ALL_SER_DE_COLS);
-        // Only keep the "borrow" column if the suite has a borrowable test data type
-        if !config
+        let suite = config
             .suites
             .get(dataset_name)
-            .map_or(false, |suite| suite.borrowable)
-        {
+            .ok_or_else(|| Error::MissingSuite {
+                dataset: dataset_name.clone(),
+            })?;
+        let mut ser_de_cols = Cow::from(ALL_SER_DE_COLS);
+        // Only keep the "borrow" column if the suite has a borrowable test data type
+        if !suite.borrowable {
             ser_de_cols.to_mut().retain(|&col| col != "borrow");
         }
-        let serde_tables = build_tables(&results.features, dataset, &config, &ser_de_cols, "†")?;
-        let zcd_tables = build_tables(&results.features, dataset, &config, ZCD_COLS, "‡")?;
+        let serde_tables =
+            build_tables(&results.features, dataset, config, &ser_de_cols, None, "†")?;
+        let zcd_tables = build_tables(&results.features, dataset, config, ZCD_COLS, None, "‡")?;
+        let builder_tables =
+            build_tables(&results.features, dataset, config, BUILDER_COLS, None, "")?;
+        // Monolithic numbers sit next to the stream ones, but only for libraries with a stream
+        let stream_tables = build_tables(
+            &results.features,
+            dataset,
+            config,
+            STREAM_COLS,
+            Some("stream_size"),
+            "",
+        )?;
+        let io_tables = build_tables(&results.features, dataset, config, IO_COLS, None, "")?;
+        let loopback_tables =
+            build_tables(&results.features, dataset, config, LOOPBACK_COLS, None, "")?;
+        let dictionary_tables = build_tables(
+            &results.features,
+            dataset,
+            config,
+            DICTIONARY_COLS,
+            None,
+            "",
+        )?;
+        let alignment_table = build_check_table(
+            &results.features,
+            dataset,
+            config,
+            ALIGNMENT_COLS,
+            alignment_header,
+            "§",
+        )?;
+        let realign_tables =
+            build_tables(&results.features, dataset, config, REALIGN_COLS, None, "")?;
+        let evolution_table = build_check_table(
+            &results.features,
+            dataset,
+            config,
+            EVOLUTION_COLS,
+            evolution_header,
+            "§",
+        )?;
+        let determinism_table = build_check_table(
+            &results.features,
+            dataset,
+            config,
+            DETERMINISM_COLS,
+            determinism_header,
+            "§",
+        )?;
+
+        let mut interop_tables = String::new();
+        for encoding in encodings.iter() {
+            let columns = dataset
+                .grouped_features(config)
+                .into_keys()
+                .filter(|feature| feature.common_encoding == Some(*encoding))
+                .map(|feature| format!("interop/{}", feature.name))
+                .collect::<Vec<_>>();
+            let columns = columns.iter().map(String::as_str).collect::<Vec<_>>();
+            let table = build_check_table(
+                &results.features,
+                dataset,
+                config,
+                &columns,
+                interop_header,
+                "§",
+            )?;
+            if !table.is_empty() {
+                write!(&mut interop_tables, "#### `{encoding}`\n\n{table}\n")?;
+            }
+        }
 
         write!(
             &mut tables,
@@ -293,11 +581,7 @@ fn format(
             {zcd_header}\n\
             {zcd_comparison}\n\
             ",
-            dataset_description = config
-                .suites
-                .get(dataset_name)
-                .map(|suite| suite.description.as_str())
-                .unwrap_or("Missing dataset description"),
+            dataset_description = suite.description,
             ser_de_header = serde_tables.header,
             ser_de_data = serde_tables.data,
             ser_de_comparison = serde_tables.comparison,
@@ -305,6 +589,158 @@ fn format(
             zcd_data = zcd_tables.data,
             zcd_comparison = zcd_tables.comparison,
         )?;
+
+        if !builder_tables.data.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Builder updates\n\
+                \n\
+                Messages updated through a builder that owns them, for libraries that can't update \
+                a serialized buffer in place. Lower is better.\n\
+                \n\
+                {builder_header}\n\
+                {builder_data}\n\
+                ",
+                builder_header = builder_tables.header,
+                builder_data = builder_tables.data,
+            )?;
+        }
+
+        if !stream_tables.data.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Streams\n\
+                \n\
+                Messages written to and read from a stream of framed records, next to the whole \
+                dataset as one value. Lower is better.\n\
+                \n\
+                {stream_header}\n\
+                {stream_data}\n\
+                ",
+                stream_header = stream_tables.header,
+                stream_data = stream_tables.data,
+            )?;
+        }
+
+        if !io_tables.data.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### IO\n\
+                \n\
+                Serialized to a `Write` and deserialized from a `Read` through a file, directly and \
+                through `BufWriter` and `BufReader` (*buffered*). Lower is better.\n\
+                \n\
+                {io_header}\n\
+                {io_data}\n\
+                ",
+                io_header = io_tables.header,
+                io_data = io_tables.data,
+            )?;
+        }
+
+        if !loopback_tables.data.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Loopback\n\
+                \n\
+                Time per message sent through an async length-delimited codec. Lower is better.\n\
+                \n\
+                {loopback_header}\n\
+                {loopback_data}\n\
+                ",
+                loopback_header = loopback_tables.header,
+                loopback_data = loopback_tables.data,
+            )?;
+        }
+
+        if !dictionary_tables.data.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Dictionary compression\n\
+                \n\
+                Messages compressed one at a time with zstd, with and without a dictionary trained \
+                on other messages. Lower is better.\n\
+                \n\
+                {dictionary_header}\n\
+                {dictionary_data}\n\
+                ",
+                dictionary_header = dictionary_tables.header,
+                dictionary_data = dictionary_tables.data,
+            )?;
+        }
+
+        if !alignment_table.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Alignment\n\
+                \n\
+                Whether zero-copy buffers can be accessed at offsets past an aligned address, or \
+                have to be copied into an aligned buffer first.\n\
+                \n\
+                {alignment_table}\n\
+                ",
+            )?;
+        }
+
+        if !realign_tables.data.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                Time to copy a misaligned buffer into an aligned one. Lower is better.\n\
+                \n\
+                {realign_header}\n\
+                {realign_data}\n\
+                ",
+                realign_header = realign_tables.header,
+                realign_data = realign_tables.data,
+            )?;
+        }
+
+        if !evolution_table.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Schema evolution\n\
+                \n\
+                Whether values survive being written with one version of a type and read with the \
+                other.\n\
+                \n\
+                {evolution_table}\n\
+                ",
+            )?;
+        }
+
+        if !interop_tables.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Interoperability\n\
+                \n\
+                Rows write the dataset and columns read it back.\n\
+                \n\
+                {interop_tables}\
+                ",
+            )?;
+        }
+
+        if !determinism_table.is_empty() {
+            write!(
+                &mut tables,
+                "\
+                ### Determinism\n\
+                \n\
+                Whether equal values always encode to the same bytes.\n\
+                \n\
+                {determinism_table}\n\
+                ",
+            )?;
+        }
     }
 
     let mut links = String::new();
@@ -314,10 +750,10 @@ fn format(
         .flat_map(|dataset| dataset.features.keys())
         .collect::<BTreeSet<_>>();
     for &feature in features.iter() {
-        write!(
+        writeln!(
             &mut links,
-            "[{feature}]: {}\n",
-            results.features.get(feature).unwrap().crates_io_url(),
+            "[{feature}]: {}",
+            find_crate(&results.features, feature)?.crates_io_url(),
         )?;
     }
 
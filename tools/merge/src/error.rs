@@ -0,0 +1,21 @@
+use std::{io, path::PathBuf};
+
+use schema::error::ReadError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Read(#[from] ReadError),
+    #[error("refusing to merge runs that differ, pass --force to merge them anyway")]
+    Conflicts,
+    #[error("{id} is a {kind} in {} but a {other_kind} in {}", path.display(), other_path.display())]
+    BenchKind {
+        id: String,
+        path: PathBuf,
+        kind: &'static str,
+        other_path: PathBuf,
+        other_kind: &'static str,
+    },
+    #[error("failed to write {}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
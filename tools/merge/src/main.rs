@@ -0,0 +1,487 @@
+mod error;
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser;
+
+use error::Error;
+use schema::{error::report, Bench, PackageId, Results, Stats, Values, SCHEMA_VERSION};
+
+#[derive(Parser, Debug)]
+#[command(name = "merge")]
+#[command(about = "Merges the results of several benchmarking runs into one")]
+struct Args {
+    /// The results to merge. Runs may cover different benchmarks, and measurements repeated by
+    /// several runs are merged into their median.
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+    /// Merge runs even if they were made with different compilers, on different CPUs, in different
+    /// environments or with different versions of a library. The first run's are kept.
+    #[arg(short, long)]
+    force: bool,
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let runs = args
+        .inputs
+        .iter()
+        .map(|path| Ok((path.as_path(), Results::read(path)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let conflicts = find_conflicts(&runs);
+    if !conflicts.is_empty() {
+        let level = if args.force { "warning" } else { "error" };
+        for conflict in conflicts.iter() {
+            eprintln!("{level}: {conflict}");
+        }
+        if !args.force {
+            return Err(Error::Conflicts);
+        }
+    }
+
+    let merged = merge(runs)?;
+    fs::write(&args.output, serde_json::to_string(&merged).unwrap()).map_err(|source| {
+        Error::Write {
+            path: args.output.clone(),
+            source,
+        }
+    })
+}
+
+fn first_line(info: &str) -> &str {
+    info.lines().next().unwrap_or_default()
+}
+
+/// Describes every way that the runs differ other than in what they measured.
+fn find_conflicts(runs: &[(&Path, Results)]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    let Some((first_path, first)) = runs.first() else {
+        return conflicts;
+    };
+
+    let mut package_ids = BTreeMap::<&str, (&Path, &PackageId)>::new();
+    let mut checks = BTreeMap::<String, (&Path, &str)>::new();
+    for (path, results) in runs.iter() {
+        if results.rustc_info != first.rustc_info {
+            conflicts.push(format!(
+                "{} was compiled with `{}` but {} was compiled with `{}`",
+                path.display(),
+                first_line(&results.rustc_info),
+                first_path.display(),
+                first_line(&first.rustc_info),
+            ));
+        }
+        if results.cpu_model() != first.cpu_model() {
+            conflicts.push(format!(
+                "{} was run on {} but {} was run on {}",
+                path.display(),
+                results.cpu_model().unwrap_or("an unknown CPU"),
+                first_path.display(),
+                first.cpu_model().unwrap_or("an unknown CPU"),
+            ));
+        }
+        if let (Some(environment), Some(first_environment)) =
+            (&results.environment, &first.environment)
+        {
+            for (setting, value, first_value) in environment.conflicts(first_environment) {
+                conflicts.push(format!(
+                    "{} was run with {setting} {value} but {} was run with {setting} {first_value}",
+                    path.display(),
+                    first_path.display(),
+                ));
+            }
+        }
+
+        for (feature, id) in results.features.iter() {
+            let (other_path, other) = *package_ids.entry(feature).or_insert((path, id));
+            if id.crate_name != other.crate_name || id.version != other.version {
+                conflicts.push(format!(
+                    "{} benchmarked {} {} as {feature} but {} benchmarked {} {}",
+                    path.display(),
+                    id.crate_name,
+                    id.version,
+                    other_path.display(),
+                    other.crate_name,
+                    other.version,
+                ));
+            }
+        }
+
+        for (dataset_name, dataset) in results.datasets.iter() {
+            for (feature_name, feature) in dataset.features.iter() {
+                for (check, outcome) in feature.checks.iter() {
+                    let id = format!("{dataset_name}/{feature_name}/check/{check}");
+                    let (other_path, other) = *checks.entry(id.clone()).or_insert((path, outcome));
+                    if outcome != other {
+                        conflicts.push(format!(
+                            "{} found {id} to be {outcome} but {} found it to be {other}",
+                            path.display(),
+                            other_path.display(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    conflicts
+}
+
+/// Every measurement of a value, keyed by its variant name or `None` for the primary value.
+type Samples<T> = BTreeMap<Option<String>, Vec<(T, Option<Stats>)>>;
+
+enum BenchSamples {
+    Nanos(Samples<f64>),
+    Bytes(Samples<u64>),
+}
+
+fn collect<T: Copy>(samples: &mut Samples<T>, values: &Values<T>) {
+    for (variant, value, stats) in values.iter_named() {
+        samples
+            .entry(variant.map(str::to_string))
+            .or_default()
+            .push((*value, stats.cloned()));
+    }
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Merges repeated measurements of each value into their median. The bounds of the merged
+/// confidence interval are the medians of the measurements' bounds, and criterion's estimates are
+/// only kept for values that were measured once since they describe a single set of samples.
+fn merge_values<T: Copy + Default>(
+    samples: Samples<T>,
+    to_f64: fn(T) -> f64,
+    from_f64: fn(f64) -> T,
+) -> Values<T> {
+    let mut values = Values::default();
+    for (variant, mut samples) in samples {
+        let (value, stats) = if samples.len() == 1 {
+            samples.pop().unwrap()
+        } else {
+            let value = from_f64(median(
+                samples.iter().map(|(value, _)| to_f64(*value)).collect(),
+            ));
+            let stats = samples
+                .iter()
+                .map(|(_, stats)| stats.as_ref())
+                .collect::<Option<Vec<_>>>()
+                .map(|stats| Stats {
+                    lower: median(stats.iter().map(|stats| stats.lower).collect()),
+                    upper: median(stats.iter().map(|stats| stats.upper).collect()),
+                    estimates: None,
+                });
+            (value, stats)
+        };
+
+        match variant {
+            Some(variant) => {
+                values.variants.insert(variant.clone(), value);
+                if let Some(stats) = stats {
+                    values.variant_stats.insert(variant, stats);
+                }
+            }
+            None => {
+                values.primary = Some(value);
+                values.primary_stats = stats;
+            }
+        }
+    }
+    values
+}
+
+fn merge(runs: Vec<(&Path, Results)>) -> Result<Results, Error> {
+    let mut merged = Results {
+        schema_version: SCHEMA_VERSION,
+        ..Default::default()
+    };
+    let mut benches = BTreeMap::<(String, String, String), (&Path, BenchSamples)>::new();
+
+    for (i, (path, results)) in runs.into_iter().enumerate() {
+        if i == 0 {
+            merged.rustc_info = results.rustc_info;
+            merged.cpu_info = results.cpu_info;
+            merged.environment = results.environment;
+        }
+        for (feature, id) in results.features {
+            merged.features.entry(feature).or_insert(id);
+        }
+
+        for (dataset_name, dataset) in results.datasets {
+            let merged_dataset = merged.datasets.entry(dataset_name.clone()).or_default();
+            for (feature_name, feature) in dataset.features {
+                let merged_feature = merged_dataset
+                    .features
+                    .entry(feature_name.clone())
+                    .or_default();
+                for (check, outcome) in feature.checks {
+                    merged_feature.checks.entry(check).or_insert(outcome);
+                }
+
+                for (bench_name, bench) in feature.benches {
+                    let id = format!("{dataset_name}/{feature_name}/{bench_name}");
+                    let (first_path, samples) = benches
+                        .entry((dataset_name.clone(), feature_name.clone(), bench_name))
+                        .or_insert_with(|| (path, BenchSamples::new(&bench)));
+                    match (samples, &bench) {
+                        (BenchSamples::Nanos(samples), Bench::Nanos(values)) => {
+                            collect(samples, values)
+                        }
+                        (BenchSamples::Bytes(samples), Bench::Bytes(values)) => {
+                            collect(samples, values)
+                        }
+                        (samples, bench) => {
+                            return Err(Error::BenchKind {
+                                id,
+                                path: path.to_path_buf(),
+                                kind: BenchSamples::new(bench).kind(),
+                                other_path: first_path.to_path_buf(),
+                                other_kind: samples.kind(),
+                            })
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for ((dataset, feature, bench), (_, samples)) in benches {
+        let bench_values = match samples {
+            BenchSamples::Nanos(samples) => {
+                Bench::Nanos(merge_values(samples, |value| value, |value| value))
+            }
+            BenchSamples::Bytes(samples) => Bench::Bytes(merge_values(
+                samples,
+                |value| value as f64,
+                |value| value.round() as u64,
+            )),
+        };
+        merged
+            .datasets
+            .get_mut(&dataset)
+            .unwrap()
+            .features
+            .get_mut(&feature)
+            .unwrap()
+            .benches
+            .insert(bench, bench_values);
+    }
+
+    Ok(merged)
+}
+
+impl BenchSamples {
+    fn new(bench: &Bench) -> Self {
+        match bench {
+            Bench::Nanos(_) => BenchSamples::Nanos(Samples::new()),
+            Bench::Bytes(_) => BenchSamples::Bytes(Samples::new()),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            BenchSamples::Nanos(_) => "time",
+            BenchSamples::Bytes(_) => "size",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /// A run that measured one time, with the given confidence interval, and one size.
+    fn run(time: f64, lower: f64, upper: f64, size: u64) -> Value {
+        json!({
+            "schema_version": SCHEMA_VERSION,
+            "cpu_info": "Model name: Example CPU\nCPU MHz: 3000.000",
+            "rustc_info": "rustc 1.80.0\nhost: x86_64-unknown-linux-gnu",
+            "datasets": { "log": { "features": { "bincode": {
+                "benches": {
+                    "serialize": { "Nanos": {
+                        "primary": time,
+                        "variants": {},
+                        "primary_stats": { "lower": lower, "upper": upper },
+                    } },
+                    "size": { "Bytes": { "primary": size, "variants": {} } },
+                },
+                "checks": { "io": "slice-only" },
+            } } } },
+            "features": { "bincode": { "crate_name": "bincode", "version": "2.0.1" } },
+            "environment": { "rustflags": [], "features": ["bincode"] },
+        })
+    }
+
+    /// Changes one thing about a run.
+    type Change = fn(&mut Value);
+
+    fn results(json: Value) -> Results {
+        Results::from_json(&json.to_string()).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    fn conflicts(runs: Vec<Value>) -> Vec<String> {
+        let runs = runs
+            .into_iter()
+            .map(|run| (Path::new("run.json"), results(run)))
+            .collect::<Vec<_>>();
+        find_conflicts(&runs)
+    }
+
+    fn nanos<'a>(results: &'a mut Results, bench: &str) -> &'a mut Values<f64> {
+        results
+            .datasets
+            .get_mut("log")
+            .unwrap()
+            .features
+            .get_mut("bincode")
+            .unwrap()
+            .benches
+            .get_mut(bench)
+            .unwrap()
+            .unwrap_nanos()
+    }
+
+    #[test]
+    fn median_of_odd_and_even_counts() {
+        assert_eq!(median(vec![3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(vec![4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert_eq!(median(vec![5.0]), 5.0);
+    }
+
+    #[test]
+    fn merges_repeated_measurements_into_their_median() {
+        let runs = [
+            run(10.0, 9.0, 11.0, 100),
+            run(30.0, 28.0, 33.0, 100),
+            run(20.0, 19.0, 22.0, 101),
+        ];
+        let runs = runs
+            .into_iter()
+            .map(|run| (Path::new("run.json"), results(run)))
+            .collect();
+        let mut merged = merge(runs).unwrap_or_else(|e| panic!("{e}"));
+
+        let serialize = nanos(&mut merged, "serialize");
+        assert_eq!(serialize.primary, Some(20.0));
+        let stats = serialize.primary_stats.as_ref().unwrap();
+        assert_eq!((stats.lower, stats.upper), (19.0, 22.0));
+
+        let feature = &mut merged
+            .datasets
+            .get_mut("log")
+            .unwrap()
+            .features
+            .get_mut("bincode")
+            .unwrap();
+        assert_eq!(
+            feature
+                .benches
+                .get_mut("size")
+                .unwrap()
+                .unwrap_bytes()
+                .primary,
+            Some(100)
+        );
+        assert_eq!(feature.checks["io"], "slice-only");
+    }
+
+    #[test]
+    fn keeps_a_single_measurement_as_is() {
+        let mut merged = merge(vec![(
+            Path::new("run.json"),
+            results(run(10.0, 9.0, 11.0, 100)),
+        )])
+        .unwrap_or_else(|e| panic!("{e}"));
+        let serialize = nanos(&mut merged, "serialize");
+        assert_eq!(serialize.primary, Some(10.0));
+        let stats = serialize.primary_stats.as_ref().unwrap();
+        assert_eq!((stats.lower, stats.upper), (9.0, 11.0));
+    }
+
+    #[test]
+    fn rejects_benches_of_different_kinds() {
+        let mut other = run(10.0, 9.0, 11.0, 100);
+        other["datasets"]["log"]["features"]["bincode"]["benches"]["serialize"] =
+            json!({ "Bytes": { "primary": 100, "variants": {} } });
+        let runs = vec![
+            (Path::new("first.json"), results(run(10.0, 9.0, 11.0, 100))),
+            (Path::new("second.json"), results(other)),
+        ];
+        assert!(matches!(merge(runs), Err(Error::BenchKind { .. })));
+    }
+
+    #[test]
+    fn identical_runs_do_not_conflict() {
+        assert!(conflicts(vec![run(10.0, 9.0, 11.0, 100), run(20.0, 19.0, 22.0, 100)]).is_empty());
+    }
+
+    #[test]
+    fn ignores_clock_speed_in_cpu_info() {
+        let mut other = run(10.0, 9.0, 11.0, 100);
+        other["cpu_info"] = "Model name: Example CPU\nCPU MHz: 2400.000".into();
+        assert!(conflicts(vec![run(10.0, 9.0, 11.0, 100), other]).is_empty());
+    }
+
+    #[test]
+    fn detects_each_kind_of_conflict() {
+        let changes: [(&str, Change); 5] = [
+            ("compiler", |run| run["rustc_info"] = "rustc 1.81.0".into()),
+            ("cpu", |run| {
+                run["cpu_info"] = "Model name: Other CPU".into()
+            }),
+            ("crate version", |run| {
+                run["features"]["bincode"]["version"] = "2.0.0".into()
+            }),
+            ("check outcome", |run| {
+                run["datasets"]["log"]["features"]["bincode"]["checks"]["io"] = "yes".into()
+            }),
+            ("timing setting", |run| {
+                run["environment"]["rustflags"] = json!(["-Ctarget-cpu=native"])
+            }),
+        ];
+        for (name, change) in changes {
+            let mut other = run(10.0, 9.0, 11.0, 100);
+            change(&mut other);
+            assert_eq!(
+                conflicts(vec![run(10.0, 9.0, 11.0, 100), other]).len(),
+                1,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn allows_settings_that_do_not_affect_timings() {
+        let mut other = run(10.0, 9.0, 11.0, 100);
+        other["environment"]["features"] = json!(["bincode", "serde_json"]);
+        other["environment"]["command_line"] = json!(["cargo", "bench"]);
+        other["environment"]["git"] = json!({ "commit": "abc123", "dirty": true });
+        assert!(conflicts(vec![run(10.0, 9.0, 11.0, 100), other]).is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+//! Reads the estimates that criterion saves for each benchmark under `target/criterion`.
+
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use schema::{Estimate, Estimates, Outliers, Stats};
+
+use crate::{read_json, Error};
+
+/// What the files read from criterion's output directory are called in errors
+const MEASUREMENT: &str = "criterion's measurement";
+
+/// A benchmark's latest measurement.
+pub struct Measurement {
+    /// The benchmark's full ID, e.g. `log/rkyv/read (unvalidated)`
+    pub id: String,
+    /// The time per iteration that criterion prints, in nanoseconds
+    pub value: f64,
+    pub stats: Stats,
+}
+
+#[derive(Deserialize)]
+struct Benchmark {
+    full_id: String,
+}
+
+#[derive(Deserialize)]
+struct CriterionEstimates {
+    mean: CriterionEstimate,
+    median: CriterionEstimate,
+    std_dev: CriterionEstimate,
+    /// Only estimated when iterations were sampled linearly
+    slope: Option<CriterionEstimate>,
+}
+
+#[derive(Deserialize)]
+struct CriterionEstimate {
+    confidence_interval: ConfidenceInterval,
+    point_estimate: f64,
+}
+
+#[derive(Deserialize)]
+struct ConfidenceInterval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+impl From<CriterionEstimate> for Estimate {
+    fn from(estimate: CriterionEstimate) -> Self {
+        Estimate {
+            point: estimate.point_estimate,
+            lower: estimate.confidence_interval.lower_bound,
+            upper: estimate.confidence_interval.upper_bound,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Sample {
+    iters: Vec<f64>,
+    times: Vec<f64>,
+}
+
+impl Sample {
+    /// Counts the samples outside the fences of criterion's Tukey test, like criterion does when it
+    /// reports outliers.
+    fn outliers(&self, [low_severe, low_mild, high_mild, high_severe]: [f64; 4]) -> Outliers {
+        let mut outliers = Outliers::default();
+        for (iters, time) in self.iters.iter().zip(self.times.iter()) {
+            let time = time / iters;
+            if time < low_severe {
+                outliers.low_severe += 1;
+            } else if time < low_mild {
+                outliers.low_mild += 1;
+            } else if time > high_severe {
+                outliers.high_severe += 1;
+            } else if time > high_mild {
+                outliers.high_mild += 1;
+            }
+        }
+        outliers
+    }
+}
+
+/// Reads the latest measurement of every benchmark saved in `dir`.
+pub fn read(dir: &Path) -> Result<Vec<Measurement>, Error> {
+    let mut measurements = Vec::new();
+    for path in find_measurements(dir)? {
+        let benchmark = read_json::<Benchmark>(&path.join("benchmark.json"), MEASUREMENT)?;
+        let estimates = read_json::<CriterionEstimates>(&path.join("estimates.json"), MEASUREMENT)?;
+        let sample = read_json::<Sample>(&path.join("sample.json"), MEASUREMENT)?;
+        let fences = read_json::<[f64; 4]>(&path.join("tukey.json"), MEASUREMENT)?;
+
+        // criterion reports the slope when it has one and the mean otherwise
+        let reported = estimates.slope.as_ref().unwrap_or(&estimates.mean);
+        measurements.push(Measurement {
+            id: benchmark.full_id,
+            value: reported.point_estimate,
+            stats: Stats {
+                lower: reported.confidence_interval.lower_bound,
+                upper: reported.confidence_interval.upper_bound,
+                estimates: Some(Estimates {
+                    mean: estimates.mean.into(),
+                    median: estimates.median.into(),
+                    std_dev: estimates.std_dev.into(),
+                    samples: sample.iters.len(),
+                    outliers: sample.outliers(fences),
+                }),
+            },
+        });
+    }
+    Ok(measurements)
+}
+
+/// Finds the `new` directories, which hold each benchmark's latest measurement, under `dir`.
+fn find_measurements(dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut found = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let read_error = |source| Error::Io {
+            path: dir.clone(),
+            source,
+        };
+        for entry in fs::read_dir(&dir).map_err(read_error)? {
+            let path = entry.map_err(read_error)?.path();
+            if !path.is_dir() {
+                continue;
+            }
+            if path.file_name().unwrap() == "new" && path.join("estimates.json").exists() {
+                found.push(path);
+            } else {
+                dirs.push(path);
+            }
+        }
+    }
+    Ok(found)
+}
@@ -0,0 +1,56 @@
+use std::{io, path::PathBuf};
+
+use schema::error::ReadError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Read(#[from] ReadError),
+    #[error("failed to read {}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("{} isn't valid UTF-8 or UTF-16", path.display())]
+    Encoding { path: PathBuf },
+    #[error("failed to parse {what} from {}", path.display())]
+    Json {
+        what: &'static str,
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    #[error("line {line}: unrecognized time `{time}`")]
+    Time { line: usize, time: String },
+    #[error("line {line}: size `{size}` is out of range")]
+    Size { line: usize, size: String },
+    #[error("line {line}: {id} was measured as both a time and a size")]
+    BenchKind { line: usize, id: String },
+    #[error(
+        "feature `{feature}`: invalid version requirement `{version}` for {crate_name} in the \
+        config's crate_matching"
+    )]
+    VersionReq {
+        feature: String,
+        crate_name: String,
+        version: String,
+        source: semver::Error,
+    },
+    #[error(
+        "feature `{feature}`: no {crate_name}{} in the cargo metadata, is it missing from the \
+        config's crate_matching?",
+        version.as_ref().map(|version| format!(" matching `{version}`")).unwrap_or_default()
+    )]
+    MissingPackage {
+        feature: String,
+        crate_name: String,
+        version: Option<String>,
+    },
+    #[error("{} benchmark lines couldn't be parsed:{}", lines.len(), list_lines(lines))]
+    Unparsed { lines: Vec<(usize, String)> },
+    #[error("failed to write {}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
+
+fn list_lines(lines: &[(usize, String)]) -> String {
+    lines
+        .iter()
+        .map(|(number, line)| format!("\n  line {number}: {line}"))
+        .collect()
+}
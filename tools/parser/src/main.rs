@@ -1,9 +1,22 @@
+mod criterion;
+mod error;
+
 use cargo_metadata::Metadata;
 use clap::Parser;
 use regex::Regex;
-use std::{fs, path::PathBuf};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
 
-use schema::{Bench, Config, Dataset, Feature, PackageId, Results};
+use error::Error;
+use schema::{
+    error::report, Bench, Config, Environment, PackageId, Results, Stats, SCHEMA_VERSION,
+    UNKNOWN_VERSION,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "parser")]
@@ -12,145 +25,391 @@ struct Args {
     log: PathBuf,
     #[arg(long)]
     config: PathBuf,
+    /// The output of `cargo metadata`. Without it, crate versions are recorded as unknown, as they
+    /// are for legacy logs that were saved without metadata.
     #[arg(long)]
-    meta: PathBuf,
+    meta: Option<PathBuf>,
+    /// The output of `rustc -vV`. Without it, rustc info is recorded as unknown.
     #[arg(long)]
-    rustc_info: PathBuf,
+    rustc_info: Option<PathBuf>,
     #[arg(long)]
     cpu_info: Option<PathBuf>,
+    /// The environment that the benchmarks were run in, as JSON written by the bencher
+    #[arg(long)]
+    environment: Option<PathBuf>,
+    /// criterion's output directory (usually `target/criterion`). Times and their statistics are
+    /// read from the estimates saved there for every benchmark in the log, and from the log for the
+    /// rest.
+    #[arg(long)]
+    criterion: Option<PathBuf>,
+    /// Fail if any line that looks like a benchmark result can't be parsed, instead of warning about it
+    #[arg(long)]
+    strict: bool,
     #[arg(short, long)]
     output: PathBuf,
 }
 
-fn parse_time(s: &str) -> f64 {
-    let (n, unit) = s.split_once(' ').unwrap();
+/// Benchmarks that every feature should have results for. Zero-copy libraries may be accessed
+/// instead of deserialized.
+const EXPECTED_BENCHES: &[&[&str]] = &[&["serialize"], &["deserialize", "access"], &["size"]];
+
+fn parse_time(s: &str, line: usize) -> Result<f64, Error> {
+    let error = || Error::Time {
+        line,
+        time: s.to_string(),
+    };
+    let (n, unit) = s.split_once(' ').ok_or_else(error)?;
     let factor = match unit {
+        "ps" => 0.001,
         "ns" => 1.0,
-        "µs" => 1_000.0,
+        // legacy logs spell microseconds without the micro sign
+        "µs" | "us" => 1_000.0,
         "ms" => 1_000_000.0,
-        _ => panic!("unrecognized unit of time"),
+        "s" => 1_000_000_000.0,
+        _ => return Err(error()),
+    };
+    Ok(n.parse::<f64>().map_err(|_| error())? * factor)
+}
+
+/// Reads a log, including the legacy logs that were redirected to a file by PowerShell and saved as
+/// UTF-16 with CRLF line endings.
+fn read_log(path: &Path) -> Result<String, Error> {
+    let bytes = fs::read(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let log = match bytes.as_slice() {
+        [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, u16::from_be_bytes),
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8(rest.to_vec()).ok(),
+        _ => String::from_utf8(bytes).ok(),
     };
-    n.parse::<f64>().unwrap() * factor
+    let log = log.ok_or_else(|| Error::Encoding {
+        path: path.to_path_buf(),
+    })?;
+    Ok(log.replace("\r\n", "\n"))
+}
+
+fn decode_utf16(bytes: &[u8], from_bytes: fn([u8; 2]) -> u16) -> Option<String> {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| from_bytes([unit[0], unit[1]]));
+    char::decode_utf16(units).collect::<Result<_, _>>().ok()
+}
+
+fn read_to_string(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })
 }
 
-fn main() {
-    let args = Args::parse();
+fn read_json<T: DeserializeOwned>(path: &Path, what: &'static str) -> Result<T, Error> {
+    serde_json::from_str(&read_to_string(path)?).map_err(|source| Error::Json {
+        what,
+        path: path.to_path_buf(),
+        source,
+    })
+}
 
-    let log = fs::read_to_string(&args.log).unwrap();
-    let config = Config::read(&args.config);
-    let metadata =
-        serde_json::from_str::<Metadata>(&fs::read_to_string(args.meta).unwrap()).unwrap();
-    let rustc_info = fs::read_to_string(&args.rustc_info).unwrap();
-    let cpu_info = args
-        .cpu_info
-        .as_ref()
-        .map(|path| fs::read_to_string(path).unwrap());
+/// Finds the line numbers of offsets into a log.
+struct LineIndex {
+    starts: Vec<usize>,
+}
 
+impl LineIndex {
+    fn new(log: &str) -> Self {
+        let starts = [0]
+            .into_iter()
+            .chain(log.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { starts }
+    }
+
+    /// Returns the number, counting from 1, of the line that `offset` is in.
+    fn line(&self, offset: usize) -> usize {
+        self.starts.partition_point(|&start| start <= offset)
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let log = read_log(&args.log)?;
+    let lines = LineIndex::new(&log);
+    let config = Config::read(&args.config)?;
+    let metadata = args
+        .meta
+        .as_deref()
+        .map(|path| read_json::<Metadata>(path, "cargo metadata"))
+        .transpose()?;
+    let metadata = metadata.as_ref();
+    let rustc_info = match args.rustc_info.as_deref() {
+        Some(path) => read_to_string(path)?,
+        None => "unknown".to_string(),
+    };
+    let cpu_info = args.cpu_info.as_deref().map(read_to_string).transpose()?;
+    let environment = args
+        .environment
+        .as_deref()
+        .map(|path| read_json::<Environment>(path, "the environment"))
+        .transpose()?;
+
+    let bench_id_re =
+        Regex::new(r"^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/([a-z0-9_\-]+)(?: \(([a-z0-9_\-+ ]*)\))?$")
+            .unwrap();
     let time_benches_re = Regex::new(
-        r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/([a-z0-9_\-]+)(?: \(([a-z0-9_\-+ ]*)\))?\s+time:   \[\d+\.\d+ [µnm]s (\d+\.\d+ [µnm]s)"
+        r"(?m)^(([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/([a-z0-9_\-]+)(?: \(([a-z0-9_\-+ ]*)\))?)\s+time:   \[(\d+\.\d+ [µunmp]?s) (\d+\.\d+ [µunmp]?s) (\d+\.\d+ [µunmp]?s)\]"
     ).unwrap();
     let size_benches_re =
-        Regex::new(r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/(size|zlib|zstd) (\d+)").unwrap();
+        Regex::new(r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/([a-z0-9_\-]+) (\d+)$").unwrap();
+    let checks_re =
+        Regex::new(r"(?m)^([a-z0-9_\-]+)\/([a-z0-9_\-]+)\/check\/([a-z0-9_\-\/]+) ([a-z0-9_\-]+)$")
+            .unwrap();
+    // Lines that start with a benchmark ID, or hold the time of the ID on the line before
+    let result_line_re = Regex::new(r"^[^\s/]+/[^\s/]+/\S|time:").unwrap();
 
     let mut results = Results {
+        schema_version: SCHEMA_VERSION,
         cpu_info,
         rustc_info,
+        environment,
         ..Default::default()
     };
 
+    // criterion keeps the estimates of every benchmark it has ever run, so only the ones that were
+    // run for this log are used. Result lines start with the benchmark ID, followed by its time on
+    // the same line or the next.
+    let mut logged = HashMap::new();
+    for (i, line) in log.lines().enumerate() {
+        let id = line.split("time:").next().unwrap().trim_end();
+        logged.entry(id).or_insert(i + 1);
+    }
+    let measurements = match args.criterion.as_deref() {
+        Some(dir) => criterion::read(dir)?,
+        None => Vec::new(),
+    };
+    let mut measured = HashSet::new();
+    // The lines of the log that were parsed, to find the ones that look like results but weren't
+    let mut parsed = HashSet::new();
+
+    for measurement in measurements.iter() {
+        let Some(&line) = logged.get(measurement.id.as_str()) else {
+            continue;
+        };
+        if let Some(capture) = bench_id_re.captures(&measurement.id) {
+            insert_time(
+                &mut results,
+                &config,
+                metadata,
+                line,
+                [&capture[1], &capture[2], &capture[3]],
+                capture.get(4).map(|variant| variant.as_str()),
+                measurement.value,
+                Some(measurement.stats.clone()),
+            )?;
+            measured.insert(measurement.id.as_str());
+            parsed.insert(line);
+        }
+    }
+
     for capture in time_benches_re.captures_iter(&log) {
-        let feature = &capture[2];
-        results
-            .features
-            .entry(feature.to_string())
-            .or_insert_with(|| find_package_id(feature, &config, &metadata));
-
-        let dataset = results
-            .datasets
-            .entry(capture[1].to_string())
-            .or_insert(Dataset::default());
-        let package = dataset
-            .features
-            .entry(feature.to_string())
-            .or_insert(Feature::default());
-        let bench = package
-            .benches
-            .entry(capture[3].to_string())
-            .or_insert(Bench::nanos());
-        let values = bench.unwrap_nanos();
-
-        let value = parse_time(&capture[5]);
-        if let Some(variant) = capture.get(4) {
-            values.variants.insert(variant.as_str().to_string(), value);
-        } else {
-            values.primary = Some(value);
+        let whole = capture.get(0).unwrap();
+        let line = lines.line(whole.start());
+        parsed.extend(line..=lines.line(whole.end() - 1));
+        if measured.contains(&capture[1]) {
+            continue;
         }
+        insert_time(
+            &mut results,
+            &config,
+            metadata,
+            line,
+            [&capture[2], &capture[3], &capture[4]],
+            capture.get(5).map(|variant| variant.as_str()),
+            parse_time(&capture[7], line)?,
+            Some(Stats {
+                lower: parse_time(&capture[6], line)?,
+                upper: parse_time(&capture[8], line)?,
+                estimates: None,
+            }),
+        )?;
     }
 
     for capture in size_benches_re.captures_iter(&log) {
+        let line = lines.line(capture.get(0).unwrap().start());
+        parsed.insert(line);
         let feature = &capture[2];
-        results
-            .features
-            .entry(feature.to_string())
-            .or_insert_with(|| find_package_id(feature, &config, &metadata));
-
-        let dataset = results
-            .datasets
-            .entry(capture[1].to_string())
-            .or_insert(Dataset::default());
-        let package = dataset
-            .features
-            .entry(feature.to_string())
-            .or_insert(Feature::default());
+        if !results.features.contains_key(feature) {
+            let package_id = find_package_id(feature, &config, metadata)?;
+            results.features.insert(feature.to_string(), package_id);
+        }
+
+        let dataset = results.datasets.entry(capture[1].to_string()).or_default();
+        let package = dataset.features.entry(feature.to_string()).or_default();
         let bench = package
             .benches
             .entry(capture[3].to_string())
             .or_insert(Bench::bytes());
-        let values = bench.unwrap_bytes();
-        values.primary = Some(capture[4].parse().unwrap());
+        let Bench::Bytes(values) = bench else {
+            return Err(Error::BenchKind {
+                line,
+                id: format!("{}/{}/{}", &capture[1], &capture[2], &capture[3]),
+            });
+        };
+        values.primary = Some(capture[4].parse().map_err(|_| Error::Size {
+            line,
+            size: capture[4].to_string(),
+        })?);
     }
 
-    fs::write(args.output, serde_json::to_string(&results).unwrap()).unwrap();
+    for capture in checks_re.captures_iter(&log) {
+        parsed.insert(lines.line(capture.get(0).unwrap().start()));
+        let feature = &capture[2];
+        if !results.features.contains_key(feature) {
+            let package_id = find_package_id(feature, &config, metadata)?;
+            results.features.insert(feature.to_string(), package_id);
+        }
+
+        let dataset = results.datasets.entry(capture[1].to_string()).or_default();
+        let package = dataset.features.entry(feature.to_string()).or_default();
+        package
+            .checks
+            .insert(capture[3].to_string(), capture[4].to_string());
+    }
+
+    let unparsed = log
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(number, line)| !parsed.contains(number) && result_line_re.is_match(line))
+        .map(|(number, line)| (number, line.trim().to_string()))
+        .collect::<Vec<_>>();
+    if args.strict && !unparsed.is_empty() {
+        return Err(Error::Unparsed { lines: unparsed });
+    }
+    for (number, line) in unparsed.iter() {
+        eprintln!("warning: line {number}: couldn't parse `{line}`");
+    }
+    for (dataset_name, dataset) in results.datasets.iter() {
+        for (feature_name, feature) in dataset.features.iter() {
+            for names in EXPECTED_BENCHES {
+                if !names.iter().any(|name| feature.benches.contains_key(*name)) {
+                    eprintln!(
+                        "warning: {dataset_name}/{feature_name} has no {} benchmark",
+                        names.join(" or ")
+                    );
+                }
+            }
+        }
+    }
+
+    fs::write(&args.output, serde_json::to_string(&results).unwrap()).map_err(|source| {
+        Error::Write {
+            path: args.output.clone(),
+            source,
+        }
+    })
 }
 
-fn find_package_id(feature: &str, config: &Config, metadata: &Metadata) -> PackageId {
-    if let Some(package_id) = config.crate_matching.get(feature) {
-        PackageId {
-            crate_name: package_id.crate_name.clone(),
-            version: find_package_version(
-                &package_id.crate_name,
-                Some(
-                    package_id
-                        .version
-                        .parse()
-                        .expect("invalid version spec in config"),
-                ),
-                metadata,
-            ),
+/// Inserts the time of the benchmark `[dataset, feature, bench]` logged on `line`, as its primary
+/// value or as one of its variants.
+#[allow(clippy::too_many_arguments)]
+fn insert_time(
+    results: &mut Results,
+    config: &Config,
+    metadata: Option<&Metadata>,
+    line: usize,
+    [dataset_name, feature, bench_name]: [&str; 3],
+    variant: Option<&str>,
+    value: f64,
+    stats: Option<Stats>,
+) -> Result<(), Error> {
+    if !results.features.contains_key(feature) {
+        let package_id = find_package_id(feature, config, metadata)?;
+        results.features.insert(feature.to_string(), package_id);
+    }
+
+    let dataset = results
+        .datasets
+        .entry(dataset_name.to_string())
+        .or_default();
+    let package = dataset.features.entry(feature.to_string()).or_default();
+    let bench = package
+        .benches
+        .entry(bench_name.to_string())
+        .or_insert(Bench::nanos());
+    let Bench::Nanos(values) = bench else {
+        return Err(Error::BenchKind {
+            line,
+            id: format!("{dataset_name}/{feature}/{bench_name}"),
+        });
+    };
+
+    if let Some(variant) = variant {
+        values.variants.insert(variant.to_string(), value);
+        if let Some(stats) = stats {
+            values.variant_stats.insert(variant.to_string(), stats);
         }
     } else {
-        PackageId {
-            crate_name: feature.to_string(),
-            version: find_package_version(feature, None, metadata),
-        }
+        values.primary = Some(value);
+        values.primary_stats = stats;
     }
+    Ok(())
+}
+
+fn find_package_id(
+    feature: &str,
+    config: &Config,
+    metadata: Option<&Metadata>,
+) -> Result<PackageId, Error> {
+    let (crate_name, version) = match config.crate_matching.get(feature) {
+        Some(package_id) => (package_id.crate_name.as_str(), Some(&package_id.version)),
+        None => (feature, None),
+    };
+    let version_req = version
+        .map(|version| {
+            version.parse().map_err(|source| Error::VersionReq {
+                feature: feature.to_string(),
+                crate_name: crate_name.to_string(),
+                version: version.clone(),
+                source,
+            })
+        })
+        .transpose()?;
+    let version =
+        find_package_version(crate_name, version_req.as_ref(), metadata).ok_or_else(|| {
+            Error::MissingPackage {
+                feature: feature.to_string(),
+                crate_name: crate_name.to_string(),
+                version: version.cloned(),
+            }
+        })?;
+    Ok(PackageId {
+        crate_name: crate_name.to_string(),
+        version,
+    })
 }
 
 fn find_package_version(
     name: &str,
-    version_req: Option<semver::VersionReq>,
-    metadata: &Metadata,
-) -> String {
+    version_req: Option<&semver::VersionReq>,
+    metadata: Option<&Metadata>,
+) -> Option<String> {
+    let Some(metadata) = metadata else {
+        return Some(UNKNOWN_VERSION.to_string());
+    };
     metadata
         .packages
         .iter()
-        .find(|pkg| {
-            pkg.name == name
-                && version_req
-                    .as_ref()
-                    .map_or(true, |req| req.matches(&pkg.version))
-        })
-        .unwrap()
-        .version
-        .to_string()
+        .find(|pkg| pkg.name == name && version_req.is_none_or(|req| req.matches(&pkg.version)))
+        .map(|pkg| pkg.version.to_string())
 }
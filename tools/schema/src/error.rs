@@ -0,0 +1,26 @@
+//! Errors shared by the tools, and how they're reported.
+
+use std::{error::Error, io, path::PathBuf};
+
+/// An error reading one of the files that the tools take as input.
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError {
+    #[error("failed to read {}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("failed to parse {}", path.display())]
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+/// Prints `error` to stderr, followed by the chain of errors that caused it.
+pub fn report(error: &dyn Error) {
+    let mut message = format!("error: {error}");
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    eprintln!("{message}");
+}
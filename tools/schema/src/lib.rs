@@ -5,23 +5,39 @@ use std::{
     path::Path,
 };
 
+pub mod error;
+pub mod migrate;
+
+use error::ReadError;
+
+/// The version of the shape that [`Results`] are saved in. Results of older versions are upgraded
+/// by [`migrate::migrate`] when they're loaded.
+pub const SCHEMA_VERSION: u32 = 3;
+
 #[derive(Clone, Deserialize, Serialize)]
 pub struct PackageId {
-    #[serde(alias = "name")]
     pub crate_name: String,
     pub version: String,
 }
 
+/// The version recorded for crates whose version wasn't saved with their results.
+pub const UNKNOWN_VERSION: &str = "unknown";
+
 impl PackageId {
     pub fn crates_io_url(&self) -> String {
-        format!(
-            "https://crates.io/crates/{}/{}",
-            self.crate_name, self.version
-        )
+        if self.version == UNKNOWN_VERSION {
+            format!("https://crates.io/crates/{}", self.crate_name)
+        } else {
+            format!(
+                "https://crates.io/crates/{}/{}",
+                self.crate_name, self.version
+            )
+        }
     }
 }
 
 #[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Suite {
     pub description: String,
     #[serde(default)]
@@ -29,6 +45,7 @@ pub struct Suite {
 }
 
 #[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     /// Benchmark suite information
     pub suites: HashMap<String, Suite>,
@@ -45,21 +62,141 @@ pub struct Config {
 }
 
 impl Config {
-    pub fn read(path: &Path) -> Self {
-        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    pub fn read(path: &Path) -> Result<Self, ReadError> {
+        serde_json::from_str(&read_to_string(path)?).map_err(|source| ReadError::Json {
+            path: path.to_path_buf(),
+            source,
+        })
     }
 }
 
+fn read_to_string(path: &Path) -> Result<String, ReadError> {
+    std::fs::read_to_string(path).map_err(|source| ReadError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
 #[derive(Default, Deserialize, Serialize)]
 pub struct Results {
+    /// Missing from results saved before versions were recorded, whose version is inferred instead
+    #[serde(default)]
+    pub schema_version: u32,
     pub cpu_info: Option<String>,
     pub rustc_info: String,
     pub datasets: BTreeMap<String, Dataset>,
     pub features: Features,
+    /// Missing from results saved before the environment was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<Environment>,
+}
+
+impl Results {
+    /// Parses results saved in any version, migrating them to the current one.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(migrate::migrate(serde_json::from_str(json)?)?)
+    }
+
+    pub fn read(path: &Path) -> Result<Self, ReadError> {
+        Self::from_json(&read_to_string(path)?).map_err(|source| ReadError::Json {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Identifies the machine that the run was made on by the model name in its `lscpu` output. The
+    /// rest of the output, like the current clock speed, changes between runs on the same machine.
+    pub fn cpu_model(&self) -> Option<&str> {
+        let cpu_info = self.cpu_info.as_deref()?;
+        let model = cpu_info
+            .lines()
+            .find_map(|line| line.strip_prefix("Model name:"))
+            .unwrap_or(cpu_info);
+        Some(model.trim())
+    }
 }
 
 pub type Features = BTreeMap<String, PackageId>;
 
+/// The environment that a run was made in, beyond its compiler and CPU.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Environment {
+    /// The commit that was benchmarked
+    pub git: Option<Git>,
+    /// The cargo features that were enabled for the benchmarks
+    pub features: Vec<String>,
+    /// The flags that were passed to rustc, from `RUSTFLAGS` or cargo's config
+    pub rustflags: Vec<String>,
+    /// The `target-cpu` from the rustflags, along with the CPU that `native` resolved to
+    pub target_cpu: Option<String>,
+    /// Settings of the profile that the benchmarks were built with, e.g. `lto` and `codegen-units`
+    pub profile: BTreeMap<String, String>,
+    /// The kernel's name and release, e.g. `Linux 6.8.0-1021-azure`
+    pub kernel: Option<String>,
+    pub cpu_frequency: Option<CpuFrequency>,
+    /// Total physical memory in bytes
+    pub total_memory: Option<u64>,
+    /// The command that ran the benchmarks
+    pub command_line: Vec<String>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Git {
+    pub commit: String,
+    /// Whether the working tree had uncommitted changes
+    pub dirty: bool,
+}
+
+/// How the CPU's clock speed was managed.
+#[derive(Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CpuFrequency {
+    /// The cpufreq scaling driver, e.g. `acpi-cpufreq` or `intel_pstate`
+    pub driver: Option<String>,
+    /// The distinct scaling governors of all CPUs, e.g. `performance`
+    pub governors: Vec<String>,
+    /// Whether CPUs could boost above their base clock speed
+    pub boost: Option<bool>,
+}
+
+impl Environment {
+    /// Lists the settings that differ between two environments, along with their values in each.
+    /// The command line isn't compared, since runs of different subsets of the benchmarks are still
+    /// comparable.
+    pub fn differences(&self, other: &Self) -> Vec<(String, String, String)> {
+        let (serde_json::Value::Object(this), serde_json::Value::Object(other)) = (
+            serde_json::to_value(self).unwrap(),
+            serde_json::to_value(other).unwrap(),
+        ) else {
+            unreachable!("environments serialize to objects");
+        };
+        this.iter()
+            .filter(|(field, _)| *field != "command_line")
+            .filter(|(field, value)| other.get(*field) != Some(value))
+            .map(|(field, value)| (field.clone(), value.to_string(), other[field].to_string()))
+            .collect()
+    }
+
+    /// Lists the settings that differ between two environments and change whether their timings
+    /// are comparable. Runs of different commits or features measured different code, but they can
+    /// still be combined.
+    pub fn conflicts(&self, other: &Self) -> Vec<(String, String, String)> {
+        const TIMING_SETTINGS: [&str; 6] = [
+            "rustflags",
+            "target_cpu",
+            "profile",
+            "kernel",
+            "cpu_frequency",
+            "total_memory",
+        ];
+        self.differences(other)
+            .into_iter()
+            .filter(|(setting, _, _)| TIMING_SETTINGS.contains(&setting.as_str()))
+            .collect()
+    }
+}
+
 #[derive(Default, Deserialize, Serialize)]
 pub struct Dataset {
     pub features: BTreeMap<String, Feature>,
@@ -124,6 +261,9 @@ impl Dataset {
 #[derive(Default, Deserialize, Serialize)]
 pub struct Feature {
     pub benches: HashMap<String, Bench>,
+    /// Outcomes of correctness checks (e.g. schema evolution) keyed by the check's path
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub checks: BTreeMap<String, String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -160,10 +300,93 @@ impl Bench {
 pub struct Values<T> {
     pub primary: Option<T>,
     pub variants: BTreeMap<String, T>,
+    /// Statistics for the primary value, if it is a time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_stats: Option<Stats>,
+    /// Statistics for the variants, if they are times
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variant_stats: BTreeMap<String, Stats>,
 }
 
 impl<T> Values<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.variants.values().chain(self.primary.as_ref())
     }
+
+    /// Iterates over the same values as [`Values::iter`], along with their statistics if they have
+    /// any.
+    pub fn iter_with_stats(&self) -> impl Iterator<Item = (&T, Option<&Stats>)> {
+        self.variants
+            .iter()
+            .map(|(name, value)| (value, self.variant_stats.get(name)))
+            .chain(
+                self.primary
+                    .as_ref()
+                    .map(|value| (value, self.primary_stats.as_ref())),
+            )
+    }
+
+    /// Iterates over the values along with their variant names, which are `None` for the primary
+    /// value, and their statistics if they have any.
+    pub fn iter_named(&self) -> impl Iterator<Item = (Option<&str>, &T, Option<&Stats>)> {
+        self.primary
+            .as_ref()
+            .map(|value| (None, value, self.primary_stats.as_ref()))
+            .into_iter()
+            .chain(
+                self.variants.iter().map(|(name, value)| {
+                    (Some(name.as_str()), value, self.variant_stats.get(name))
+                }),
+            )
+    }
+}
+
+/// Names a bench's value the way criterion does, e.g. `read (unvalidated)` for the `unvalidated`
+/// variant of `read`, so that values can be matched across runs.
+pub fn value_name(bench: &str, variant: Option<&str>) -> String {
+    match variant {
+        Some(variant) => format!("{bench} ({variant})"),
+        None => bench.to_string(),
+    }
+}
+
+/// The uncertainty of a time measurement, in nanoseconds.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Stats {
+    /// The lower bound of the measurement's 95% confidence interval
+    pub lower: f64,
+    /// The upper bound of the measurement's 95% confidence interval
+    pub upper: f64,
+    /// criterion's estimates, if they were read from its saved output rather than the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimates: Option<Estimates>,
+}
+
+/// Statistics that criterion estimated from a measurement's samples.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Estimates {
+    pub mean: Estimate,
+    pub median: Estimate,
+    pub std_dev: Estimate,
+    /// The number of samples the estimates were made from
+    pub samples: usize,
+    pub outliers: Outliers,
+}
+
+/// A point estimate with the bounds of its 95% confidence interval.
+#[derive(Clone, Copy, Deserialize, Serialize)]
+pub struct Estimate {
+    pub point: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// The number of samples that criterion classified as outliers, by how far they fell from the
+/// others.
+#[derive(Clone, Copy, Default, Deserialize, Serialize)]
+pub struct Outliers {
+    pub low_severe: usize,
+    pub low_mild: usize,
+    pub high_mild: usize,
+    pub high_severe: usize,
 }
@@ -0,0 +1,227 @@
+//! Migrations from every shape that results have been saved in to the current one.
+//!
+//! Results saved before `schema_version` was added are recognized by their shape:
+//!
+//! 0. Datasets list their libraries under `crates`, and crate versions are under `meta`
+//! 1. Datasets list their libraries under `features`, with a top-level `features` map of package IDs
+//! 2. `rustc_info` and `cpu_info` are added
+//! 3. Package IDs call the crate's name `crate_name` instead of `name`
+
+use serde::de::Error as _;
+use serde_json::{Map, Value};
+
+use crate::{SCHEMA_VERSION, UNKNOWN_VERSION};
+
+type Object = Map<String, Value>;
+
+/// Migrates results from one version to the next.
+type Migration = fn(&mut Object) -> Result<(), String>;
+
+/// Migrations from each version to the next, indexed by the version they migrate from.
+const MIGRATIONS: [Migration; SCHEMA_VERSION as usize] =
+    [crates_to_features, add_runtime_info, rename_crate_name];
+
+/// Migrates results of any version to the current one.
+pub fn migrate(mut results: Value) -> Result<Value, serde_json::Error> {
+    let object = results
+        .as_object_mut()
+        .ok_or_else(|| serde_json::Error::custom("results are not an object"))?;
+
+    let version = match object.get("schema_version") {
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| serde_json::Error::custom("schema_version is not a number"))?
+            as u32,
+        None => infer_version(object),
+    };
+    if version > SCHEMA_VERSION {
+        return Err(serde_json::Error::custom(format!(
+            "schema version {version} is newer than the supported version {SCHEMA_VERSION}"
+        )));
+    }
+
+    for migration in MIGRATIONS[version as usize..].iter() {
+        migration(object).map_err(serde_json::Error::custom)?;
+    }
+    object.insert("schema_version".to_string(), SCHEMA_VERSION.into());
+
+    Ok(results)
+}
+
+/// Recognizes the version of results that were saved without a `schema_version`.
+fn infer_version(results: &Object) -> u32 {
+    if results.contains_key("meta") {
+        0
+    } else if !results.contains_key("rustc_info") {
+        1
+    } else if results
+        .get("features")
+        .and_then(Value::as_object)
+        .is_some_and(|features| features.values().any(|id| id.get("name").is_some()))
+    {
+        2
+    } else {
+        3
+    }
+}
+
+fn object_mut<'a>(value: &'a mut Object, key: &str) -> Result<&'a mut Object, String> {
+    value
+        .get_mut(key)
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| format!("missing object `{key}`"))
+}
+
+/// Renames each dataset's `crates` to `features`, and turns `meta.crate_versions` into package IDs.
+/// Features were always named after their crates then.
+fn crates_to_features(results: &mut Object) -> Result<(), String> {
+    let meta = results.remove("meta");
+    let versions = meta
+        .as_ref()
+        .and_then(|meta| meta.get("crate_versions"))
+        .and_then(Value::as_object);
+
+    let mut features = Object::new();
+    for dataset in object_mut(results, "datasets")?.values_mut() {
+        let dataset = dataset
+            .as_object_mut()
+            .ok_or_else(|| "dataset is not an object".to_string())?;
+        let crates = dataset
+            .remove("crates")
+            .ok_or_else(|| "dataset is missing `crates`".to_string())?;
+        for name in crates.as_object().into_iter().flat_map(Object::keys) {
+            let version = versions
+                .and_then(|versions| versions.get(name))
+                .cloned()
+                .unwrap_or_else(|| UNKNOWN_VERSION.into());
+            features.insert(
+                name.clone(),
+                Value::Object(Object::from_iter([
+                    ("name".to_string(), name.clone().into()),
+                    ("version".to_string(), version),
+                ])),
+            );
+        }
+        dataset.insert("features".to_string(), crates);
+    }
+    results.insert("features".to_string(), Value::Object(features));
+
+    Ok(())
+}
+
+/// Adds `rustc_info` and `cpu_info`, which weren't recorded.
+fn add_runtime_info(results: &mut Object) -> Result<(), String> {
+    results.insert("rustc_info".to_string(), "unknown".into());
+    results.insert("cpu_info".to_string(), Value::Null);
+    Ok(())
+}
+
+/// Renames each package ID's `name` to `crate_name`.
+fn rename_crate_name(results: &mut Object) -> Result<(), String> {
+    for id in object_mut(results, "features")?.values_mut() {
+        let id = id
+            .as_object_mut()
+            .ok_or_else(|| "package ID is not an object".to_string())?;
+        if let Some(name) = id.remove("name") {
+            id.insert("crate_name".to_string(), name);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Results;
+
+    /// Results with one dataset and one feature in each historical shape, oldest first.
+    fn shapes() -> [Value; 4] {
+        let dataset = json!({ "log": { "crates": { "bincode": { "benches": {} } } } });
+        let features_dataset = json!({ "log": { "features": { "bincode": { "benches": {} } } } });
+        [
+            json!({
+                "meta": { "crate_versions": { "bincode": "1.3.3" } },
+                "datasets": dataset,
+            }),
+            json!({
+                "datasets": features_dataset,
+                "features": { "bincode": { "name": "bincode", "version": "1.3.3" } },
+            }),
+            json!({
+                "cpu_info": "Model name: Example CPU",
+                "rustc_info": "rustc 1.70.0",
+                "datasets": features_dataset,
+                "features": { "bincode": { "name": "bincode", "version": "1.3.3" } },
+            }),
+            json!({
+                "cpu_info": "Model name: Example CPU",
+                "rustc_info": "rustc 1.70.0",
+                "datasets": features_dataset,
+                "features": { "bincode": { "crate_name": "bincode", "version": "1.3.3" } },
+            }),
+        ]
+    }
+
+    #[test]
+    fn infers_each_version() {
+        for (version, shape) in shapes().iter().enumerate() {
+            assert_eq!(
+                infer_version(shape.as_object().unwrap()),
+                version as u32,
+                "{shape}"
+            );
+        }
+    }
+
+    #[test]
+    fn migrates_each_version() {
+        for (version, shape) in shapes().into_iter().enumerate() {
+            let results = Results::from_json(&shape.to_string())
+                .unwrap_or_else(|e| panic!("version {version}: {e}"));
+            assert_eq!(results.schema_version, SCHEMA_VERSION);
+            assert!(results.datasets["log"].features.contains_key("bincode"));
+            let id = &results.features["bincode"];
+            assert_eq!(id.crate_name, "bincode");
+            assert_eq!(id.version, "1.3.3");
+            if version < 2 {
+                assert_eq!(results.rustc_info, "unknown");
+                assert!(results.cpu_info.is_none());
+            } else {
+                assert_eq!(results.rustc_info, "rustc 1.70.0");
+                assert_eq!(results.cpu_model(), Some("Example CPU"));
+            }
+        }
+    }
+
+    #[test]
+    fn records_unknown_versions() {
+        let results = json!({
+            "meta": {},
+            "datasets": { "log": { "crates": { "bincode": { "benches": {} } } } },
+        });
+        let results = Results::from_json(&results.to_string()).unwrap_or_else(|e| panic!("{e}"));
+        assert_eq!(results.features["bincode"].version, UNKNOWN_VERSION);
+    }
+
+    #[test]
+    fn trusts_recorded_version() {
+        // A current version with a leftover `name` would be inferred as version 2
+        let mut results = json!({
+            "schema_version": SCHEMA_VERSION,
+            "rustc_info": "rustc 1.70.0",
+            "datasets": {},
+            "features": { "bincode": { "crate_name": "bincode", "version": "1.3.3" } },
+        });
+        results["features"]["bincode"]["name"] = "bincode".into();
+        let migrated = migrate(results.clone()).unwrap();
+        assert_eq!(migrated, results);
+    }
+
+    #[test]
+    fn rejects_newer_versions() {
+        let results = json!({ "schema_version": SCHEMA_VERSION + 1 });
+        assert!(migrate(results).is_err());
+    }
+}
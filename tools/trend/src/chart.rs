@@ -0,0 +1,199 @@
+//! Renders time series as SVG line charts.
+
+use std::fmt::Write;
+
+const WIDTH: f64 = 960.0;
+const HEIGHT: f64 = 480.0;
+const LEFT: f64 = 80.0;
+const RIGHT: f64 = 260.0;
+const TOP: f64 = 40.0;
+const BOTTOM: f64 = 40.0;
+
+const COLORS: [&str; 10] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+/// A named series of `(x, y)` points, ordered by x.
+pub struct Line {
+    pub name: String,
+    pub points: Vec<(f64, f64)>,
+}
+
+/// A vertical line marking where something changed between runs.
+pub struct Annotation {
+    pub x: f64,
+    pub label: String,
+    pub kind: AnnotationKind,
+}
+
+pub enum AnnotationKind {
+    /// The library was released in a new version
+    Release,
+    /// The benchmarks were run on a different machine
+    Machine,
+}
+
+pub struct Chart<'a> {
+    pub title: &'a str,
+    pub lines: &'a [Line],
+    pub annotations: &'a [Annotation],
+    /// Labeled positions along the x axis
+    pub x_ticks: &'a [(f64, String)],
+    /// Formats values along the y axis
+    pub y_label: &'a dyn Fn(f64) -> String,
+}
+
+impl Chart<'_> {
+    pub fn render(&self) -> String {
+        let points = self.lines.iter().flat_map(|line| line.points.iter());
+        let (mut x_min, mut x_max, mut y_max) = (f64::INFINITY, f64::NEG_INFINITY, 0f64);
+        for &(x, y) in points {
+            x_min = x_min.min(x);
+            x_max = x_max.max(x);
+            y_max = y_max.max(y);
+        }
+        if x_min > x_max {
+            (x_min, x_max) = (0.0, 1.0);
+        } else if x_min == x_max {
+            (x_min, x_max) = (x_min - 1.0, x_max + 1.0);
+        }
+        let y_step = tick_step(y_max);
+        let y_max = (y_max / y_step).ceil().max(1.0) * y_step;
+
+        let plot_width = WIDTH - LEFT - RIGHT;
+        let plot_height = HEIGHT - TOP - BOTTOM;
+        let x_pos = |x: f64| LEFT + (x - x_min) / (x_max - x_min) * plot_width;
+        let y_pos = |y: f64| TOP + plot_height - y / y_max * plot_height;
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" font-family="sans-serif" font-size="12">"#
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect width="{WIDTH}" height="{HEIGHT}" fill="white"/>"#
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<text x="{}" y="24" text-anchor="middle" font-size="16">{}</text>"#,
+            LEFT + plot_width / 2.0,
+            escape(self.title)
+        )
+        .unwrap();
+
+        // Axes and grid
+        for i in 0..=(y_max / y_step).round() as usize {
+            let y = i as f64 * y_step;
+            writeln!(
+                svg,
+                r##"<line x1="{LEFT}" x2="{}" y1="{pos:.1}" y2="{pos:.1}" stroke="#e0e0e0"/><text x="{}" y="{:.1}" text-anchor="end">{}</text>"##,
+                LEFT + plot_width,
+                LEFT - 6.0,
+                y_pos(y) + 4.0,
+                escape(&(self.y_label)(y)),
+                pos = y_pos(y),
+            )
+            .unwrap();
+        }
+        for (x, label) in self.x_ticks {
+            if (x_min..=x_max).contains(x) {
+                writeln!(
+                    svg,
+                    r##"<line x1="{pos:.1}" x2="{pos:.1}" y1="{TOP}" y2="{}" stroke="#e0e0e0"/><text x="{pos:.1}" y="{}" text-anchor="middle">{}</text>"##,
+                    TOP + plot_height,
+                    TOP + plot_height + 16.0,
+                    escape(label),
+                    pos = x_pos(*x),
+                )
+                .unwrap();
+            }
+        }
+        writeln!(
+            svg,
+            r#"<rect x="{LEFT}" y="{TOP}" width="{plot_width}" height="{plot_height}" fill="none" stroke="black"/>"#
+        )
+        .unwrap();
+
+        // Annotations go under the lines so they don't hide them
+        for annotation in self.annotations {
+            let (color, dash) = match annotation.kind {
+                AnnotationKind::Release => ("#555555", "4 3"),
+                AnnotationKind::Machine => ("#d62728", "1 3"),
+            };
+            let pos = x_pos(annotation.x);
+            writeln!(
+                svg,
+                r#"<line x1="{pos:.1}" x2="{pos:.1}" y1="{TOP}" y2="{}" stroke="{color}" stroke-dasharray="{dash}"/><text transform="translate({:.1} {}) rotate(-90)" text-anchor="end" font-size="10" fill="{color}">{}</text>"#,
+                TOP + plot_height,
+                pos - 3.0,
+                TOP + 4.0,
+                escape(&annotation.label),
+            )
+            .unwrap();
+        }
+
+        for (i, line) in self.lines.iter().enumerate() {
+            let color = COLORS[i % COLORS.len()];
+            let path = line
+                .points
+                .iter()
+                .map(|&(x, y)| format!("{:.1},{:.1}", x_pos(x), y_pos(y)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            writeln!(
+                svg,
+                r#"<polyline points="{path}" fill="none" stroke="{color}" stroke-width="1.5"/>"#
+            )
+            .unwrap();
+            for &(x, y) in line.points.iter() {
+                writeln!(
+                    svg,
+                    r#"<circle cx="{:.1}" cy="{:.1}" r="2" fill="{color}"/>"#,
+                    x_pos(x),
+                    y_pos(y)
+                )
+                .unwrap();
+            }
+
+            let legend_y = TOP + 8.0 + i as f64 * 18.0;
+            writeln!(
+                svg,
+                r#"<line x1="{}" x2="{}" y1="{legend_y}" y2="{legend_y}" stroke="{color}" stroke-width="3"/><text x="{}" y="{}">{}</text>"#,
+                WIDTH - RIGHT + 16.0,
+                WIDTH - RIGHT + 36.0,
+                WIDTH - RIGHT + 42.0,
+                legend_y + 4.0,
+                escape(&line.name),
+            )
+            .unwrap();
+        }
+
+        writeln!(svg, "</svg>").unwrap();
+        svg
+    }
+}
+
+/// Picks a step of 1, 2 or 5 times a power of ten that divides `0..=max` into about five ticks.
+fn tick_step(max: f64) -> f64 {
+    if max <= 0.0 {
+        return 1.0;
+    }
+    let raw = max / 5.0;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
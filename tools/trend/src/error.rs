@@ -0,0 +1,15 @@
+use std::{io, path::PathBuf};
+
+use schema::error::ReadError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Read(#[from] ReadError),
+    #[error("{} isn't named after its date", path.display())]
+    RunName { path: PathBuf },
+    #[error("failed to create {}", path.display())]
+    CreateDir { path: PathBuf, source: io::Error },
+    #[error("failed to write {}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
@@ -0,0 +1,444 @@
+mod chart;
+mod error;
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Write},
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser;
+
+use chart::{Annotation, AnnotationKind, Chart, Line};
+use error::Error;
+use schema::{
+    error::{report, ReadError},
+    value_name, Bench, PackageId, Results, UNKNOWN_VERSION,
+};
+
+#[derive(Parser, Debug)]
+#[command(name = "trend")]
+#[command(about = "Charts how every library's results changed across all benchmarking runs")]
+struct Args {
+    /// Results files, or directories whose `.json` files are all loaded. Runs are ordered by the
+    /// dates in their file names.
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+    /// The directory to write a CSV file and an SVG chart to for each library on each dataset
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+/// When a run was started, parsed from its file name.
+///
+/// Runs have been named `{year}-{month}-{day}`, `{year}-{month}-{day}_{n}` for the `n`th run of a
+/// day, and `{year}-{month}-{day}_{hour}-{minute}-{second}`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Timestamp {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    index: u8,
+}
+
+impl Timestamp {
+    fn parse(stem: &str) -> Option<Self> {
+        let (date, rest) = stem.split_once('_').unwrap_or((stem, ""));
+        let [year, month, day] = split_numbers(date)?;
+        let mut timestamp = Timestamp {
+            year: year.try_into().ok()?,
+            month: month.try_into().ok()?,
+            day: day.try_into().ok()?,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            index: 0,
+        };
+        if let Some([hour, minute, second]) = split_numbers(rest) {
+            timestamp.hour = hour.try_into().ok()?;
+            timestamp.minute = minute.try_into().ok()?;
+            timestamp.second = second.try_into().ok()?;
+        } else if !rest.is_empty() {
+            timestamp.index = rest.parse().ok()?;
+        }
+        Some(timestamp)
+    }
+
+    /// Days since 1970-01-01, including the fraction of the day.
+    fn days(&self) -> f64 {
+        let seconds = self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32;
+        days_from_civil(self.year, self.month, self.day) as f64 + seconds as f64 / 86400.0
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+fn split_numbers<const N: usize>(s: &str) -> Option<[u32; N]> {
+    let mut numbers = [0; N];
+    let mut parts = s.split('-');
+    for number in numbers.iter_mut() {
+        *number = parts.next()?.parse().ok()?;
+    }
+    parts.next().is_none().then_some(numbers)
+}
+
+/// Counts the days from 1970-01-01 to a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+struct Run {
+    name: String,
+    timestamp: Timestamp,
+    results: Results,
+}
+
+fn load_runs(inputs: &[PathBuf]) -> Result<Vec<Run>, Error> {
+    let mut paths = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            let read_error = |source| ReadError::Io {
+                path: input.clone(),
+                source,
+            };
+            for entry in fs::read_dir(input).map_err(read_error)? {
+                let path = entry.map_err(read_error)?.path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+                {
+                    paths.push(path);
+                }
+            }
+        } else {
+            paths.push(input.clone());
+        }
+    }
+
+    let mut runs = paths
+        .into_iter()
+        .map(|path| {
+            let Some((name, timestamp)) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|name| Some((name.to_string(), Timestamp::parse(name)?)))
+            else {
+                return Err(Error::RunName { path });
+            };
+            Ok(Run {
+                name,
+                timestamp,
+                results: Results::read(&path)?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    runs.sort_by_key(|run| run.timestamp);
+    Ok(runs)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Nanos,
+    Bytes,
+}
+
+impl Unit {
+    fn suffix(&self) -> &'static str {
+        match self {
+            Unit::Nanos => "ns",
+            Unit::Bytes => "bytes",
+        }
+    }
+}
+
+/// One library's results on one dataset in one run.
+struct Point<'a> {
+    run: &'a Run,
+    package_id: Option<&'a PackageId>,
+    /// Values keyed by their names, e.g. `read (unvalidated)`
+    values: BTreeMap<String, (f64, Unit)>,
+}
+
+/// Collects every library's results on every dataset across runs, keyed by dataset and feature.
+fn collect_series(runs: &[Run]) -> BTreeMap<(&str, &str), Vec<Point<'_>>> {
+    let mut series = BTreeMap::<_, Vec<_>>::new();
+    for run in runs {
+        for (dataset_name, dataset) in run.results.datasets.iter() {
+            for (feature_name, feature) in dataset.features.iter() {
+                let mut values = BTreeMap::new();
+                for (bench_name, bench) in feature.benches.iter() {
+                    match bench {
+                        Bench::Nanos(bench) => {
+                            for (variant, value, _) in bench.iter_named() {
+                                values
+                                    .insert(value_name(bench_name, variant), (*value, Unit::Nanos));
+                            }
+                        }
+                        Bench::Bytes(bench) => {
+                            for (variant, value, _) in bench.iter_named() {
+                                values.insert(
+                                    value_name(bench_name, variant),
+                                    (*value as f64, Unit::Bytes),
+                                );
+                            }
+                        }
+                    }
+                }
+                series
+                    .entry((dataset_name.as_str(), feature_name.as_str()))
+                    .or_default()
+                    .push(Point {
+                        run,
+                        package_id: run.results.features.get(feature_name),
+                        values,
+                    });
+            }
+        }
+    }
+    series
+}
+
+fn known(package_id: Option<&PackageId>) -> Option<&PackageId> {
+    package_id.filter(|package_id| package_id.version != UNKNOWN_VERSION)
+}
+
+/// Marks the runs where the library was released in a new version. Runs whose versions weren't
+/// recorded are skipped over.
+fn release_annotations(points: &[Point]) -> Vec<Annotation> {
+    let mut annotations = Vec::new();
+    let mut previous = None::<&PackageId>;
+    for point in points {
+        let Some(current) = known(point.package_id) else {
+            continue;
+        };
+        if let Some(previous) = previous {
+            if previous.crate_name != current.crate_name {
+                annotations.push(Annotation {
+                    x: point.run.timestamp.days(),
+                    label: format!("{} {}", current.crate_name, current.version),
+                    kind: AnnotationKind::Release,
+                });
+            } else if previous.version != current.version {
+                annotations.push(Annotation {
+                    x: point.run.timestamp.days(),
+                    label: current.version.clone(),
+                    kind: AnnotationKind::Release,
+                });
+            }
+        }
+        previous = Some(current);
+    }
+    annotations
+}
+
+/// Marks the runs that were made on a different CPU model than the run before them. Runs whose CPUs
+/// weren't recorded are skipped over.
+fn machine_annotations(runs: &[Run]) -> Vec<Annotation> {
+    let mut annotations = Vec::new();
+    let mut previous = None::<&str>;
+    for run in runs {
+        let Some(current) = run.results.cpu_model() else {
+            continue;
+        };
+        if previous.is_some_and(|previous| previous != current) {
+            annotations.push(Annotation {
+                x: run.timestamp.days(),
+                label: "new machine".to_string(),
+                kind: AnnotationKind::Machine,
+            });
+        }
+        previous = Some(current);
+    }
+    annotations
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_csv(points: &[Point]) -> String {
+    let mut columns = BTreeMap::new();
+    for point in points {
+        for (name, (_, unit)) in point.values.iter() {
+            columns.insert(name.as_str(), *unit);
+        }
+    }
+
+    let mut csv = String::from("run,date,crate,version,cpu");
+    for (name, unit) in columns.iter() {
+        write!(
+            csv,
+            ",{}",
+            csv_field(&format!("{name} ({})", unit.suffix()))
+        )
+        .unwrap();
+    }
+    writeln!(csv).unwrap();
+
+    for point in points {
+        let (crate_name, version) = point
+            .package_id
+            .map_or(("", ""), |id| (id.crate_name.as_str(), id.version.as_str()));
+        write!(
+            csv,
+            "{},{},{},{},{}",
+            csv_field(&point.run.name),
+            point.run.timestamp,
+            csv_field(crate_name),
+            csv_field(version),
+            csv_field(point.run.results.cpu_model().unwrap_or_default()),
+        )
+        .unwrap();
+        for name in columns.keys() {
+            match point.values.get(*name) {
+                Some((value, _)) => write!(csv, ",{value}").unwrap(),
+                None => write!(csv, ",").unwrap(),
+            }
+        }
+        writeln!(csv).unwrap();
+    }
+    csv
+}
+
+/// Charts every time in `points`. Sizes are left to the CSV, since they rarely change and don't
+/// share an axis with times.
+fn write_svg(
+    title: &str,
+    points: &[Point],
+    machine_annotations: &[Annotation],
+    x_ticks: &[(f64, String)],
+) -> String {
+    let mut lines = BTreeMap::<&str, Vec<_>>::new();
+    for point in points {
+        for (name, (value, unit)) in point.values.iter() {
+            if *unit == Unit::Nanos {
+                lines
+                    .entry(name.as_str())
+                    .or_default()
+                    .push((point.run.timestamp.days(), *value));
+            }
+        }
+    }
+    let lines = lines
+        .into_iter()
+        .map(|(name, points)| Line {
+            name: name.to_string(),
+            points,
+        })
+        .collect::<Vec<_>>();
+
+    let first = points
+        .first()
+        .map_or(0.0, |point| point.run.timestamp.days());
+    let last = points
+        .last()
+        .map_or(0.0, |point| point.run.timestamp.days());
+    let mut annotations = release_annotations(points);
+    annotations.extend(
+        machine_annotations
+            .iter()
+            .filter(|annotation| (first..=last).contains(&annotation.x))
+            .map(|annotation| Annotation {
+                x: annotation.x,
+                label: annotation.label.clone(),
+                kind: AnnotationKind::Machine,
+            }),
+    );
+
+    let max = lines
+        .iter()
+        .flat_map(|line| line.points.iter().map(|(_, y)| *y))
+        .fold(0.0, f64::max);
+    let (scale, suffix) = if max > 1_000_000.0 {
+        (1_000_000.0, "ms")
+    } else if max > 1_000.0 {
+        (1_000.0, "µs")
+    } else {
+        (1.0, "ns")
+    };
+    let y_label = |y: f64| format!("{} {suffix}", (y / scale * 1000.0).round() / 1000.0);
+
+    Chart {
+        title,
+        lines: &lines,
+        annotations: &annotations,
+        x_ticks,
+        y_label: &y_label,
+    }
+    .render()
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let runs = load_runs(&args.inputs)?;
+    let machine_annotations = machine_annotations(&runs);
+    let x_ticks = match (runs.first(), runs.last()) {
+        (Some(first), Some(last)) => (first.timestamp.year..=last.timestamp.year + 1)
+            .map(|year| (days_from_civil(year, 1, 1) as f64, year.to_string()))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    for ((dataset, feature), points) in collect_series(&runs) {
+        let dir = args.output.join(dataset);
+        fs::create_dir_all(&dir).map_err(|source| Error::CreateDir {
+            path: dir.clone(),
+            source,
+        })?;
+        write(&dir.join(format!("{feature}.csv")), &write_csv(&points))?;
+        write(
+            &dir.join(format!("{feature}.svg")),
+            &write_svg(
+                &format!("{feature} on {dataset}"),
+                &points,
+                &machine_annotations,
+                &x_ticks,
+            ),
+        )?;
+    }
+    println!(
+        "Wrote trends for {} runs to {}",
+        runs.len(),
+        args.output.display()
+    );
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    fs::write(path, contents).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
@@ -0,0 +1,15 @@
+use std::{io, path::PathBuf};
+
+use schema::error::ReadError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Read(#[from] ReadError),
+    #[error("{} has no file name to write the upgraded results to", path.display())]
+    FileName { path: PathBuf },
+    #[error("failed to create {}", path.display())]
+    CreateDir { path: PathBuf, source: io::Error },
+    #[error("failed to write {}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+}
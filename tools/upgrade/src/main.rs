@@ -0,0 +1,83 @@
+mod error;
+
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+
+use error::Error;
+use schema::{
+    error::{report, ReadError},
+    Results,
+};
+
+#[derive(Parser, Debug)]
+#[command(name = "upgrade")]
+#[command(about = "Upgrades saved benchmark results to the current schema version")]
+struct Args {
+    /// Results files, or directories whose `.json` files are all upgraded
+    #[arg(required = true)]
+    inputs: Vec<PathBuf>,
+    /// The directory to write upgraded files to, instead of upgrading them in place
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<(), Error> {
+    let mut paths = Vec::new();
+    for input in args.inputs {
+        if input.is_dir() {
+            let read_error = |source| ReadError::Io {
+                path: input.clone(),
+                source,
+            };
+            for entry in fs::read_dir(&input).map_err(read_error)? {
+                let path = entry.map_err(read_error)?.path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+                {
+                    paths.push(path);
+                }
+            }
+        } else {
+            paths.push(input);
+        }
+    }
+    paths.sort();
+
+    if let Some(output) = &args.output {
+        fs::create_dir_all(output).map_err(|source| Error::CreateDir {
+            path: output.clone(),
+            source,
+        })?;
+    }
+
+    for path in paths {
+        let results = Results::read(&path)?;
+        let output = match &args.output {
+            Some(output) => output.join(
+                path.file_name()
+                    .ok_or_else(|| Error::FileName { path: path.clone() })?,
+            ),
+            None => path.clone(),
+        };
+        fs::write(&output, serde_json::to_string(&results).unwrap()).map_err(|source| {
+            Error::Write {
+                path: output.clone(),
+                source,
+            }
+        })?;
+        println!("{} -> {}", path.display(), output.display());
+    }
+    Ok(())
+}